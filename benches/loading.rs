@@ -0,0 +1,98 @@
+//! Compares sequential and parallel loading of a large generated translation tree.
+//! Run with `cargo bench --bench loading`.
+
+use std::{
+  env, fs,
+  path::{Path, PathBuf},
+  time::{Duration, Instant},
+};
+
+use translate::{Language, TranslationKey, Translator};
+
+const LANGUAGES: usize = 40;
+const FILES_PER_LANGUAGE: usize = 50;
+const MESSAGES_PER_FILE: usize = 100;
+const ITERATIONS: u32 = 5;
+
+struct BenchLanguage(&'static str);
+
+impl Language for BenchLanguage {
+  fn as_str(&self) -> &'static str {
+    self.0
+  }
+}
+
+struct BenchKey(&'static str);
+
+impl TranslationKey for BenchKey {
+  fn as_str(&self) -> &'static str {
+    self.0
+  }
+}
+
+fn main() {
+  let directory = env::temp_dir().join(format!("translate-loading-bench-{}", std::process::id()));
+  generate_tree(&directory);
+
+  // Warms up the file system cache so the first measurement is not penalized.
+  measure(&directory, false);
+
+  let sequential = measure(&directory, false);
+  let parallel = measure(&directory, true);
+
+  println!(
+    "{LANGUAGES} languages, {FILES_PER_LANGUAGE} files each, {MESSAGES_PER_FILE} messages per file"
+  );
+  println!("sequential: {sequential:?} per load");
+  println!("parallel:   {parallel:?} per load");
+  println!(
+    "speedup:    {:.2}x",
+    sequential.as_secs_f64() / parallel.as_secs_f64()
+  );
+
+  fs::remove_dir_all(&directory).expect("Could not remove the generated translations");
+}
+
+fn measure(directory: &Path, parallel_loading: bool) -> Duration {
+  let directory = directory.to_string_lossy();
+  let start = Instant::now();
+
+  for _ in 0..ITERATIONS {
+    let translator = Translator::<BenchLanguage, BenchKey>::builder()
+      .directory(&directory)
+      .default_language(&BenchLanguage("qaa"))
+      .parallel_loading(parallel_loading)
+      .build()
+      .expect("Could not load the generated translations");
+
+    assert!(translator.bundle(&BenchLanguage("qaa")).is_some());
+  }
+
+  start.elapsed() / ITERATIONS
+}
+
+fn generate_tree(directory: &Path) {
+  for language in 0..LANGUAGES {
+    let language_directory: PathBuf = directory.join(language_code(language));
+    fs::create_dir_all(&language_directory).expect("Could not create a language directory");
+
+    for file in 0..FILES_PER_LANGUAGE {
+      let content: String = (0..MESSAGES_PER_FILE)
+        .map(|message| {
+          format!(
+            "message-{file}-{message} = {{ $count ->\n    [one] One item in file {file}\n   *[other] {{ $count }} items for {{ $name }}\n}}\n    .title = Title {message}\n"
+          )
+        })
+        .collect();
+
+      fs::write(language_directory.join(format!("file-{file}.ftl")), content)
+        .expect("Could not write a translation file");
+    }
+  }
+}
+
+/// Private use language codes, `qaa`, `qab` and so on.
+fn language_code(index: usize) -> String {
+  let letter = |offset: usize| char::from(b'a' + (offset % 26) as u8);
+  format!("q{}{}", letter(index / 26), letter(index))
+}
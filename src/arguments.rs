@@ -0,0 +1,32 @@
+use fluent_bundle::FluentArgs;
+
+/// ### Description
+/// Converts a value into the arguments of a message, so call sites pass a typed struct instead of naming
+/// every argument. With the `derive` feature it can be derived for structs with named fields.
+/// ### Usage
+/// ```ignore
+/// struct UserContext<'a> {
+///   name: &'a str,
+///   count: u32,
+/// }
+///
+/// impl<'a> IntoFluentArgs<'a> for UserContext<'a> {
+///   fn into_fluent_args(self) -> FluentArgs<'a> {
+///     let mut args = FluentArgs::new();
+///     args.set("name", self.name);
+///     args.set("count", self.count);
+///     args
+///   }
+/// }
+///
+/// let message = translator.translate(language, key).with(UserContext { name: "Alex", count: 3 });
+/// ```
+pub trait IntoFluentArgs<'args> {
+  fn into_fluent_args(self) -> FluentArgs<'args>;
+}
+
+impl<'args> IntoFluentArgs<'args> for FluentArgs<'args> {
+  fn into_fluent_args(self) -> FluentArgs<'args> {
+    self
+  }
+}
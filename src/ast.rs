@@ -0,0 +1,81 @@
+use fluent_syntax::ast::{Expression, InlineExpression, Message, Pattern, PatternElement, Variant};
+
+/// An expression found while walking a pattern.
+pub(crate) enum Node<'ast, 'source> {
+  /// Any inline expression, such as a variable, a reference, a function call or a literal.
+  Inline(&'ast InlineExpression<&'source str>),
+  /// The variants of a select expression, visited before its selector and its variants.
+  Select(&'ast [Variant<&'source str>]),
+}
+
+/// The value and the attributes of a message.
+pub(crate) fn message_patterns<'ast, 'source>(
+  message: &'ast Message<&'source str>,
+) -> impl Iterator<Item = &'ast Pattern<&'source str>> {
+  message
+    .value
+    .iter()
+    .chain(message.attributes.iter().map(|attribute| &attribute.value))
+}
+
+/// Calls `visit` for every expression of a pattern, including the ones nested in selectors, variants,
+/// placeables and call arguments. References to other messages and terms are not followed.
+pub(crate) fn walk_pattern<'ast, 'source, Visit>(
+  pattern: &'ast Pattern<&'source str>,
+  visit: &mut Visit,
+) where
+  Visit: FnMut(Node<'ast, 'source>),
+{
+  for element in &pattern.elements {
+    if let PatternElement::Placeable { expression } = element {
+      walk_expression(expression, visit);
+    }
+  }
+}
+
+fn walk_expression<'ast, 'source, Visit>(
+  expression: &'ast Expression<&'source str>,
+  visit: &mut Visit,
+) where
+  Visit: FnMut(Node<'ast, 'source>),
+{
+  match expression {
+    Expression::Inline(inline_expression) => walk_inline(inline_expression, visit),
+    Expression::Select { selector, variants } => {
+      visit(Node::Select(variants));
+      walk_inline(selector, visit);
+
+      for variant in variants {
+        walk_pattern(&variant.value, visit);
+      }
+    }
+  }
+}
+
+fn walk_inline<'ast, 'source, Visit>(
+  inline_expression: &'ast InlineExpression<&'source str>,
+  visit: &mut Visit,
+) where
+  Visit: FnMut(Node<'ast, 'source>),
+{
+  visit(Node::Inline(inline_expression));
+
+  match inline_expression {
+    InlineExpression::FunctionReference { arguments, .. }
+    | InlineExpression::TermReference {
+      arguments: Some(arguments),
+      ..
+    } => {
+      let values = arguments
+        .positional
+        .iter()
+        .chain(arguments.named.iter().map(|named| &named.value));
+
+      for value in values {
+        walk_inline(value, visit);
+      }
+    }
+    InlineExpression::Placeable { expression } => walk_expression(expression, visit),
+    _ => {}
+  }
+}
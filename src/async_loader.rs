@@ -0,0 +1,287 @@
+use crate::{
+  loaded_languages::index_languages,
+  loader::{
+    classify_entry, common_file_names, directory_error, ftl_source, language_name,
+    overlay_directories, sorted_language_names, DirectoryIndex, FileData, TopLevelEntry,
+    TranslationFiles,
+  },
+  FileFilter, Language, TranslationKey, Translator, TranslatorBuilder, TranslatorError,
+  TranslatorOptions,
+};
+
+use std::path::{Path, PathBuf};
+
+use tokio::fs::{self, DirEntry};
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Creates a new translator like `new`, reading directories and files with `tokio::fs` so loading
+  /// does not block the worker threads of the runtime.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::new_async(path, &Languages::English).await?;
+  /// ```
+  pub async fn new_async(
+    directory_path: &str,
+    default_language: &LanguageGeneric,
+  ) -> Result<Translator<LanguageGeneric, TranslationKeyGeneric>, TranslatorError> {
+    TranslatorBuilder::new()
+      .directory(directory_path)
+      .default_language(default_language)
+      .build_async()
+      .await
+  }
+
+  pub(crate) async fn load_async(
+    directory_path: &str,
+    default_language: &'static str,
+    options: TranslatorOptions,
+  ) -> Result<Translator<LanguageGeneric, TranslationKeyGeneric>, TranslatorError> {
+    if options.lazy_loading {
+      return Self::load_lazily_async(directory_path, default_language, options).await;
+    }
+
+    info!("Loading languages...");
+
+    let language_files = read_language_files(Path::new(directory_path), &options).await?;
+
+    let mut overlay_files = Vec::new();
+
+    for overlay_directory in overlay_directories(&options) {
+      overlay_files.push(read_language_files(&overlay_directory, &options).await?);
+    }
+
+    Self::from_language_files(
+      language_files,
+      overlay_files,
+      directory_path,
+      default_language,
+      options,
+    )
+  }
+
+  /// Indexes the languages like `load_lazily`, reading the directories and the files of the default language
+  /// with `tokio::fs`. Other languages are read the first time they are used.
+  async fn load_lazily_async(
+    directory_path: &str,
+    default_language: &'static str,
+    options: TranslatorOptions,
+  ) -> Result<Translator<LanguageGeneric, TranslationKeyGeneric>, TranslatorError> {
+    info!("Indexing languages...");
+
+    let mut indexes = Vec::new();
+
+    for directory in
+      std::iter::once(PathBuf::from(directory_path)).chain(overlay_directories(&options))
+    {
+      indexes.push(read_directory_index(&directory, &options).await?);
+    }
+
+    let (mut language_slots, lazy_loader) = index_languages(indexes, &options)?;
+
+    if let Some(identifier) = language_slots.identifier(default_language) {
+      let mut directory_files = Vec::new();
+
+      for directory in lazy_loader.directories() {
+        directory_files.push(
+          read_single_language_files(directory, default_language, &options)
+            .await
+            .unwrap_or_else(|error| {
+              warn!(
+                "Could not read language {default_language} from {}: {error}",
+                directory.display()
+              );
+              Vec::new()
+            }),
+        );
+      }
+
+      let loaded = lazy_loader.load_files(default_language, identifier, directory_files);
+      language_slots.insert(
+        default_language.to_string(),
+        loaded.bundle,
+        loaded.resources,
+      );
+    }
+
+    Self::from_language_slots(
+      language_slots,
+      lazy_loader,
+      directory_path,
+      default_language,
+      options,
+    )
+  }
+}
+
+/// Reads every translation file of a translations directory, grouped by language, along with the files of
+/// the common directory. Entries are classified like the synchronous loader does, only the reads differ.
+async fn read_language_files(
+  directory_path: &Path,
+  options: &TranslatorOptions,
+) -> Result<TranslationFiles, TranslatorError> {
+  let mut translations_directory = fs::read_dir(directory_path)
+    .await
+    .map_err(|error| directory_error(directory_path, error))?;
+
+  let mut translation_files = TranslationFiles {
+    directory: directory_path.to_path_buf(),
+    ..TranslationFiles::default()
+  };
+
+  while let Some((entry, entry_name, is_dir)) =
+    next_entry(&mut translations_directory, directory_path).await
+  {
+    match classify_entry(&entry_name, is_dir, options) {
+      Some(TopLevelEntry::Common) => {
+        let common_files = read_nested_files(&entry.path(), &options.file_filter).await?;
+        translation_files.add_common_files(&entry_name, common_files);
+      }
+      Some(TopLevelEntry::LanguageDirectory(language_name, language_identifier)) => {
+        let files = read_nested_files(&entry.path(), &options.file_filter).await?;
+        translation_files.add_language_files(language_name, language_identifier, files, false);
+      }
+      Some(TopLevelEntry::LanguageFile(language_name, language_identifier)) => {
+        let files = read_file(&entry.path(), entry_name)
+          .await
+          .into_iter()
+          .collect();
+        translation_files.add_language_files(language_name, language_identifier, files, true);
+      }
+      None => {}
+    }
+  }
+
+  Ok(translation_files)
+}
+
+/// Reads the language names and common files of a translations directory, see `read_directory_index`.
+async fn read_directory_index(
+  directory_path: &Path,
+  options: &TranslatorOptions,
+) -> Result<DirectoryIndex, TranslatorError> {
+  let mut entries = fs::read_dir(directory_path)
+    .await
+    .map_err(|error| directory_error(directory_path, error))?;
+
+  let mut language_names = Vec::new();
+  let mut common = Vec::new();
+
+  while let Some((entry, entry_name, is_dir)) = next_entry(&mut entries, directory_path).await {
+    match classify_entry(&entry_name, is_dir, options) {
+      Some(TopLevelEntry::Common) => {
+        let common_files = read_nested_files(&entry.path(), &options.file_filter).await?;
+        common.extend(common_file_names(&entry_name, common_files));
+      }
+      language_entry => language_names.extend(language_name(language_entry)),
+    }
+  }
+
+  Ok(DirectoryIndex {
+    directory: directory_path.to_path_buf(),
+    language_names: sorted_language_names(language_names),
+    common,
+  })
+}
+
+/// Reads the files of a single language of a translations directory, see `read_single_language_files`.
+async fn read_single_language_files(
+  directory_path: &Path,
+  language: &str,
+  options: &TranslatorOptions,
+) -> Result<Vec<FileData>, TranslatorError> {
+  let mut entries = fs::read_dir(directory_path)
+    .await
+    .map_err(|error| directory_error(directory_path, error))?;
+
+  let mut files = Vec::new();
+
+  while let Some((entry, entry_name, is_dir)) = next_entry(&mut entries, directory_path).await {
+    match classify_entry(&entry_name, is_dir, options) {
+      Some(TopLevelEntry::LanguageDirectory(language_name, _)) if language_name == language => {
+        files.extend(read_nested_files(&entry.path(), &options.file_filter).await?);
+      }
+      Some(TopLevelEntry::LanguageFile(language_name, _)) if language_name == language => {
+        files.splice(0..0, read_file(&entry.path(), entry_name).await);
+      }
+      _ => {}
+    }
+  }
+
+  Ok(files)
+}
+
+/// Next entry of a directory along with its name and whether it is a directory, skipping the entries which
+/// cannot be checked.
+async fn next_entry(
+  entries: &mut fs::ReadDir,
+  directory_path: &Path,
+) -> Option<(DirEntry, String, bool)> {
+  loop {
+    let entry = match entries.next_entry().await {
+      Ok(Some(entry)) => entry,
+      Ok(None) => return None,
+      Err(error) => {
+        warn!(
+          "Stopped reading {} because of the following error: {error}",
+          directory_path.display()
+        );
+        return None;
+      }
+    };
+
+    let entry_name = entry.file_name().to_string_lossy().to_string();
+
+    match entry.file_type().await {
+      Ok(file_type) => return Some((entry, entry_name, file_type.is_dir())),
+      Err(_) => warn!("Could not check if {entry_name} is a directory"),
+    }
+  }
+}
+
+/// Reads every file below a directory, naming them by their path relative to it.
+async fn read_nested_files(
+  directory_path: &Path,
+  file_filter: &FileFilter,
+) -> Result<Vec<FileData>, TranslatorError> {
+  let mut files = Vec::new();
+  let mut pending_directories: Vec<(PathBuf, String)> =
+    vec![(directory_path.to_path_buf(), String::new())];
+
+  while let Some((directory, prefix)) = pending_directories.pop() {
+    let mut entries = fs::read_dir(&directory)
+      .await
+      .map_err(|error| directory_error(&directory, error))?;
+
+    while let Some((entry, entry_name, is_dir)) = next_entry(&mut entries, &directory).await {
+      let file_name = format!("{prefix}{entry_name}");
+
+      if is_dir {
+        pending_directories.push((entry.path(), format!("{file_name}/")));
+      } else if !file_filter.accepts(&file_name) {
+        debug!("Skipping {file_name} as it is not a translation file");
+      } else {
+        files.extend(read_file(&entry.path(), file_name).await);
+      }
+    }
+  }
+
+  Ok(files)
+}
+
+async fn read_file(path: &Path, file_name: String) -> Option<FileData> {
+  trace!("Loading file {file_name}");
+
+  match fs::read_to_string(path).await {
+    Ok(file_content) => Some((ftl_source(&file_name, file_content), file_name)),
+    Err(error) => {
+      warn!("Could not read file {file_name} because of the following error: {error}");
+      None
+    }
+  }
+}
@@ -0,0 +1,268 @@
+use crate::{
+  ast::{message_patterns, walk_pattern, Node},
+  Language, TranslationKey, Translator,
+};
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use fluent_bundle::{
+  resolver::{errors::ReferenceKind, ResolverError},
+  FluentError,
+};
+use fluent_syntax::ast::{Entry, InlineExpression};
+
+/// A problem found by [`Translator::audit`] in a message of a language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditIssue {
+  pub language: String,
+  pub key: String,
+  pub kind: AuditIssueKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuditIssueKind {
+  /// The message references a message, term or function which does not exist.
+  UndefinedReference { reference: String },
+  /// The entry could not be parsed and was skipped while loading the file. Lines start at 1.
+  ParseRecovered {
+    file_name: String,
+    line: usize,
+    detail: String,
+  },
+  /// The message is defined again by a later file of the language, which is ignored or overrides it
+  /// depending on the conflict policy.
+  DuplicateDefinition { file_name: String },
+  /// The message uses different arguments than in the default language, usually a renamed or forgotten
+  /// placeholder.
+  ArgumentsDiffer {
+    missing: Vec<String>,
+    extra: Vec<String>,
+  },
+  /// Any other formatting failure, such as a cyclic reference or a select without default variant.
+  FormatError { detail: String },
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Formats every message of every language without arguments and reports the problems found, along with
+  /// the entries that could not be parsed, messages defined twice and messages using other arguments than
+  /// in the default language. Meant to be used as a CI gate to catch broken translations before deploying.
+  /// Missing arguments are not reported, as no arguments are given while auditing.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let issues = translator.audit();
+  ///
+  /// assert!(issues.is_empty(), "{issues:#?}");
+  /// ```
+  pub fn audit(&self) -> Vec<AuditIssue> {
+    let mut languages: Vec<&str> = self.translations.keys().collect();
+    languages.sort_unstable();
+
+    let mut issues = Vec::new();
+    let mut errors = Vec::new();
+
+    let default_arguments = self.message_arguments(self.default_language);
+
+    for language in languages {
+      let Some(loaded) = self.translations.get(language) else {
+        continue;
+      };
+      let bundle = &loaded.bundle;
+      let resources = loaded.resources.iter();
+
+      let mut keys = BTreeSet::new();
+      let mut defined_ids = BTreeSet::new();
+
+      for loaded_resource in resources {
+        for parse_error in &loaded_resource.parse_errors {
+          let source = loaded_resource.resource.source();
+          let snippet = parse_error
+            .slice
+            .clone()
+            .and_then(|slice| source.get(slice))
+            .unwrap_or_default();
+
+          // Line of the broken entry, the error itself may only be noticed lines later.
+          let entry_start = parse_error
+            .slice
+            .as_ref()
+            .map_or(parse_error.pos.start, |slice| {
+              slice.start + (snippet.len() - snippet.trim_start().len())
+            });
+          let line = source
+            .get(..entry_start)
+            .map_or(0, |before| before.matches('\n').count())
+            + 1;
+
+          issues.push(AuditIssue {
+            language: language.to_string(),
+            key: snippet
+              .split('=')
+              .next()
+              .unwrap_or_default()
+              .trim()
+              .to_string(),
+            kind: AuditIssueKind::ParseRecovered {
+              file_name: loaded_resource.file_name.clone(),
+              line,
+              detail: parse_error.to_string(),
+            },
+          });
+        }
+
+        let message_ids = loaded_resource
+          .resource
+          .entries()
+          .filter_map(|entry| match entry {
+            Entry::Message(message) => Some(message.id.name),
+            _ => None,
+          });
+
+        for message_id in message_ids {
+          keys.insert(message_id);
+
+          // Common files and overlays are meant to be replaced by later files.
+          if loaded_resource.common || loaded_resource.overriding {
+            continue;
+          }
+
+          if !defined_ids.insert(message_id) {
+            issues.push(AuditIssue {
+              language: language.to_string(),
+              key: message_id.to_string(),
+              kind: AuditIssueKind::DuplicateDefinition {
+                file_name: loaded_resource.file_name.clone(),
+              },
+            });
+          }
+        }
+      }
+
+      if language != self.default_language {
+        let arguments = self.message_arguments(language);
+
+        for (key, language_arguments) in &arguments {
+          let Some(expected_arguments) = default_arguments.get(key) else {
+            continue;
+          };
+
+          let missing: Vec<String> = expected_arguments
+            .difference(language_arguments)
+            .map(ToString::to_string)
+            .collect();
+          let extra: Vec<String> = language_arguments
+            .difference(expected_arguments)
+            .map(ToString::to_string)
+            .collect();
+
+          if !missing.is_empty() || !extra.is_empty() {
+            issues.push(AuditIssue {
+              language: language.to_string(),
+              key: key.to_string(),
+              kind: AuditIssueKind::ArgumentsDiffer { missing, extra },
+            });
+          }
+        }
+      }
+
+      for key in keys {
+        let Some(message) = bundle.get_message(key) else {
+          continue;
+        };
+
+        let mut push_issue = |kind| {
+          issues.push(AuditIssue {
+            language: language.to_string(),
+            key: key.to_string(),
+            kind,
+          })
+        };
+
+        // Messages with only attributes are valid, they are translated through their attributes.
+        let patterns = message
+          .value()
+          .into_iter()
+          .chain(message.attributes().map(|attribute| attribute.value()));
+
+        for pattern in patterns {
+          errors.clear();
+          bundle.format_pattern(pattern, None, &mut errors);
+          errors
+            .iter()
+            .filter_map(audit_issue_kind)
+            .for_each(&mut push_issue);
+        }
+      }
+    }
+
+    issues
+  }
+
+  /// Arguments every message of a language uses in its value and attributes, by key. The first definition
+  /// of a message is the one kept, like when loading.
+  fn message_arguments(&self, language: &str) -> BTreeMap<&str, BTreeSet<&str>> {
+    let mut arguments = BTreeMap::new();
+
+    let messages = self
+      .translations
+      .resources(language)
+      .into_iter()
+      .flatten()
+      .flat_map(|loaded_resource| loaded_resource.resource.entries())
+      .filter_map(|entry| match entry {
+        Entry::Message(message) => Some(message),
+        _ => None,
+      });
+
+    for message in messages {
+      arguments.entry(message.id.name).or_insert_with(|| {
+        let mut message_arguments = BTreeSet::new();
+
+        for pattern in message_patterns(message) {
+          walk_pattern(pattern, &mut |node| {
+            if let Node::Inline(InlineExpression::VariableReference { id }) = node {
+              message_arguments.insert(id.name);
+            }
+          });
+        }
+
+        message_arguments
+      });
+    }
+
+    arguments
+  }
+}
+
+fn audit_issue_kind(error: &FluentError) -> Option<AuditIssueKind> {
+  let FluentError::ResolverError(resolver_error) = error else {
+    return Some(AuditIssueKind::FormatError {
+      detail: error.to_string(),
+    });
+  };
+
+  let reference = match resolver_error {
+    ResolverError::Reference(ReferenceKind::Variable { .. }) => return None,
+    ResolverError::Reference(ReferenceKind::Function { id }) => format!("{id}()"),
+    ResolverError::Reference(ReferenceKind::Message { id, attribute }) => match attribute {
+      Some(attribute) => format!("{id}.{attribute}"),
+      None => id.clone(),
+    },
+    ResolverError::Reference(ReferenceKind::Term { id, attribute }) => match attribute {
+      Some(attribute) => format!("-{id}.{attribute}"),
+      None => format!("-{id}"),
+    },
+    other => {
+      return Some(AuditIssueKind::FormatError {
+        detail: other.to_string(),
+      })
+    }
+  };
+
+  Some(AuditIssueKind::UndefinedReference { reference })
+}
@@ -0,0 +1,118 @@
+use crate::{FluentArgs, Language, SharedTranslator, TranslationKey};
+
+use std::convert::Infallible;
+
+use axum::{
+  async_trait,
+  extract::{FromRef, FromRequestParts},
+  http::{header::ACCEPT_LANGUAGE, request::Parts},
+};
+
+/// ### Description
+/// Extracts a translator scoped to the language negotiated from the `Accept-Language` header of the
+/// request, falling back to the default language. The application state must provide the
+/// [`SharedTranslator`] through `FromRef`.
+/// ### Usage
+/// ```ignore
+/// ...
+/// async fn hello(translated: Translated<Languages, TranslationKeys>) -> String {
+///   translated.t(TranslationKeys::Hello)
+/// }
+///
+/// let app = Router::new().route("/", get(hello)).with_state(shared_translator);
+/// ```
+pub struct Translated<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+{
+  translator: SharedTranslator<LanguageGeneric, TranslationKeyGeneric>,
+  language: String,
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translated<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// The loaded language negotiated for the request.
+  pub fn language(&self) -> &str {
+    &self.language
+  }
+
+  /// Translates a message without arguments into the language of the request.
+  pub fn t(&self, key: TranslationKeyGeneric) -> String {
+    self
+      .translator
+      .read()
+      .translate_str(&self.language, key)
+      .build()
+      .into_owned()
+  }
+
+  /// Translates a message with arguments into the language of the request.
+  pub fn t_args(&self, key: TranslationKeyGeneric, args: FluentArgs) -> String {
+    self
+      .translator
+      .read()
+      .translate_str(&self.language, key)
+      .with_args(args)
+      .build()
+      .into_owned()
+  }
+}
+
+#[async_trait]
+impl<State, LanguageGeneric, TranslationKeyGeneric> FromRequestParts<State>
+  for Translated<LanguageGeneric, TranslationKeyGeneric>
+where
+  State: Send + Sync,
+  SharedTranslator<LanguageGeneric, TranslationKeyGeneric>: FromRef<State>,
+  LanguageGeneric: Language + Send + Sync,
+  TranslationKeyGeneric: TranslationKey + Send + Sync,
+{
+  type Rejection = Infallible;
+
+  async fn from_request_parts(parts: &mut Parts, state: &State) -> Result<Self, Self::Rejection> {
+    let translator = SharedTranslator::from_ref(state);
+
+    let requested = parts
+      .headers
+      .get(ACCEPT_LANGUAGE)
+      .and_then(|header| header.to_str().ok())
+      .map(accepted_languages)
+      .unwrap_or_default();
+
+    let language = translator.read().negotiate_language(&requested).to_string();
+
+    Ok(Self {
+      translator,
+      language,
+    })
+  }
+}
+
+/// Languages of an `Accept-Language` header from most to least preferred, such as
+/// `es-ES,es;q=0.9,en;q=0.8`, leaving out the `*` wildcard and languages with a quality of 0.
+fn accepted_languages(header: &str) -> Vec<&str> {
+  let mut languages: Vec<(&str, f32)> = header
+    .split(',')
+    .filter_map(|entry| {
+      let mut parts = entry.split(';');
+      let language = parts.next()?.trim();
+
+      let quality = parts
+        .find_map(|parameter| parameter.trim().strip_prefix("q="))
+        .map_or(Some(1.0), |quality| quality.trim().parse().ok())?;
+
+      (!language.is_empty() && language != "*" && quality > 0.0).then_some((language, quality))
+    })
+    .collect();
+
+  // Stable, so languages with the same quality keep the order they were sent in.
+  languages.sort_by(|(_, first), (_, second)| second.total_cmp(first));
+
+  languages
+    .into_iter()
+    .map(|(language, _)| language)
+    .collect()
+}
@@ -0,0 +1,101 @@
+use crate::{Language, TextDirection, TranslationKey, Translator};
+
+use std::borrow::Cow;
+
+/// First strong isolate, which takes the direction of the first strong character of the text after it.
+const FIRST_STRONG_ISOLATE: char = '\u{2068}';
+const POP_DIRECTIONAL_ISOLATE: char = '\u{2069}';
+
+/// Ranges of the scripts written right-to-left: Hebrew, Arabic, Syriac, Thaana, N'Ko, Samaritan, Mandaic
+/// and their presentation forms, along with the historic scripts of the supplementary planes.
+const RIGHT_TO_LEFT_RANGES: [(char, char); 5] = [
+  ('\u{0590}', '\u{08FF}'),
+  ('\u{FB1D}', '\u{FDFF}'),
+  ('\u{FE70}', '\u{FEFF}'),
+  ('\u{10800}', '\u{10FFF}'),
+  ('\u{1E800}', '\u{1EFFF}'),
+];
+
+/// ### Description
+/// Returns the direction of the first strong character of a text, the one Unicode uses to lay it out, or
+/// `None` when it has no letters, such as numbers and punctuation.
+/// ### Usage
+/// ```ignore
+/// ...
+/// assert_eq!(text_direction("مرحبا Alex"), Some(TextDirection::RightToLeft));
+/// assert_eq!(text_direction("42"), None);
+/// ```
+pub fn text_direction(text: &str) -> Option<TextDirection> {
+  text
+    .chars()
+    .find(|character| character.is_alphabetic())
+    .map(|character| {
+      let is_right_to_left = RIGHT_TO_LEFT_RANGES
+        .iter()
+        .any(|(start, end)| (*start..=*end).contains(&character));
+
+      if is_right_to_left {
+        TextDirection::RightToLeft
+      } else {
+        TextDirection::LeftToRight
+      }
+    })
+}
+
+/// ### Description
+/// Wraps text in Unicode isolation marks (U+2068 and U+2069), the same way Fluent isolates placeables, so
+/// it keeps its own direction wherever it is placed.
+/// ### Usage
+/// ```ignore
+/// ...
+/// assert_eq!(isolate("Alex"), "\u{2068}Alex\u{2069}");
+/// ```
+pub fn isolate(text: &str) -> String {
+  format!("{FIRST_STRONG_ISOLATE}{text}{POP_DIRECTIONAL_ISOLATE}")
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Isolates an argument written in the opposite direction of the language, such as a Latin username in
+  /// an Arabic message, when the language does not isolate placeables already following the
+  /// `BidiIsolation` of the translator. Other arguments are returned as they are, so isolation marks are
+  /// never doubled nor added where they are not needed.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let name = translator.isolate_argument(&Languages::Arabic, user.name());
+  /// let message = translator.translate(&Languages::Arabic, TranslationKeys::Welcome).add_argument("name", name);
+  /// ```
+  pub fn isolate_argument<'text>(
+    &self,
+    language: &LanguageGeneric,
+    text: &'text str,
+  ) -> Cow<'text, str> {
+    let language = self.resolve_language(language.as_str());
+
+    let isolates_placeables = self
+      .translations
+      .bundle(language)
+      .and_then(|bundle| bundle.locales.first())
+      .is_some_and(|language_identifier| self.options.bidi_isolation.isolates(language_identifier));
+
+    let language_direction = self
+      .language_metadata(language)
+      .map_or(TextDirection::LeftToRight, |metadata| metadata.direction);
+    let is_right_to_left = |direction| direction == TextDirection::RightToLeft;
+
+    match text_direction(text) {
+      Some(direction)
+        if !isolates_placeables
+          && is_right_to_left(direction) != is_right_to_left(language_direction) =>
+      {
+        Cow::Owned(isolate(text))
+      }
+      _ => Cow::Borrowed(text),
+    }
+  }
+}
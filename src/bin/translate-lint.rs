@@ -0,0 +1,183 @@
+//! Lints a translations directory: syntax errors, messages defined twice, broken references, placeholders
+//! which differ from the default language and untranslated messages. Exits with 1 when problems are found.
+//! The `unused` subcommand instead reports messages no string literal of the given Rust sources refers to.
+//!
+//! Usage: translate-lint <directory> <default-language> [--allow-missing]
+//!        translate-lint unused <directory> <default-language> <source-directory>...
+
+use std::{collections::HashSet, env, fs, path::Path, process::ExitCode};
+
+use translate::{AuditIssue, AuditIssueKind, Language, TranslationKey, Translator};
+
+const USAGE: &str = "Usage: translate-lint <directory> <default-language> [--allow-missing]
+       translate-lint unused <directory> <default-language> <source-directory>...";
+
+/// Languages and keys are only known at runtime, so the default language code is leaked once to get the
+/// `&'static str` the traits need.
+struct Code(&'static str);
+
+impl Language for Code {
+  fn as_str(&self) -> &'static str {
+    self.0
+  }
+}
+
+impl TranslationKey for Code {
+  fn as_str(&self) -> &'static str {
+    self.0
+  }
+}
+
+fn main() -> ExitCode {
+  let arguments: Vec<String> = env::args().skip(1).collect();
+  let allow_missing = arguments
+    .iter()
+    .any(|argument| argument == "--allow-missing");
+  let positional: Vec<&String> = arguments
+    .iter()
+    .filter(|argument| !argument.starts_with("--"))
+    .collect();
+
+  match positional.as_slice() {
+    [command, directory, default_language, source_directories @ ..]
+      if command.as_str() == "unused" && !source_directories.is_empty() =>
+    {
+      unused(directory, default_language, source_directories)
+    }
+    [directory, default_language] => lint(directory, default_language, allow_missing),
+    _ => {
+      eprintln!("{USAGE}");
+      ExitCode::from(2)
+    }
+  }
+}
+
+fn load(directory: &str, default_language: &str) -> Option<Translator<Code, Code>> {
+  let default_language = Code(Box::leak(default_language.to_string().into_boxed_str()));
+
+  match Translator::<Code, Code>::builder()
+    .directory(directory)
+    .default_language(&default_language)
+    .build()
+  {
+    Ok(translator) => Some(translator),
+    Err(error) => {
+      eprintln!("error: {error}");
+      None
+    }
+  }
+}
+
+fn lint(directory: &str, default_language: &str, allow_missing: bool) -> ExitCode {
+  let Some(translator) = load(directory, default_language) else {
+    return ExitCode::from(2);
+  };
+
+  let issues = translator.audit();
+
+  for issue in &issues {
+    println!("error: {}", describe(issue));
+  }
+
+  let mut missing_count = 0;
+
+  for coverage in translator.coverage() {
+    println!(
+      "{}: {:.1}% translated ({}/{})",
+      coverage.language,
+      coverage.percentage(),
+      coverage.translated,
+      coverage.total
+    );
+
+    for key in &coverage.missing_keys {
+      let level = if allow_missing { "warning" } else { "error" };
+      println!("{level}: {} {key}: missing translation", coverage.language);
+    }
+
+    missing_count += coverage.missing_keys.len();
+  }
+
+  if issues.is_empty() && (allow_missing || missing_count == 0) {
+    ExitCode::SUCCESS
+  } else {
+    ExitCode::FAILURE
+  }
+}
+
+fn unused(directory: &str, default_language: &str, source_directories: &[&String]) -> ExitCode {
+  let Some(translator) = load(directory, default_language) else {
+    return ExitCode::from(2);
+  };
+
+  let mut literals = HashSet::new();
+
+  for source_directory in source_directories {
+    if let Err(error) = collect_literals(Path::new(source_directory), &mut literals) {
+      eprintln!("error: {source_directory}: {error}");
+      return ExitCode::from(2);
+    }
+  }
+
+  let unused_keys = translator.unused_keys(literals.iter().map(String::as_str));
+
+  for key in &unused_keys {
+    println!("warning: {default_language} {key}: not referenced by the sources");
+  }
+
+  if unused_keys.is_empty() {
+    ExitCode::SUCCESS
+  } else {
+    ExitCode::FAILURE
+  }
+}
+
+/// Collects the string literals of every Rust file of a directory, which includes the keys returned by
+/// `TranslationKey::as_str` implementations and `#[translate(key = "...")]` attributes. Keys derived from
+/// variant names are not literals, use `Translator::unused_keys` with the key enum for those. Escapes are not
+/// interpreted, which never matters for message identifiers.
+fn collect_literals(path: &Path, literals: &mut HashSet<String>) -> std::io::Result<()> {
+  if path.is_dir() {
+    for entry in fs::read_dir(path)? {
+      collect_literals(&entry?.path(), literals)?;
+    }
+  } else if path.extension().is_some_and(|extension| extension == "rs") {
+    let source = fs::read_to_string(path)?;
+
+    literals.extend(
+      source
+        .split('"')
+        .skip(1)
+        .step_by(2)
+        .map(ToString::to_string),
+    );
+  }
+
+  Ok(())
+}
+
+fn describe(issue: &AuditIssue) -> String {
+  let AuditIssue {
+    language,
+    key,
+    kind,
+  } = issue;
+
+  match kind {
+    AuditIssueKind::ParseRecovered {
+      file_name,
+      line,
+      detail,
+    } => format!("{language}/{file_name}:{line}: {detail}"),
+    AuditIssueKind::DuplicateDefinition { file_name } => {
+      format!("{language}/{file_name}: {key} is already defined by a previous file")
+    }
+    AuditIssueKind::UndefinedReference { reference } => {
+      format!("{language} {key}: references undefined {reference}")
+    }
+    AuditIssueKind::ArgumentsDiffer { missing, extra } => format!(
+      "{language} {key}: placeholders differ from the default language, missing {missing:?}, unknown {extra:?}"
+    ),
+    AuditIssueKind::FormatError { detail } => format!("{language} {key}: {detail}"),
+  }
+}
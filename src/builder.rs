@@ -0,0 +1,891 @@
+use crate::{
+  global_args::GlobalArgs, observer::Observer, Language, LogLevel, TranslationKey,
+  TranslationObserver, Translator, TranslatorError,
+};
+
+use std::{borrow::Cow, fmt, marker::PhantomData, path::Path, sync::Arc};
+
+use fluent_bundle::{FluentArgs, FluentValue};
+use unic_langid::{CharacterDirection, LanguageIdentifier};
+
+type LanguageParserFn = dyn Fn(&str) -> Option<LanguageIdentifier> + Send + Sync;
+type FileFilterFn = dyn Fn(&str) -> bool + Send + Sync;
+type MissingMessageHandlerFn = dyn Fn(&str, &str) -> String + Send + Sync;
+/// Post-processes every text fragment of the patterns being formatted, such as applying smart quotes.
+/// Arguments and the results of functions are not passed through it.
+pub type TextTransform = fn(&str) -> Cow<'_, str>;
+pub(crate) type FluentFunctionFn =
+  dyn for<'value> Fn(&[FluentValue<'value>], &FluentArgs) -> FluentValue<'value> + Send + Sync;
+
+/// Turns the name of a language directory or file into the identifier its bundle is created with.
+#[derive(Clone)]
+pub(crate) struct LanguageParser(Arc<LanguageParserFn>);
+
+impl LanguageParser {
+  pub(crate) fn parse(&self, language_name: &str) -> Option<LanguageIdentifier> {
+    (self.0)(language_name)
+  }
+}
+
+impl Default for LanguageParser {
+  fn default() -> Self {
+    Self(Arc::new(|language_name| language_name.parse().ok()))
+  }
+}
+
+impl fmt::Debug for LanguageParser {
+  fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    formatter.write_str("LanguageParser")
+  }
+}
+
+/// Decides which files of language and common directories are loaded, by their name relative to the directory.
+#[derive(Clone)]
+pub(crate) struct FileFilter(Arc<FileFilterFn>);
+
+impl FileFilter {
+  pub(crate) fn accepts(&self, file_name: &str) -> bool {
+    (self.0)(file_name)
+  }
+
+  fn extensions(extensions: &[&str]) -> Self {
+    let extensions: Vec<String> = extensions
+      .iter()
+      .map(|extension| extension.trim_start_matches('.').to_string())
+      .collect();
+
+    Self(Arc::new(move |file_name| {
+      Path::new(file_name).extension().is_some_and(|extension| {
+        extensions
+          .iter()
+          .any(|accepted| extension == accepted.as_str())
+      })
+    }))
+  }
+}
+
+impl Default for FileFilter {
+  /// Key-value files with the `serde` feature and gettext files with the `gettext` feature are converted
+  /// into Fluent when loaded.
+  fn default() -> Self {
+    #[allow(unused_mut)]
+    let mut extensions = vec!["ftl"];
+
+    #[cfg(feature = "serde")]
+    extensions.extend(crate::KeyValueFormat::EXTENSIONS);
+    #[cfg(feature = "gettext")]
+    extensions.push(crate::gettext::PO_EXTENSION);
+
+    Self::extensions(&extensions)
+  }
+}
+
+impl fmt::Debug for FileFilter {
+  fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    formatter.write_str("FileFilter")
+  }
+}
+
+/// Decides which languages of the translations directory are loaded, by their name.
+#[derive(Debug, Clone, Default)]
+pub(crate) enum LanguageFilter {
+  #[default]
+  All,
+  Only(Vec<String>),
+  Except(Vec<String>),
+}
+
+impl LanguageFilter {
+  pub(crate) fn accepts(&self, language_name: &str) -> bool {
+    match self {
+      Self::All => true,
+      Self::Only(languages) => languages.iter().any(|language| language == language_name),
+      Self::Except(languages) => !languages.iter().any(|language| language == language_name),
+    }
+  }
+
+  /// Makes sure a language is loaded whatever the filter, used for the default language.
+  fn keep(&mut self, language_name: &str) {
+    match self {
+      Self::All => {}
+      Self::Only(languages) => {
+        if !languages.iter().any(|language| language == language_name) {
+          languages.push(language_name.to_string());
+        }
+      }
+      Self::Except(languages) => languages.retain(|language| language != language_name),
+    }
+  }
+}
+
+/// What happens when a file defines a message or term an earlier file of the same language already defined.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConflictPolicy {
+  /// Keeps the first definition and logs the conflict, or fails to load in strict mode.
+  #[default]
+  KeepFirst,
+  /// Keeps the last definition without reporting the conflict, so later files can override earlier ones.
+  /// Common files are still overridden by the files of the language.
+  KeepLast,
+  /// Fails to load with `LoadErrors`, even when not in strict mode.
+  Error,
+}
+
+/// What translating returns for a message which can not be translated, because its key does not exist in
+/// any language, it has no value or formatting it fails.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MissingMessagePolicy {
+  /// Returns `TRANSLATION_FAILED`.
+  #[default]
+  FailureText,
+  /// Returns the translation key, such as `hello_with_arguments`.
+  Key,
+  /// Returns the message formatted in the default language, or the key when that fails as well.
+  DefaultLanguage,
+  /// Returns an empty string.
+  Empty,
+}
+
+/// What happens when a message is given arguments it does not use, which usually means an argument name
+/// has a typo. Global arguments are not checked, as most messages do not use them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnknownArgumentPolicy {
+  /// Ignores them, the same way Fluent does.
+  #[default]
+  Ignore,
+  /// Logs them and keeps translating.
+  Warn,
+  /// Logs them and fails to translate with `UnknownArguments`, so `build` returns the fallback text.
+  Error,
+}
+
+/// Which languages wrap placeables in Unicode isolation marks (U+2068 and U+2069), so arguments written in
+/// another direction, such as a Latin username inside an Arabic message, do not reorder the text around them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BidiIsolation {
+  /// Every language isolates placeables, the same way Fluent does.
+  #[default]
+  Always,
+  /// Only right-to-left languages isolate placeables, so left-to-right output such as logs and terminals
+  /// has no invisible marks. `isolate_argument` can isolate single arguments of other languages.
+  RightToLeft,
+  /// No language isolates placeables.
+  Never,
+}
+
+impl BidiIsolation {
+  /// Whether the bundle of a language isolates its placeables.
+  pub(crate) fn isolates(&self, language_identifier: &LanguageIdentifier) -> bool {
+    match self {
+      Self::Always => true,
+      Self::RightToLeft => language_identifier.character_direction() == CharacterDirection::RTL,
+      Self::Never => false,
+    }
+  }
+}
+
+/// Builds the text of a message which can not be translated from its language and key.
+#[derive(Clone)]
+pub(crate) struct MissingMessageHandler(Arc<MissingMessageHandlerFn>);
+
+impl MissingMessageHandler {
+  pub(crate) fn handle(&self, language: &str, key: &str) -> String {
+    (self.0)(language, key)
+  }
+}
+
+impl fmt::Debug for MissingMessageHandler {
+  fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    formatter.write_str("MissingMessageHandler")
+  }
+}
+
+/// Custom Fluent functions added to every bundle, by name.
+#[derive(Clone, Default)]
+pub(crate) struct FluentFunctions(pub(crate) Vec<(String, Arc<FluentFunctionFn>)>);
+
+impl fmt::Debug for FluentFunctions {
+  fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    formatter
+      .debug_list()
+      .entries(self.0.iter().map(|(name, _)| name))
+      .finish()
+  }
+}
+
+/// Options set through [`TranslatorBuilder`] which are kept by the translator once built.
+#[derive(Debug, Clone)]
+pub(crate) struct TranslatorOptions {
+  pub(crate) miss_level: LogLevel,
+  pub(crate) dev_overlay_env: Option<String>,
+  pub(crate) layers: Vec<String>,
+  pub(crate) language_parser: LanguageParser,
+  pub(crate) bidi_isolation: BidiIsolation,
+  pub(crate) transform: Option<TextTransform>,
+  pub(crate) strict: bool,
+  pub(crate) functions: FluentFunctions,
+  pub(crate) global_args: GlobalArgs,
+  pub(crate) parallel_loading: bool,
+  pub(crate) lazy_loading: bool,
+  pub(crate) common_directory: Option<String>,
+  pub(crate) file_filter: FileFilter,
+  pub(crate) language_filter: LanguageFilter,
+  pub(crate) format_cache_capacity: Option<usize>,
+  pub(crate) namespace_separator: Option<String>,
+  pub(crate) conflict_policy: ConflictPolicy,
+  pub(crate) missing_message_policy: MissingMessagePolicy,
+  pub(crate) unknown_argument_policy: UnknownArgumentPolicy,
+  pub(crate) missing_message_handler: Option<MissingMessageHandler>,
+  pub(crate) observer: Option<Observer>,
+  pub(crate) pseudo_languages: Vec<String>,
+}
+
+impl Default for TranslatorOptions {
+  fn default() -> Self {
+    Self {
+      miss_level: LogLevel::Warn,
+      dev_overlay_env: None,
+      layers: Vec::new(),
+      language_parser: LanguageParser::default(),
+      bidi_isolation: BidiIsolation::default(),
+      transform: None,
+      strict: false,
+      functions: FluentFunctions::default(),
+      global_args: GlobalArgs::default(),
+      parallel_loading: true,
+      lazy_loading: false,
+      common_directory: Some("common".to_string()),
+      file_filter: FileFilter::default(),
+      language_filter: LanguageFilter::default(),
+      format_cache_capacity: None,
+      namespace_separator: None,
+      conflict_policy: ConflictPolicy::default(),
+      missing_message_policy: MissingMessagePolicy::default(),
+      unknown_argument_policy: UnknownArgumentPolicy::default(),
+      missing_message_handler: None,
+      observer: None,
+      pseudo_languages: Vec::new(),
+    }
+  }
+}
+
+pub struct TranslatorBuilder<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+{
+  directory_path: Option<String>,
+  default_language: Option<&'static str>,
+  options: TranslatorOptions,
+  phantom: PhantomData<(LanguageGeneric, TranslationKeyGeneric)>,
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Default
+  for TranslatorBuilder<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric>
+  TranslatorBuilder<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Creates a builder to configure a translator before loading it.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = TranslatorBuilder::<Languages, TranslationKeys>::new()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .with_miss_level(tracing::Level::DEBUG)
+  ///   .build()?;
+  /// ```
+  pub fn new() -> Self {
+    Self {
+      directory_path: None,
+      default_language: None,
+      options: TranslatorOptions::default(),
+      phantom: PhantomData,
+    }
+  }
+
+  /// Sets the directory translations are loaded from.
+  pub fn directory(mut self, directory_path: &str) -> Self {
+    self.directory_path = Some(directory_path.to_string());
+    self
+  }
+
+  /// Sets the language used when a language or a message is not available.
+  pub fn default_language(mut self, default_language: &LanguageGeneric) -> Self {
+    self.default_language = Some(default_language.as_str());
+    self
+  }
+
+  /// Sets the level at which missing keys and fallbacks to the default language are logged, `WARN` by default.
+  /// Accepts a [`LogLevel`] as well as a `tracing::Level` or, with the `log` feature, a `log::Level`.
+  pub fn with_miss_level<Level>(mut self, level: Level) -> Self
+  where
+    Level: Into<LogLevel>,
+  {
+    self.options.miss_level = level.into();
+    self
+  }
+
+  /// ### Description
+  /// Loads a directory laid out like the translations directory on top of it, replacing the messages it
+  /// defines and keeping the rest, such as the wording of a single customer. Layers are applied in the order
+  /// they are added, so later layers win.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("translations/base")
+  ///   .default_language(&Languages::English)
+  ///   .with_layer("translations/brands/acme")
+  ///   .build()?;
+  /// ```
+  pub fn with_layer(mut self, directory_path: &str) -> Self {
+    self.options.layers.push(directory_path.to_string());
+    self
+  }
+
+  /// ### Description
+  /// Loads translations from the directory named by an environment variable after the base translations and
+  /// their layers, replacing the messages they define. Lets developers tweak strings locally without touching
+  /// committed files. Nothing happens when the variable is not set or does not point to a directory.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// // TRANSLATE_OVERLAY_DIR=~/my-overlay cargo run
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .with_dev_overlay_from_env("TRANSLATE_OVERLAY_DIR")
+  ///   .build()?;
+  /// ```
+  pub fn with_dev_overlay_from_env(mut self, variable: &str) -> Self {
+    self.options.dev_overlay_env = Some(variable.to_string());
+    self
+  }
+
+  /// ### Description
+  /// Sets how language directory and file names are turned into language identifiers, for projects using codes
+  /// which are not valid BCP-47, such as `zh_Hans_CN`. Returning `None` skips the language.
+  /// Translations are still looked up by the original name. Defaults to parsing the name as a BCP-47 identifier.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .with_language_parser(|name| name.replace('_', "-").parse().ok())
+  ///   .build()?;
+  /// ```
+  pub fn with_language_parser<Parser>(mut self, parser: Parser) -> Self
+  where
+    Parser: Fn(&str) -> Option<LanguageIdentifier> + Send + Sync + 'static,
+  {
+    self.options.language_parser = LanguageParser(Arc::new(parser));
+    self
+  }
+
+  /// ### Description
+  /// Sets whether placeables are wrapped in Unicode isolation marks (U+2068 and U+2069), `true` by default.
+  /// Isolation keeps right-to-left arguments from garbling the surrounding text, but breaks exact string
+  /// comparisons of the output.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .use_isolating(false)
+  ///   .build()?;
+  /// ```
+  pub fn use_isolating(mut self, use_isolating: bool) -> Self {
+    self.options.bidi_isolation = if use_isolating {
+      BidiIsolation::Always
+    } else {
+      BidiIsolation::Never
+    };
+    self
+  }
+
+  /// ### Description
+  /// Sets which languages wrap placeables in Unicode isolation marks, `BidiIsolation::Always` by default.
+  /// Replaces `use_isolating`.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .with_bidi_isolation(BidiIsolation::RightToLeft)
+  ///   .build()?;
+  /// ```
+  pub fn with_bidi_isolation(mut self, bidi_isolation: BidiIsolation) -> Self {
+    self.options.bidi_isolation = bidi_isolation;
+    self
+  }
+
+  /// ### Description
+  /// HTML-escapes the string arguments of every call before they are placed into messages, for web pages
+  /// where user supplied values such as names end up in translated templates. Global arguments, numbers
+  /// and the text of the messages are not escaped. Disabled by default.
+  /// ### Usage
+  /// ```ignore
+  /// // welcome = Welcome, <b>{ $name }</b>
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .escape_html_arguments(true)
+  ///   .build()?;
+  ///
+  /// let message = translator.translate(&Languages::English, TranslationKeys::Welcome).add_argument("name", "<script>");
+  ///
+  /// assert_eq!(message.build(), "Welcome, <b>\u{2068}&lt;script&gt;\u{2069}</b>");
+  /// ```
+  pub fn escape_html_arguments(mut self, escape_html_arguments: bool) -> Self {
+    self.options.global_args.escape_html = escape_html_arguments;
+    self
+  }
+
+  /// ### Description
+  /// Sets a function every text fragment of the patterns is passed through when formatting, such as to
+  /// apply smart quotes or strip markdown for plain text contexts. Arguments and the results of functions
+  /// are not passed through it, and pseudo languages apply their own transformation on top.
+  /// ### Usage
+  /// ```ignore
+  /// fn smart_quotes(text: &str) -> Cow<'_, str> {
+  ///   if text.contains('"') {
+  ///     return Cow::Owned(text.replacen('"', "\u{201C}", 1).replacen('"', "\u{201D}", 1));
+  ///   }
+  ///   Cow::Borrowed(text)
+  /// }
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .with_transform(smart_quotes)
+  ///   .build()?;
+  /// ```
+  pub fn with_transform(mut self, transform: TextTransform) -> Self {
+    self.options.transform = Some(transform);
+    self
+  }
+
+  /// ### Description
+  /// Makes `build` fail with `LoadErrors` when a translation file has syntax errors or redefines a message,
+  /// instead of logging the problem and skipping the broken entries. `false` by default.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let result = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .strict(true)
+  ///   .build();
+  ///
+  /// if let Err(TranslatorError::LoadErrors(errors)) = result {
+  ///   errors.iter().for_each(|error| eprintln!("{error}"));
+  /// }
+  /// ```
+  pub fn strict(mut self, strict: bool) -> Self {
+    self.options.strict = strict;
+    self
+  }
+
+  /// ### Description
+  /// Sets what happens when a file redefines a message or term of an earlier file of the same language,
+  /// `ConflictPolicy::KeepFirst` by default. Files are ordered by name, so with `ConflictPolicy::KeepLast`
+  /// a `zz-brand.ftl` file can override the base translations of its language.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .with_conflict_policy(ConflictPolicy::KeepLast)
+  ///   .build()?;
+  /// ```
+  pub fn with_conflict_policy(mut self, conflict_policy: ConflictPolicy) -> Self {
+    self.options.conflict_policy = conflict_policy;
+    self
+  }
+
+  /// ### Description
+  /// Sets what translating returns for messages which can not be translated, instead of `TRANSLATION_FAILED`.
+  /// Methods returning `Option` or `Result` are not affected.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .with_missing_message_policy(MissingMessagePolicy::Key)
+  ///   .build()?;
+  ///
+  /// assert_eq!(translator.translate_without_arguments(&Languages::English, TranslationKeys::Missing), "missing");
+  /// ```
+  pub fn with_missing_message_policy(mut self, policy: MissingMessagePolicy) -> Self {
+    self.options.missing_message_policy = policy;
+    self
+  }
+
+  /// ### Description
+  /// Sets what happens when a message is given arguments it does not use, including through the messages
+  /// it references, ignored by default. Useful in development and tests to catch misspelled argument names.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .with_unknown_argument_policy(UnknownArgumentPolicy::Error)
+  ///   .build()?;
+  ///
+  /// let result = translator.translate(&Languages::English, TranslationKeys::HelloWithArguments)
+  ///   .add_argument("nmae", "Alex")
+  ///   .try_build();
+  ///
+  /// assert!(matches!(result, Err(TranslationError::UnknownArguments { .. })));
+  /// ```
+  pub fn with_unknown_argument_policy(mut self, policy: UnknownArgumentPolicy) -> Self {
+    self.options.unknown_argument_policy = policy;
+    self
+  }
+
+  /// ### Description
+  /// Builds the text of messages which can not be translated with a function receiving the loaded language
+  /// closest to the requested one and the key, `key.attribute` for attributes. Takes precedence over the
+  /// missing message policy.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .with_missing_message_handler(|language, key| {
+  ///     metrics.record_missing(language, key);
+  ///     String::new()
+  ///   })
+  ///   .build()?;
+  /// ```
+  pub fn with_missing_message_handler<Handler>(mut self, handler: Handler) -> Self
+  where
+    Handler: Fn(&str, &str) -> String + Send + Sync + 'static,
+  {
+    self.options.missing_message_handler = Some(MissingMessageHandler(Arc::new(handler)));
+    self
+  }
+
+  /// ### Description
+  /// Reports missing messages and formatting errors to a `TranslationObserver`, such as to count them in
+  /// metrics. Replaces any previous observer.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .with_observer(MissingCounter(counter))
+  ///   .build()?;
+  /// ```
+  pub fn with_observer<ObserverGeneric>(mut self, observer: ObserverGeneric) -> Self
+  where
+    ObserverGeneric: TranslationObserver + 'static,
+  {
+    self.options.observer = Some(Observer(Arc::new(observer)));
+    self
+  }
+
+  /// ### Description
+  /// Sets an argument available to every message unless the call passes one with the same name, the same
+  /// as calling `Translator::set_global_arg` on the built translator.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .with_global_arg("brand-name", "Acme")
+  ///   .with_global_arg("support-email", "help@acme.com")
+  ///   .build()?;
+  /// ```
+  pub fn with_global_arg<Value>(mut self, name: &str, value: Value) -> Self
+  where
+    Value: Into<FluentValue<'static>>,
+  {
+    self.options.global_args.set(name, value.into());
+    self
+  }
+
+  /// ### Description
+  /// Generates a pseudo-language from the default language once loaded, the same as calling
+  /// `Translator::enable_pseudo` on the built translator.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .with_pseudo_language("en-XA")
+  ///   .build()?;
+  /// ```
+  pub fn with_pseudo_language(mut self, language: &str) -> Self {
+    self.options.pseudo_languages.push(language.to_string());
+    self
+  }
+
+  /// ### Description
+  /// Adds a custom function every bundle can call from its messages, receiving the positional and named
+  /// arguments written in the placeable.
+  /// ### Usage
+  /// ```ignore
+  /// // download = Download for { PLATFORM() }
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .add_function("PLATFORM", |_positional, _named| FluentValue::from(std::env::consts::OS))
+  ///   .build()?;
+  /// ```
+  pub fn add_function<Function>(mut self, name: &str, function: Function) -> Self
+  where
+    Function: for<'value> Fn(&[FluentValue<'value>], &FluentArgs) -> FluentValue<'value>
+      + Send
+      + Sync
+      + 'static,
+  {
+    self
+      .options
+      .functions
+      .0
+      .push((name.to_string(), Arc::new(function)));
+    self
+  }
+
+  /// ### Description
+  /// Sets the name of the directory, next to the language directories, whose files are shared by every
+  /// language, `common` by default. Messages defined by a language take precedence over the common ones.
+  /// ### Usage
+  /// ```ignore
+  /// // translations/shared/brand.ftl, translations/en-US/main.ftl, translations/es-ES/main.ftl
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("translations")
+  ///   .default_language(&Languages::English)
+  ///   .with_common_directory("shared")
+  ///   .build()?;
+  /// ```
+  pub fn with_common_directory(mut self, directory_name: &str) -> Self {
+    self.options.common_directory = Some(directory_name.to_string());
+    self
+  }
+
+  /// ### Description
+  /// Sets the extensions of the files loaded from language and common directories, `ftl` by default.
+  /// Other files, such as `README.md` or editor swap files, are skipped.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .with_file_extensions(&["ftl", "fluent"])
+  ///   .build()?;
+  /// ```
+  pub fn with_file_extensions(mut self, extensions: &[&str]) -> Self {
+    self.options.file_filter = FileFilter::extensions(extensions);
+    self
+  }
+
+  /// ### Description
+  /// Sets which files of language and common directories are loaded, given their name relative to the
+  /// directory, such as `settings/profile.ftl`. Replaces the extension filter.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .with_file_filter(|file_name| file_name.ends_with(".ftl") && !file_name.starts_with("drafts/"))
+  ///   .build()?;
+  /// ```
+  pub fn with_file_filter<Filter>(mut self, filter: Filter) -> Self
+  where
+    Filter: Fn(&str) -> bool + Send + Sync + 'static,
+  {
+    self.options.file_filter = FileFilter(Arc::new(filter));
+    self
+  }
+
+  /// ### Description
+  /// Loads only the given languages of the translations directory, skipping the others. The default
+  /// language is always loaded. Replaces the languages skipped with `skip_languages`.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .languages(&["en-US", "es-ES"])
+  ///   .build()?;
+  /// ```
+  pub fn languages(mut self, languages: &[&str]) -> Self {
+    self.options.language_filter =
+      LanguageFilter::Only(languages.iter().map(ToString::to_string).collect());
+    self
+  }
+
+  /// ### Description
+  /// Skips the given languages of the translations directory, loading the others. The default language is
+  /// always loaded. Replaces the languages set with `languages`.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .skip_languages(&["tlh", "x-pirate"])
+  ///   .build()?;
+  /// ```
+  pub fn skip_languages(mut self, languages: &[&str]) -> Self {
+    self.options.language_filter =
+      LanguageFilter::Except(languages.iter().map(ToString::to_string).collect());
+    self
+  }
+
+  /// ### Description
+  /// Sets whether languages are parsed and bundled concurrently on scoped threads, `true` by default.
+  /// Speeds up loading large translation trees, disabling it keeps loading on the calling thread.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .parallel_loading(false)
+  ///   .build()?;
+  /// ```
+  pub fn parallel_loading(mut self, parallel_loading: bool) -> Self {
+    self.options.parallel_loading = parallel_loading;
+    self
+  }
+
+  /// ### Description
+  /// Sets whether languages other than the default one are only indexed when building, `false` by default.
+  /// Their files are read and parsed the first time they are used, so services with many languages but
+  /// traffic in a few of them do not pay for all of them upfront. Languages loaded on first use log their
+  /// syntax errors and conflicts instead of failing, whatever `strict` and the conflict policy are. With
+  /// `build_async`, indexing and the default language are read asynchronously while languages loaded on first
+  /// use are still read in place.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .lazy_loading(true)
+  ///   .build()?;
+  /// ```
+  pub fn lazy_loading(mut self, lazy_loading: bool) -> Self {
+    self.options.lazy_loading = lazy_loading;
+    self
+  }
+
+  /// ### Description
+  /// Caches up to `capacity` formatted argument-free messages per language, so services translating the
+  /// same messages many times per second skip the Fluent resolver. Disabled by default, a capacity of 0
+  /// disables it as well. See `Translator::format_cache_stats`.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .with_format_cache(1024)
+  ///   .build()?;
+  /// ```
+  pub fn with_format_cache(mut self, capacity: usize) -> Self {
+    self.options.format_cache_capacity = (capacity > 0).then_some(capacity);
+    self
+  }
+
+  /// ### Description
+  /// Prefixes the messages of every file with a namespace taken from its path inside the language
+  /// directory, so files of different teams can not clobber each other. With `-`, `not-found` in
+  /// `errors.ftl` becomes `errors-not-found` and `title` in `settings/profile.ftl` becomes
+  /// `settings-profile-title`. References to messages of the same file are prefixed too, terms are shared.
+  /// Fluent identifiers can not contain dots, so the separator may only contain `-` and `_`, otherwise
+  /// loading fails with `InvalidNamespaceSeparator`. Top level `<language>.ftl` files are not namespaced.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .with_file_namespaces("__")
+  ///   .build()?;
+  ///
+  /// let text = translator.translate_dynamic(&Languages::English, "errors__not-found");
+  /// ```
+  pub fn with_file_namespaces(mut self, separator: &str) -> Self {
+    self.options.namespace_separator = Some(separator.to_string());
+    self
+  }
+
+  /// ### Description
+  /// Loads the translations with the configured options.
+  pub fn build(
+    self,
+  ) -> Result<Translator<LanguageGeneric, TranslationKeyGeneric>, TranslatorError> {
+    let Some(directory_path) = self.directory_path else {
+      return Err(TranslatorError::NoDirectory);
+    };
+
+    let Some(default_language) = self.default_language else {
+      return Err(TranslatorError::NoDefaultLanuage);
+    };
+
+    let mut options = self.options;
+    options.language_filter.keep(default_language);
+
+    Translator::load(&directory_path, default_language, options)
+  }
+}
+
+#[cfg(feature = "tokio")]
+impl<LanguageGeneric, TranslationKeyGeneric>
+  TranslatorBuilder<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Loads the translations with the configured options, reading files with `tokio::fs`.
+  pub async fn build_async(
+    self,
+  ) -> Result<Translator<LanguageGeneric, TranslationKeyGeneric>, TranslatorError> {
+    let Some(directory_path) = self.directory_path else {
+      return Err(TranslatorError::NoDirectory);
+    };
+
+    let Some(default_language) = self.default_language else {
+      return Err(TranslatorError::NoDefaultLanuage);
+    };
+
+    let mut options = self.options;
+    options.language_filter.keep(default_language);
+
+    Translator::load_async(&directory_path, default_language, options).await
+  }
+}
@@ -0,0 +1,90 @@
+use crate::{
+  introspection::{defining_resource, entry_id},
+  loader::LoadedResource,
+  Language, TranslationKey, Translator,
+};
+
+use std::{collections::BTreeMap, ptr};
+
+/// A message or term defined more than once by the files of a language, along with the file whose
+/// definition is used. Common files and overlays are not reported, as they are meant to be replaced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DefinitionConflict {
+  pub language: String,
+  /// Identifier of the message, or of the term starting with `-`.
+  pub id: String,
+  /// File whose definition is used, which is an overlay file when one replaces every definition.
+  pub kept_file: String,
+  /// Files whose definitions are ignored, in load order.
+  pub ignored_files: Vec<String>,
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Reports every message and term defined more than once by the files of a language, sorted by language
+  /// and identifier. Files are loaded in name order, term files first, so the same definition wins on
+  /// every machine, following the conflict policy of the translator.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// for conflict in translator.conflicts() {
+  ///   println!(
+  ///     "{} in {}: {} wins over {:?}",
+  ///     conflict.id, conflict.language, conflict.kept_file, conflict.ignored_files
+  ///   );
+  /// }
+  /// ```
+  pub fn conflicts(&self) -> Vec<DefinitionConflict> {
+    let mut languages: Vec<&str> = self.translations.keys().collect();
+    languages.sort_unstable();
+
+    let mut conflicts = Vec::new();
+
+    for language in languages {
+      let Some(resources) = self.translations.resources(language) else {
+        continue;
+      };
+
+      let mut definitions: BTreeMap<String, Vec<&LoadedResource>> = BTreeMap::new();
+
+      for loaded_resource in resources
+        .iter()
+        .filter(|loaded_resource| !loaded_resource.common && !loaded_resource.overriding)
+      {
+        for id in loaded_resource.resource.entries().filter_map(entry_id) {
+          definitions.entry(id).or_default().push(loaded_resource);
+        }
+      }
+
+      for (id, mut definers) in definitions {
+        if definers.len() < 2 {
+          continue;
+        }
+
+        let Some(kept) = defining_resource(resources, &id, self.options.conflict_policy) else {
+          continue;
+        };
+
+        if let Some(index) = definers.iter().position(|definer| ptr::eq(*definer, kept)) {
+          definers.remove(index);
+        }
+
+        conflicts.push(DefinitionConflict {
+          language: language.to_string(),
+          id,
+          kept_file: kept.file_name.clone(),
+          ignored_files: definers
+            .into_iter()
+            .map(|definer| definer.file_name.clone())
+            .collect(),
+        });
+      }
+    }
+
+    conflicts
+  }
+}
@@ -0,0 +1,58 @@
+/// Identifiers start with a letter followed by letters, digits, `-` or `_`.
+pub(crate) fn is_identifier(id: &str) -> bool {
+  let mut characters = id.chars();
+
+  characters
+    .next()
+    .is_some_and(|first| first.is_ascii_alphabetic())
+    && characters
+      .all(|character| character.is_ascii_alphanumeric() || character == '-' || character == '_')
+}
+
+/// Turns text into a Fluent pattern, escaping what Fluent would parse as syntax. Lines after the first one
+/// are indented by `indent` spaces. `placeholder` reads the placeholders of the converted format at the
+/// start of the text, returning the Fluent to write for them and the rest of the text.
+pub(crate) fn pattern<'text>(
+  text: &'text str,
+  indent: usize,
+  mut placeholder: impl FnMut(&'text str) -> Option<(String, &'text str)>,
+) -> String {
+  let mut pattern = String::with_capacity(text.len());
+  let mut rest = text;
+  let mut line_start = true;
+
+  while let Some(character) = rest.chars().next() {
+    if let Some((fluent, remaining)) = placeholder(rest) {
+      pattern.push_str(&fluent);
+      rest = remaining;
+      line_start = false;
+      continue;
+    }
+
+    match character {
+      '{' | '}' => pattern.push_str(&format!("{{\"{character}\"}}")),
+      '[' | '*' | '.' if line_start => pattern.push_str(&format!("{{\"{character}\"}}")),
+      ' ' if line_start => pattern.push_str("{\" \"}"),
+      '\n' => {
+        pattern.push('\n');
+        pattern.push_str(&" ".repeat(indent));
+      }
+      '\r' => {}
+      character => pattern.push(character),
+    }
+
+    line_start = character == '\n';
+    rest = &rest[character.len_utf8()..];
+  }
+
+  if pattern.is_empty() {
+    return "{\"\"}".to_string();
+  }
+
+  pattern
+}
+
+/// Fluent for a placeholder of a converted format, a reference to the variable of the same name.
+pub(crate) fn variable(name: &str) -> String {
+  format!("{{ ${name} }}")
+}
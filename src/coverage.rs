@@ -0,0 +1,69 @@
+use crate::{Language, TranslationKey, Translator};
+
+use std::collections::BTreeSet;
+
+/// How much of the default language a language translates, returned by [`Translator::coverage`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageCoverage {
+  pub language: String,
+  /// Messages of the default language the language defines as well.
+  pub translated: usize,
+  /// Messages of the default language.
+  pub total: usize,
+  pub missing_keys: Vec<String>,
+  /// Messages the language defines which the default language does not.
+  pub extra_keys: Vec<String>,
+}
+
+impl LanguageCoverage {
+  /// Translated messages as a percentage of the default language messages, 100 when it has none.
+  pub fn percentage(&self) -> f64 {
+    if self.total == 0 {
+      return 100.0;
+    }
+
+    self.translated as f64 * 100.0 / self.total as f64
+  }
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Compares the messages of every loaded language against the default language, ordered by language.
+  /// Messages of common files and runtime overrides count as translated.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// for coverage in translator.coverage() {
+  ///   println!("{} is {:.0}% translated", coverage.language, coverage.percentage());
+  /// }
+  /// ```
+  pub fn coverage(&self) -> Vec<LanguageCoverage> {
+    let default_keys: BTreeSet<&str> = self.message_keys(self.default_language).collect();
+
+    self
+      .languages()
+      .map(|language| {
+        let keys: BTreeSet<&str> = self.message_keys(language).collect();
+
+        let to_strings = |keys: BTreeSet<&&str>| {
+          keys
+            .into_iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+        };
+
+        LanguageCoverage {
+          language: language.to_string(),
+          translated: default_keys.intersection(&keys).count(),
+          total: default_keys.len(),
+          missing_keys: to_strings(default_keys.difference(&keys).collect()),
+          extra_keys: to_strings(keys.difference(&default_keys).collect()),
+        }
+      })
+      .collect()
+  }
+}
@@ -0,0 +1,113 @@
+use crate::{Language, TranslationKey, Translator};
+
+use std::collections::HashMap;
+
+use unic_langid::LanguageIdentifier;
+
+/// Locales accepted by Discord for command names and descriptions.
+pub const DISCORD_LOCALES: &[&str] = &[
+  "id", "da", "de", "en-GB", "en-US", "es-ES", "es-419", "fr", "hr", "it", "lt", "hu", "nl", "no",
+  "pl", "pt-BR", "ro", "fi", "sv-SE", "vi", "tr", "cs", "el", "bg", "ru", "uk", "hi", "th",
+  "zh-CN", "ja", "zh-TW", "ko",
+];
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Builds the localization map expected by serenity and poise command builders, from Discord locale to
+  /// the message translated into the loaded language matching it. Discord locales without a matching
+  /// language, or whose language does not define the message, are left out instead of falling back.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let mut command = CreateCommand::new("ping").description("Check the latency");
+  ///
+  /// for (locale, name) in translator.discord_localizations(TranslationKeys::PingName) {
+  ///   command = command.name_localized(locale, name);
+  /// }
+  /// ```
+  pub fn discord_localizations(&self, key: TranslationKeyGeneric) -> HashMap<String, String> {
+    DISCORD_LOCALES
+      .iter()
+      .filter_map(|discord_locale| {
+        let language = self.discord_language(discord_locale)?;
+        let bundle = self.translations.bundle(language)?;
+        let message = bundle.get_message(key.as_str());
+
+        let text = self
+          .format_message_value(message, bundle, language, key.as_str(), None)
+          .ok()?;
+
+        Some((discord_locale.to_string(), text.into_owned()))
+      })
+      .collect()
+  }
+
+  /// ### Description
+  /// Returns the loaded language used for a Discord locale: the language with the same code, otherwise
+  /// one with the same script or region, otherwise one with the same base language, such as `es` for
+  /// `es-419` or `nb` for `no`. Languages written in another script are never used.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// assert_eq!(translator.discord_language("pt-BR"), Some("pt"));
+  /// ```
+  pub fn discord_language(&self, discord_locale: &str) -> Option<&str> {
+    let discord_identifier = discord_identifier(discord_locale)?;
+
+    let mut candidates: Vec<(&str, LanguageIdentifier)> = self
+      .languages()
+      .filter_map(|language| Some((language, language.parse().ok()?)))
+      .filter(|(_, identifier): &(&str, LanguageIdentifier)| {
+        let other_script = identifier.script.is_some()
+          && discord_identifier.script.is_some()
+          && identifier.script != discord_identifier.script;
+
+        base_language(identifier) == base_language(&discord_identifier) && !other_script
+      })
+      .collect();
+
+    candidates.sort_by_key(|(language, identifier)| {
+      let exact = identifier == &discord_identifier;
+      let same_region =
+        identifier.region.is_some() && identifier.region == discord_identifier.region;
+      let same_script =
+        identifier.script.is_some() && identifier.script == discord_identifier.script;
+      let generic = identifier.region.is_none() && identifier.script.is_none();
+
+      // Prefer the closest language, then the least specific one, then the shortest code.
+      (
+        !exact,
+        !(same_region || same_script),
+        !generic,
+        language.len(),
+      )
+    });
+
+    candidates.first().map(|(language, _)| *language)
+  }
+}
+
+/// Identifier of a Discord locale as Fluent languages are usually named, `no` being Norwegian Bokmål and
+/// the Chinese locales meaning simplified and traditional scripts.
+fn discord_identifier(discord_locale: &str) -> Option<LanguageIdentifier> {
+  let locale = match discord_locale {
+    "no" => "nb",
+    "zh-CN" => "zh-Hans-CN",
+    "zh-TW" => "zh-Hant-TW",
+    locale => locale,
+  };
+
+  locale.parse().ok()
+}
+
+/// Norwegian translations are as often named `no` as `nb`.
+fn base_language(identifier: &LanguageIdentifier) -> &str {
+  match identifier.language.as_str() {
+    "no" => "nb",
+    language => language,
+  }
+}
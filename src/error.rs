@@ -1,16 +1,140 @@
+use std::io::Error as IoError;
+
+use fluent_bundle::FluentError;
+use fluent_syntax::parser::ParserError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum TranslatorError {
-  #[error("[ReadirError]: An error has ocurred while trying to read directory {directory_path}.\nDetail: {detail}")]
+  #[error("[ReadirError]: An error has ocurred while trying to read directory {directory_path}.\nDetail: {source}")]
   ReadDirError {
     directory_path: String,
-    detail: String,
+    #[source]
+    source: IoError,
   },
+  #[error("[DirectoryNotFound]: Directory {path} does not exist, check the translations path.")]
+  DirectoryNotFound { path: String },
+  #[error(
+    "[DirectoryNotReadable]: Directory {path} could not be read because permission was denied."
+  )]
+  DirectoryNotReadable { path: String },
   #[error("[BundleResourceError]: An error has ocurred while adding a resource to bundle.")]
   BundleResourceError,
-  #[error("[DirEntryError]: An error has ocurred while reading directory data.\nDetail: {detail}")]
-  DirEntryError { detail: String },
+  #[error("[DirEntryError]: An error has ocurred while reading directory data.\nDetail: {source}")]
+  DirEntryError {
+    #[source]
+    source: IoError,
+  },
   #[error("[NoDefaultLanguage]: Default language has not been added to the translator")]
   NoDefaultLanuage,
+  #[error("[NoDirectory]: Translations directory has not been set on the builder")]
+  NoDirectory,
+  #[error("[UnknownLanguage]: {language} does not match any language")]
+  UnknownLanguage { language: String },
+  #[error("[DefaultLanguage]: {language} is the default language and can not be removed")]
+  DefaultLanguage { language: String },
+  #[error("[UnknownKey]: {key} does not match any translation key")]
+  UnknownKey { key: String },
+  #[error(
+    "[InvalidOverride]: Override for {key} is not a valid Fluent pattern.\nDetail: {detail}"
+  )]
+  InvalidOverride { key: String, detail: String },
+  #[cfg(feature = "notify")]
+  #[error("[WatchError]: An error has ocurred while trying to watch {path}.\nDetail: {source}")]
+  WatchError {
+    path: String,
+    #[source]
+    source: notify::Error,
+  },
+  #[cfg(feature = "serde")]
+  #[error("[InvalidKeyValueFile]: A {format:?} translation file could not be converted into Fluent.\nDetail: {detail}")]
+  InvalidKeyValueFile {
+    format: crate::KeyValueFormat,
+    detail: String,
+  },
+  #[cfg(feature = "gettext")]
+  #[error("[InvalidPoFile]: A gettext translation file could not be converted into Fluent.\nDetail: {detail}")]
+  InvalidPoFile { detail: String },
+  #[cfg(feature = "reqwest")]
+  #[error("[RemoteError]: Translations could not be fetched from {url}.\nDetail: {detail}")]
+  RemoteError { url: String, detail: String },
+  #[cfg(feature = "xliff")]
+  #[error(
+    "[InvalidXliffFile]: An XLIFF file could not be converted into Fluent.\nDetail: {detail}"
+  )]
+  InvalidXliffFile { detail: String },
+  #[error("[InvalidNamespaceSeparator]: {separator} can not separate namespaces, Fluent identifiers only allow - and _ besides letters and digits")]
+  InvalidNamespaceSeparator { separator: String },
+  #[error("[LoadErrors]: Translations could not be loaded.\n{}", join_load_errors(.0))]
+  LoadErrors(Vec<LoadError>),
+}
+
+/// Why a message could not be translated.
+#[derive(Debug, Error)]
+pub enum TranslationError {
+  #[error("[MessageNotFound]: {key} does not exist in the language nor in the default language")]
+  MessageNotFound { key: String },
+  #[error("[NoValue]: {key} has no value to format")]
+  NoValue { key: String },
+  #[error("[AttributeNotFound]: {key} has no attribute {attribute}")]
+  AttributeNotFound { key: String, attribute: String },
+  #[error("[ArgumentMismatch]: {key} is missing arguments {missing:?} and got unexpected arguments {extra:?}")]
+  ArgumentMismatch {
+    key: String,
+    missing: Vec<String>,
+    extra: Vec<String>,
+  },
+  #[error("[FormattingErrors]: {key} could not be formatted.\nDetail: {errors:?}")]
+  FormattingErrors {
+    key: String,
+    errors: Vec<FluentError>,
+  },
+  #[error("[UnknownArguments]: {key} does not use the arguments {arguments:?}")]
+  UnknownArguments { key: String, arguments: Vec<String> },
+  #[error("[WriteError]: {key} could not be written to the output")]
+  WriteError {
+    key: String,
+    /// The error of the `io::Write` target, if any.
+    #[source]
+    source: Option<IoError>,
+  },
+}
+
+/// A problem found in a translation file while loading it.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LoadError {
+  #[error("Syntax error in file {file_name} from language {language} at line {line}, column {column}: {source}")]
+  Parse {
+    language: String,
+    file_name: String,
+    /// Line of the error, starting at 1.
+    line: usize,
+    /// Column of the error in characters, starting at 1.
+    column: usize,
+    #[source]
+    source: ParserError,
+  },
+  #[error("File {file_name} from language {language} redefines {id}")]
+  Conflict {
+    language: String,
+    file_name: String,
+    id: String,
+  },
+  #[error(
+    "Message {key} in file {file_name} from language {language} references undefined term {term}"
+  )]
+  MissingTerm {
+    language: String,
+    file_name: String,
+    key: String,
+    term: String,
+  },
+}
+
+fn join_load_errors(errors: &[LoadError]) -> String {
+  errors
+    .iter()
+    .map(ToString::to_string)
+    .collect::<Vec<String>>()
+    .join("\n")
 }
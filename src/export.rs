@@ -0,0 +1,69 @@
+use crate::{Language, TranslationKey, Translator};
+
+use std::collections::BTreeMap;
+
+use fluent_bundle::FluentArgs;
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Formats every message a language defines into a map sorted by key, meant for golden tests that catch
+  /// accidental wording changes. Attributes are exported as `key.attribute`. The provider returns the
+  /// arguments of each key, and messages which fail to format keep the text Fluent falls back to, such as
+  /// `{$name}` for a missing argument, so the snapshot shows them. Fallback languages are not used.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let snapshot = translator.export(&Languages::Spanish, |key| match key {
+  ///   "hello_with_arguments" => Some(FluentArgs::from_iter([("name", "Alex")])),
+  ///   _ => None,
+  /// });
+  ///
+  /// insta::assert_yaml_snapshot!(snapshot);
+  /// ```
+  pub fn export<ArgsProvider>(
+    &self,
+    language: &LanguageGeneric,
+    args_provider: ArgsProvider,
+  ) -> BTreeMap<String, String>
+  where
+    ArgsProvider: Fn(&str) -> Option<FluentArgs<'static>>,
+  {
+    let mut exported = BTreeMap::new();
+
+    let Some(bundle) = self.translations.bundle(language.as_str()) else {
+      return exported;
+    };
+
+    let global_args = &self.options.global_args;
+
+    for key in self.message_keys(language.as_str()) {
+      let Some(message) = bundle.get_message(key) else {
+        continue;
+      };
+
+      let args = args_provider(key);
+      let mut errors = Vec::new();
+
+      if let Some(value) = message.value() {
+        let text = global_args.format_pattern(bundle, value, args.as_ref(), &mut errors);
+        exported.insert(key.to_string(), text.into_owned());
+      }
+
+      for attribute in message.attributes() {
+        let text =
+          global_args.format_pattern(bundle, attribute.value(), args.as_ref(), &mut errors);
+        exported.insert(format!("{key}.{}", attribute.id()), text.into_owned());
+      }
+
+      if !errors.is_empty() {
+        debug!("Exported {key} with formatting errors: {errors:?}");
+      }
+    }
+
+    exported
+  }
+}
@@ -0,0 +1,151 @@
+use crate::{Language, TranslationKey, Translator};
+
+use std::{
+  collections::{BTreeMap, HashMap},
+  sync::{Mutex, MutexGuard, PoisonError},
+};
+
+/// How often the format cache was used, returned by [`Translator::format_cache_stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FormatCacheStats {
+  pub hits: u64,
+  pub misses: u64,
+  /// Messages currently cached, across every language.
+  pub entries: usize,
+}
+
+/// Formatted argument-free messages, by requested language and key. Each language keeps at most `capacity`
+/// messages, dropping the least recently used one.
+#[derive(Debug)]
+pub(crate) struct FormatCache {
+  capacity: usize,
+  state: Mutex<CacheState>,
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+  languages: HashMap<&'static str, LeastRecentlyUsed>,
+  hits: u64,
+  misses: u64,
+}
+
+#[derive(Debug, Default)]
+struct LeastRecentlyUsed {
+  entries: HashMap<&'static str, (String, u64)>,
+  /// Keys by the tick they were last used at, the first one being the least recently used.
+  recency: BTreeMap<u64, &'static str>,
+  tick: u64,
+}
+
+impl LeastRecentlyUsed {
+  fn get(&mut self, key: &'static str) -> Option<String> {
+    let (text, last_used) = self.entries.get_mut(key)?;
+
+    self.tick += 1;
+    self.recency.remove(last_used);
+    self.recency.insert(self.tick, key);
+    *last_used = self.tick;
+
+    Some(text.clone())
+  }
+
+  fn insert(&mut self, key: &'static str, text: String, capacity: usize) {
+    self.tick += 1;
+
+    if let Some((_, last_used)) = self.entries.insert(key, (text, self.tick)) {
+      self.recency.remove(&last_used);
+    } else if self.entries.len() > capacity {
+      if let Some((_, evicted_key)) = self.recency.pop_first() {
+        self.entries.remove(evicted_key);
+      }
+    }
+
+    self.recency.insert(self.tick, key);
+  }
+}
+
+impl FormatCache {
+  pub(crate) fn new(capacity: usize) -> Self {
+    Self {
+      capacity,
+      state: Mutex::default(),
+    }
+  }
+
+  pub(crate) fn get(&self, language: &'static str, key: &'static str) -> Option<String> {
+    let mut state = self.lock();
+
+    let cached = state
+      .languages
+      .get_mut(language)
+      .and_then(|messages| messages.get(key));
+
+    match cached {
+      Some(_) => state.hits += 1,
+      None => state.misses += 1,
+    }
+
+    cached
+  }
+
+  pub(crate) fn insert(&self, language: &'static str, key: &'static str, text: String) {
+    let capacity = self.capacity;
+
+    self
+      .lock()
+      .languages
+      .entry(language)
+      .or_default()
+      .insert(key, text, capacity);
+  }
+
+  pub(crate) fn clear(&self) {
+    self.lock().languages.clear();
+  }
+
+  fn stats(&self) -> FormatCacheStats {
+    let state = self.lock();
+
+    FormatCacheStats {
+      hits: state.hits,
+      misses: state.misses,
+      entries: state
+        .languages
+        .values()
+        .map(|messages| messages.entries.len())
+        .sum(),
+    }
+  }
+
+  fn lock(&self) -> MutexGuard<'_, CacheState> {
+    self.state.lock().unwrap_or_else(PoisonError::into_inner)
+  }
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Returns how often the format cache enabled with `with_format_cache` was hit, or `None` when it is
+  /// not enabled.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// if let Some(stats) = translator.format_cache_stats() {
+  ///   println!("{} hits, {} misses", stats.hits, stats.misses);
+  /// }
+  /// ```
+  pub fn format_cache_stats(&self) -> Option<FormatCacheStats> {
+    self.format_cache.as_ref().map(FormatCache::stats)
+  }
+
+  /// Empties the format cache, keeping its statistics. Bundles changed through the translator, such as by
+  /// overrides, clear it already.
+  pub fn clear_format_cache(&self) {
+    if let Some(format_cache) = &self.format_cache {
+      format_cache.clear();
+    }
+  }
+}
@@ -0,0 +1,79 @@
+use crate::{Language, TranslationKey, Translator};
+
+use std::{borrow::Cow, collections::HashMap};
+
+use fluent_bundle::FluentArgs;
+
+/// The value and every attribute of a message, formatted at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormattedMessage<'lifetime> {
+  /// `None` for messages made only of attributes.
+  pub value: Option<Cow<'lifetime, str>>,
+  pub attributes: HashMap<&'lifetime str, Cow<'lifetime, str>>,
+}
+
+impl FormattedMessage<'_> {
+  /// The formatted attribute with the given name, if the message defines it.
+  pub fn attribute(&self, name: &str) -> Option<&str> {
+    self.attributes.get(name).map(AsRef::as_ref)
+  }
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Formats the value and every attribute of a message in a single lookup, such as the label, placeholder
+  /// and help text of a form field. Falls back like `translate` does, and returns `None` when the key does
+  /// not exist in any fallback or formatting the value or an attribute fails.
+  /// ### Usage
+  /// ```ignore
+  /// // email-input = Email
+  /// //     .placeholder = email@example.com
+  /// //     .help = We will never share it
+  /// ...
+  /// let field = translator.format_message(&Languages::English, TranslationKeys::EmailInput, None)?;
+  ///
+  /// assert_eq!(field.value.as_deref(), Some("Email"));
+  /// assert_eq!(field.attribute("placeholder"), Some("email@example.com"));
+  /// ```
+  pub fn format_message<'lifetime>(
+    &'lifetime self,
+    language: &LanguageGeneric,
+    key: TranslationKeyGeneric,
+    args: Option<&'lifetime FluentArgs>,
+  ) -> Option<FormattedMessage<'lifetime>> {
+    let (message, bundle, effective_language) =
+      self.resolve_message(language.as_str(), key.as_str());
+    let message = message?;
+
+    let global_args = &self.options.global_args;
+    let mut errors = Vec::new();
+
+    let value = message
+      .value()
+      .map(|value| global_args.format_pattern(bundle, value, args, &mut errors));
+
+    let attributes = message
+      .attributes()
+      .map(|attribute| {
+        let text = global_args.format_pattern(bundle, attribute.value(), args, &mut errors);
+        (attribute.id(), text)
+      })
+      .collect();
+
+    if !errors.is_empty() {
+      warn!(
+        "Translation failure(s) when translating {}: {:?}",
+        key.as_str(),
+        errors
+      );
+      self.report_format_error(effective_language, key.as_str(), &errors);
+      return None;
+    }
+
+    Some(FormattedMessage { value, attributes })
+  }
+}
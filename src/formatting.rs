@@ -0,0 +1,518 @@
+use crate::{list_format::list, Bundle};
+
+use std::borrow::Cow;
+
+use fluent_bundle::{
+  types::{FluentNumber, FluentNumberOptions, FluentNumberStyle, FluentType},
+  FluentArgs, FluentValue,
+};
+use intl_memoizer::{concurrent, IntlLangMemoizer, Memoizable};
+use unic_langid::LanguageIdentifier;
+
+/// Separators of CLDR decimal formats.
+pub(crate) struct NumberSymbols {
+  decimal: &'static str,
+  group: &'static str,
+  /// Digits the integer part needs before it is grouped, 4 digits are not grouped in Spanish or Polish.
+  minimum_grouping_digits: usize,
+  /// Whether the currency code goes before the number.
+  currency_first: bool,
+}
+
+const fn symbols(
+  decimal: &'static str,
+  group: &'static str,
+  currency_first: bool,
+) -> NumberSymbols {
+  NumberSymbols {
+    decimal,
+    group,
+    minimum_grouping_digits: 4,
+    currency_first,
+  }
+}
+
+/// CLDR number symbols, looked up by full locale first and then by language.
+const NUMBER_SYMBOLS: &[(&str, NumberSymbols)] = &[
+  ("en", symbols(".", ",", true)),
+  ("de", symbols(",", ".", false)),
+  ("de-CH", symbols(".", "’", true)),
+  (
+    "es",
+    NumberSymbols {
+      decimal: ",",
+      group: ".",
+      minimum_grouping_digits: 5,
+      currency_first: false,
+    },
+  ),
+  ("fr", symbols(",", "\u{202f}", false)),
+  ("it", symbols(",", ".", false)),
+  ("pt", symbols(",", ".", true)),
+  ("pt-PT", symbols(",", "\u{a0}", false)),
+  ("nl", symbols(",", ".", true)),
+  ("ca", symbols(",", ".", false)),
+  (
+    "pl",
+    NumberSymbols {
+      decimal: ",",
+      group: "\u{a0}",
+      minimum_grouping_digits: 5,
+      currency_first: false,
+    },
+  ),
+  ("cs", symbols(",", "\u{a0}", false)),
+  ("ru", symbols(",", "\u{a0}", false)),
+  ("uk", symbols(",", "\u{a0}", false)),
+  ("sv", symbols(",", "\u{a0}", false)),
+  ("da", symbols(",", ".", false)),
+  ("nb", symbols(",", "\u{a0}", false)),
+  ("fi", symbols(",", "\u{a0}", false)),
+  ("tr", symbols(",", ".", true)),
+  ("ja", symbols(".", ",", true)),
+  ("zh", symbols(".", ",", true)),
+  ("ko", symbols(".", ",", true)),
+  ("hi", symbols(".", ",", true)),
+  ("he", symbols(".", ",", false)),
+  ("ar", symbols(".", ",", false)),
+];
+
+const FALLBACK_NUMBER_SYMBOLS: NumberSymbols = symbols(".", ",", true);
+
+#[derive(Debug, Clone, Copy)]
+enum DateOrder {
+  MonthDayYear,
+  DayMonthYear,
+  YearMonthDay,
+}
+
+/// Numeric CLDR short date and time formats.
+struct DateTimePattern {
+  order: DateOrder,
+  separator: &'static str,
+  /// Whether days and months are padded to two digits.
+  padded: bool,
+  hour12: bool,
+}
+
+const fn date_pattern(order: DateOrder, separator: &'static str, padded: bool) -> DateTimePattern {
+  DateTimePattern {
+    order,
+    separator,
+    padded,
+    hour12: false,
+  }
+}
+
+/// CLDR short date patterns, looked up by full locale first and then by language.
+const DATE_TIME_PATTERNS: &[(&str, DateTimePattern)] = &[
+  (
+    "en",
+    DateTimePattern {
+      order: DateOrder::MonthDayYear,
+      separator: "/",
+      padded: false,
+      hour12: true,
+    },
+  ),
+  ("en-GB", date_pattern(DateOrder::DayMonthYear, "/", true)),
+  ("en-AU", date_pattern(DateOrder::DayMonthYear, "/", true)),
+  ("de", date_pattern(DateOrder::DayMonthYear, ".", true)),
+  ("es", date_pattern(DateOrder::DayMonthYear, "/", false)),
+  ("fr", date_pattern(DateOrder::DayMonthYear, "/", true)),
+  ("it", date_pattern(DateOrder::DayMonthYear, "/", true)),
+  ("pt", date_pattern(DateOrder::DayMonthYear, "/", true)),
+  ("nl", date_pattern(DateOrder::DayMonthYear, "-", false)),
+  ("ca", date_pattern(DateOrder::DayMonthYear, "/", false)),
+  ("pl", date_pattern(DateOrder::DayMonthYear, ".", true)),
+  ("cs", date_pattern(DateOrder::DayMonthYear, ". ", false)),
+  ("ru", date_pattern(DateOrder::DayMonthYear, ".", true)),
+  ("uk", date_pattern(DateOrder::DayMonthYear, ".", true)),
+  ("sv", date_pattern(DateOrder::YearMonthDay, "-", true)),
+  ("da", date_pattern(DateOrder::DayMonthYear, ".", false)),
+  ("nb", date_pattern(DateOrder::DayMonthYear, ".", true)),
+  ("fi", date_pattern(DateOrder::DayMonthYear, ".", false)),
+  ("tr", date_pattern(DateOrder::DayMonthYear, ".", true)),
+  ("ja", date_pattern(DateOrder::YearMonthDay, "/", true)),
+  ("zh", date_pattern(DateOrder::YearMonthDay, "/", false)),
+  ("ko", date_pattern(DateOrder::YearMonthDay, ". ", false)),
+  (
+    "hi",
+    DateTimePattern {
+      order: DateOrder::DayMonthYear,
+      separator: "/",
+      padded: false,
+      hour12: true,
+    },
+  ),
+  ("he", date_pattern(DateOrder::DayMonthYear, ".", false)),
+  ("ar", date_pattern(DateOrder::DayMonthYear, "/", false)),
+];
+
+/// ISO 8601 dates for languages without data.
+const FALLBACK_DATE_TIME_PATTERN: DateTimePattern =
+  date_pattern(DateOrder::YearMonthDay, "-", true);
+
+/// Formats of a locale, memoized by the bundle so they are only looked up once.
+struct LocaleFormats {
+  number_symbols: &'static NumberSymbols,
+  date_time_pattern: &'static DateTimePattern,
+}
+
+impl Memoizable for LocaleFormats {
+  type Args = ();
+  type Error = ();
+
+  fn construct(language_identifier: LanguageIdentifier, _args: ()) -> Result<Self, ()> {
+    Ok(Self {
+      number_symbols: number_symbols(&language_identifier),
+      date_time_pattern: find_locale_data(DATE_TIME_PATTERNS, &language_identifier)
+        .unwrap_or(&FALLBACK_DATE_TIME_PATTERN),
+    })
+  }
+}
+
+/// Adds `NUMBER`, `DATETIME` and `LIST` to a bundle and formats numbers returned by `NUMBER` with the separators
+/// of its language. Functions with the same name added before are kept.
+pub(crate) fn add_builtin_functions(bundle: &mut Bundle) {
+  bundle.set_formatter(Some(format_value));
+
+  let builtins = [
+    ("NUMBER", number as BuiltinFunction),
+    ("DATETIME", date_time as BuiltinFunction),
+    ("LIST", list as BuiltinFunction),
+  ];
+
+  for (name, function) in builtins {
+    if bundle.add_function(name, function).is_err() {
+      debug!("Keeping custom {name} function instead of the builtin one");
+    }
+  }
+}
+
+type BuiltinFunction = for<'value> fn(&[FluentValue<'value>], &FluentArgs) -> FluentValue<'value>;
+
+/// Formats numbers with explicit options, the ones returned by `NUMBER` or created from `NumberOptions`.
+/// Plain number arguments keep the formatting of Fluent, so `{ $year }` is still shown as `2024`.
+pub(crate) fn format_value(
+  value: &FluentValue,
+  memoizer: &concurrent::IntlLangMemoizer,
+) -> Option<String> {
+  let FluentValue::Number(number) = value else {
+    return None;
+  };
+
+  memoizer
+    .with_try_get::<LocaleFormats, _, _>((), |formats| {
+      format_number_with_options(number, formats.number_symbols)
+    })
+    .ok()
+    .flatten()
+}
+
+/// Formats a number with the separators of a language when it has explicit options, see `format_value`.
+pub(crate) fn format_number_with_options(
+  number: &FluentNumber,
+  symbols: &NumberSymbols,
+) -> Option<String> {
+  if number.options == FluentNumberOptions::default() {
+    return None;
+  }
+
+  Some(format_number(number, symbols))
+}
+
+/// Number symbols of the closest locale with data, English like symbols when there is none.
+pub(crate) fn number_symbols(language_identifier: &LanguageIdentifier) -> &'static NumberSymbols {
+  find_locale_data(NUMBER_SYMBOLS, language_identifier).unwrap_or(&FALLBACK_NUMBER_SYMBOLS)
+}
+
+/// `NUMBER($value, minimumFractionDigits: 2)`. Fluent formats function results before matching variants, so
+/// selectors should use the variable itself, `{ $count -> ... }`, rather than `NUMBER($count)`.
+fn number<'value>(positional: &[FluentValue<'value>], named: &FluentArgs) -> FluentValue<'value> {
+  let number = match positional.first() {
+    Some(FluentValue::Number(number)) => number.clone(),
+    Some(FluentValue::String(text)) => match text.parse::<FluentNumber>() {
+      Ok(number) => number,
+      Err(_) => return FluentValue::Error,
+    },
+    _ => return FluentValue::Error,
+  };
+
+  let mut options = number.options.clone();
+  options.merge(named);
+  // Marks the number as formatted by `NUMBER` even without options, 1 is already the default.
+  options.minimum_integer_digits.get_or_insert(1);
+
+  FluentValue::Number(FluentNumber::new(number.value, options))
+}
+
+/// `DATETIME($date, timeStyle: "short")`, taking a Unix timestamp in milliseconds, like JavaScript dates,
+/// or an ISO 8601 string such as `2024-01-15` or `2024-01-15T09:30:00`. Timestamps are shown in UTC.
+fn date_time<'value>(
+  positional: &[FluentValue<'value>],
+  named: &FluentArgs,
+) -> FluentValue<'value> {
+  let date_time = match positional.first() {
+    Some(FluentValue::Number(number)) => Some(DateTime::from_timestamp_millis(number.value)),
+    Some(FluentValue::String(text)) => DateTime::parse(text),
+    _ => None,
+  };
+
+  let Some(date_time) = date_time else {
+    return FluentValue::Error;
+  };
+
+  let style = |name: &str, default: &'static str| match named.get(name) {
+    Some(FluentValue::String(style)) => style.to_string(),
+    _ => default.to_string(),
+  };
+
+  FluentValue::Custom(Box::new(FluentDateTime {
+    date_time,
+    show_date: style("dateStyle", "short") != "none",
+    show_time: style("timeStyle", "none") != "none",
+  }))
+}
+
+fn find_locale_data<Data>(
+  table: &'static [(&str, Data)],
+  language_identifier: &LanguageIdentifier,
+) -> Option<&'static Data> {
+  let find = |locale: &str| {
+    table
+      .iter()
+      .find(|(known_locale, _)| *known_locale == locale)
+      .map(|(_, data)| data)
+  };
+
+  find(&language_identifier.to_string()).or_else(|| find(language_identifier.language.as_str()))
+}
+
+fn format_number(number: &FluentNumber, symbols: &NumberSymbols) -> String {
+  let options = &number.options;
+
+  if !number.value.is_finite() {
+    return number.value.to_string();
+  }
+
+  let (value, default_minimum, default_maximum) = match options.style {
+    FluentNumberStyle::Decimal => (number.value, 0, 3),
+    FluentNumberStyle::Percent => (number.value * 100.0, 0, 0),
+    FluentNumberStyle::Currency => (number.value, 2, 2),
+  };
+
+  let minimum_fraction_digits = options.minimum_fraction_digits.unwrap_or(default_minimum);
+  let maximum_fraction_digits = options
+    .maximum_fraction_digits
+    .unwrap_or(default_maximum)
+    .max(minimum_fraction_digits);
+
+  let rounded = format!("{:.*}", maximum_fraction_digits, value.abs());
+  let (integer, fraction) = rounded.split_once('.').unwrap_or((&rounded, ""));
+
+  let mut fraction = fraction.trim_end_matches('0').to_string();
+  while fraction.len() < minimum_fraction_digits {
+    fraction.push('0');
+  }
+
+  let minimum_integer_digits = options.minimum_integer_digits.unwrap_or(1);
+  let integer = format!("{integer:0>minimum_integer_digits$}");
+
+  let mut formatted = String::new();
+
+  if value < 0.0 && (integer.chars().any(|digit| digit != '0') || !fraction.is_empty()) {
+    formatted.push('-');
+  }
+
+  if options.use_grouping && integer.len() >= symbols.minimum_grouping_digits {
+    for (index, digit) in integer.chars().enumerate() {
+      if index > 0 && (integer.len() - index) % 3 == 0 {
+        formatted.push_str(symbols.group);
+      }
+      formatted.push(digit);
+    }
+  } else {
+    formatted.push_str(&integer);
+  }
+
+  if !fraction.is_empty() {
+    formatted.push_str(symbols.decimal);
+    formatted.push_str(&fraction);
+  }
+
+  match (&options.style, options.currency.as_deref()) {
+    (FluentNumberStyle::Percent, _) => formatted.push('%'),
+    (FluentNumberStyle::Currency, Some(currency)) if symbols.currency_first => {
+      formatted = format!("{currency}\u{a0}{formatted}");
+    }
+    (FluentNumberStyle::Currency, Some(currency)) => {
+      formatted = format!("{formatted}\u{a0}{currency}");
+    }
+    _ => {}
+  }
+
+  formatted
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct DateTime {
+  year: i64,
+  month: u32,
+  day: u32,
+  hour: u32,
+  minute: u32,
+}
+
+impl DateTime {
+  fn from_timestamp_millis(milliseconds: f64) -> Self {
+    let seconds = (milliseconds / 1000.0).floor() as i64;
+    let (days, seconds_of_day) = (seconds.div_euclid(86_400), seconds.rem_euclid(86_400));
+
+    // Days to civil date, from Howard Hinnant's date algorithms.
+    let shifted_days = days + 719_468;
+    let era = shifted_days.div_euclid(146_097);
+    let day_of_era = shifted_days.rem_euclid(146_097);
+    let year_of_era =
+      (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+      shifted_month + 3
+    } else {
+      shifted_month - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    Self {
+      year,
+      month,
+      day,
+      hour: (seconds_of_day / 3_600) as u32,
+      minute: (seconds_of_day % 3_600 / 60) as u32,
+    }
+  }
+
+  /// `YYYY-MM-DD` optionally followed by `THH:MM`, anything after the minutes is ignored.
+  /// Dates and times out of range, such as `2024-02-30` or `25:00`, are rejected.
+  fn parse(text: &str) -> Option<Self> {
+    let (date, time) = text.split_once(['T', ' ']).unwrap_or((text, ""));
+
+    let mut date_parts = date.splitn(3, '-');
+    let year = date_parts.next()?.parse().ok()?;
+    let month = date_parts
+      .next()?
+      .parse()
+      .ok()
+      .filter(|month| (1..=12).contains(month))?;
+    let day = date_parts
+      .next()?
+      .parse()
+      .ok()
+      .filter(|day| (1..=days_in_month(year, month)).contains(day))?;
+
+    let (hour, minute) = if time.is_empty() {
+      (0, 0)
+    } else {
+      let (hour, minute) = time.split_once(':')?;
+      let hour = hour.parse().ok().filter(|hour| *hour < 24)?;
+      let minute = minute
+        .get(..2)?
+        .parse()
+        .ok()
+        .filter(|minute| *minute < 60)?;
+      (hour, minute)
+    };
+
+    Some(Self {
+      year,
+      month,
+      day,
+      hour,
+      minute,
+    })
+  }
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+  match month {
+    2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+    2 => 28,
+    4 | 6 | 9 | 11 => 30,
+    _ => 31,
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct FluentDateTime {
+  date_time: DateTime,
+  show_date: bool,
+  show_time: bool,
+}
+
+impl FluentDateTime {
+  fn format(&self, pattern: &DateTimePattern) -> String {
+    let DateTime {
+      year,
+      month,
+      day,
+      hour,
+      minute,
+    } = &self.date_time;
+
+    let pad = |value: &u32| {
+      if pattern.padded {
+        format!("{value:02}")
+      } else {
+        value.to_string()
+      }
+    };
+
+    let mut parts = Vec::new();
+
+    if self.show_date {
+      let fields = match pattern.order {
+        DateOrder::MonthDayYear => [pad(month), pad(day), year.to_string()],
+        DateOrder::DayMonthYear => [pad(day), pad(month), year.to_string()],
+        DateOrder::YearMonthDay => [year.to_string(), pad(month), pad(day)],
+      };
+      parts.push(fields.join(pattern.separator));
+    }
+
+    if self.show_time {
+      parts.push(if pattern.hour12 {
+        let period = if *hour < 12 { "AM" } else { "PM" };
+        let hour = match hour % 12 {
+          0 => 12,
+          hour => hour,
+        };
+        format!("{hour}:{minute:02}\u{202f}{period}")
+      } else {
+        format!("{hour:02}:{minute:02}")
+      });
+    }
+
+    parts.join(", ")
+  }
+}
+
+impl FluentType for FluentDateTime {
+  fn duplicate(&self) -> Box<dyn FluentType + Send> {
+    Box::new(self.clone())
+  }
+
+  fn as_string(&self, memoizer: &IntlLangMemoizer) -> Cow<'static, str> {
+    memoizer
+      .with_try_get::<LocaleFormats, _, _>((), |formats| self.format(formats.date_time_pattern))
+      .unwrap_or_else(|()| self.format(&FALLBACK_DATE_TIME_PATTERN))
+      .into()
+  }
+
+  fn as_string_threadsafe(&self, memoizer: &concurrent::IntlLangMemoizer) -> Cow<'static, str> {
+    memoizer
+      .with_try_get::<LocaleFormats, _, _>((), |formats| self.format(formats.date_time_pattern))
+      .unwrap_or_else(|()| self.format(&FALLBACK_DATE_TIME_PATTERN))
+      .into()
+  }
+}
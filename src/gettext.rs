@@ -0,0 +1,616 @@
+use crate::{
+  conversion::{is_identifier, pattern, variable},
+  plurals::{cardinal_rules, category_name},
+  TranslatorError,
+};
+
+use std::{mem, path::Path};
+
+use unic_langid::LanguageIdentifier;
+
+/// Extension of the gettext files loaded from translations directories, accepted by the default file filter.
+pub(crate) const PO_EXTENSION: &str = "po";
+
+/// Variable plural messages converted from gettext select their form on.
+const COUNT_VARIABLE: &str = "count";
+
+/// Plural expression gettext uses when a file does not declare one.
+const DEFAULT_PLURAL_EXPRESSION: &str = "n != 1";
+
+const SAMPLE_LARGE_INTEGERS: [u64; 3] = [1_000, 10_000, 1_000_000];
+
+/// ### Description
+/// Converts a gettext `.po` file into Fluent, so projects can migrate from gettext without a converter.
+/// Message identifiers are made from the `msgctxt` and `msgid` of every entry with `gettext_message_id`.
+/// Placeholders such as `%(name)s` or `{name}` become `{ $name }` and positional ones such as `%s` or
+/// `%2$d` become `{ $arg1 }` or `{ $arg2 }`. Plural entries select on `$count`, mapping every `msgstr[n]`
+/// to the CLDR category of the `Language` header by evaluating the `Plural-Forms` header. Untranslated,
+/// fuzzy and obsolete entries are skipped. Files with this extension inside translations directories are
+/// converted when loaded, this is only needed for other sources. Compiled `.mo` files are not supported,
+/// `msgunfmt` turns them back into `.po` files.
+/// ### Usage
+/// ```ignore
+/// ...
+/// let ftl = po_to_ftl("msgid \"Hello, %(name)s!\"\nmsgstr \"¡Hola, %(name)s!\"\n")?;
+///
+/// assert_eq!(ftl, "hello-name-s = ¡Hola, { $name }!\n");
+/// ```
+pub fn po_to_ftl(content: &str) -> Result<String, TranslatorError> {
+  let invalid_file = |detail: String| TranslatorError::InvalidPoFile { detail };
+
+  let entries = parse_entries(content).map_err(invalid_file)?;
+
+  let header = entries
+    .iter()
+    .find(|entry| entry.context.is_none() && entry.id.is_empty())
+    .and_then(|entry| entry.translations.first())
+    .map(|header| Header::parse(header))
+    .unwrap_or_default();
+
+  let plural_expression = header
+    .plural_expression
+    .as_deref()
+    .unwrap_or(DEFAULT_PLURAL_EXPRESSION);
+  let plural_expression = PluralExpression::parse(plural_expression)
+    .map_err(|detail| invalid_file(format!("invalid plural expression, {detail}")))?;
+
+  let mut ftl = String::new();
+
+  for entry in &entries {
+    if entry.id.is_empty() || entry.fuzzy {
+      continue;
+    }
+
+    let id = gettext_message_id(entry.context.as_deref(), &entry.id);
+
+    if entry.plural_id.is_none() {
+      let Some(translation) = entry.translations.first().filter(|text| !text.is_empty()) else {
+        continue;
+      };
+
+      ftl.push_str(&format!("{id} = {}\n", gettext_pattern(translation, 4)));
+      continue;
+    }
+
+    if entry.translations.iter().any(String::is_empty) {
+      continue;
+    }
+
+    let variant_keys = plural_variant_keys(
+      header.language.as_ref(),
+      &plural_expression,
+      entry.translations.len(),
+    );
+    write_plural_message(&mut ftl, &id, &entry.translations, &variant_keys);
+  }
+
+  Ok(ftl)
+}
+
+/// ### Description
+/// Returns the Fluent identifier a gettext entry is converted into: its context and text lowercased, with
+/// every run of characters other than ASCII letters and digits replaced by `-`. Identifiers which would not
+/// start with a letter are prefixed with `msg-`.
+/// ### Usage
+/// ```ignore
+/// ...
+/// assert_eq!(gettext_message_id(None, "Save changes?"), "save-changes");
+/// assert_eq!(gettext_message_id(Some("menu"), "Open"), "menu-open");
+/// ```
+pub fn gettext_message_id(context: Option<&str>, message_id: &str) -> String {
+  let source = match context {
+    Some(context) => format!("{context}-{message_id}"),
+    None => message_id.to_string(),
+  };
+
+  let mut id = String::with_capacity(source.len());
+
+  for character in source.chars() {
+    if character.is_ascii_alphanumeric() {
+      id.push(character.to_ascii_lowercase());
+    } else if !id.ends_with('-') {
+      id.push('-');
+    }
+  }
+
+  let id = id.trim_matches('-');
+
+  match id.chars().next() {
+    Some(first) if first.is_ascii_alphabetic() => id.to_string(),
+    Some(_) => format!("msg-{id}"),
+    None => "msg".to_string(),
+  }
+}
+
+/// Converts a loaded file when it is a gettext file, keeping other files as they are. Files which can not
+/// be converted are loaded empty.
+pub(crate) fn ftl_source(file_name: &str, content: String) -> String {
+  if !is_po_file(file_name) {
+    return content;
+  }
+
+  po_to_ftl(&content).unwrap_or_else(|error| {
+    warn!("Ignoring {file_name}: {error}");
+    String::new()
+  })
+}
+
+pub(crate) fn is_po_file(file_name: &str) -> bool {
+  Path::new(file_name)
+    .extension()
+    .is_some_and(|extension| extension == PO_EXTENSION)
+}
+
+#[derive(Debug, Default)]
+struct PoEntry {
+  context: Option<String>,
+  id: String,
+  plural_id: Option<String>,
+  /// Every `msgstr[n]` by index, or the only `msgstr` of entries without plural forms.
+  translations: Vec<String>,
+  fuzzy: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Field {
+  Context,
+  Id,
+  PluralId,
+  Translation(usize),
+}
+
+fn parse_entries(content: &str) -> Result<Vec<PoEntry>, String> {
+  let mut entries = Vec::new();
+  let mut entry = PoEntry::default();
+  let mut field = None;
+
+  for (index, line) in content.lines().enumerate() {
+    let line = line.trim();
+    let invalid_line = |detail: &str| format!("line {}: {detail}", index + 1);
+
+    // Obsolete entries are kept commented out by gettext tools.
+    if line.is_empty() || line.starts_with("#~") {
+      continue;
+    }
+
+    // A comment, a context or an identifier after the translations of an entry starts the next one.
+    let starts_entry =
+      line.starts_with('#') || line.starts_with("msgctxt") || line.starts_with("msgid ");
+
+    if starts_entry && !entry.translations.is_empty() {
+      entries.push(mem::take(&mut entry));
+      field = None;
+    }
+
+    if let Some(flags) = line.strip_prefix("#,") {
+      entry.fuzzy |= flags.split(',').any(|flag| flag.trim() == "fuzzy");
+      continue;
+    }
+
+    if line.starts_with('#') {
+      continue;
+    }
+
+    if line.starts_with('"') {
+      let text = unquote(line).ok_or_else(|| invalid_line("unterminated string"))?;
+      let Some(current_field) = field else {
+        return Err(invalid_line("string outside of an entry"));
+      };
+
+      field_text(&mut entry, current_field).push_str(&text);
+      continue;
+    }
+
+    let (keyword, rest) = line
+      .split_once(char::is_whitespace)
+      .ok_or_else(|| invalid_line("expected a keyword followed by a string"))?;
+    let text = unquote(rest.trim()).ok_or_else(|| invalid_line("unterminated string"))?;
+
+    let current_field = match keyword {
+      "msgctxt" => Field::Context,
+      "msgid" => Field::Id,
+      "msgid_plural" => Field::PluralId,
+      "msgstr" => Field::Translation(0),
+      keyword => {
+        let index = keyword
+          .strip_prefix("msgstr[")
+          .and_then(|index| index.strip_suffix(']'))
+          .and_then(|index| index.parse().ok())
+          .ok_or_else(|| invalid_line(&format!("unknown keyword {keyword}")))?;
+
+        Field::Translation(index)
+      }
+    };
+
+    *field_text(&mut entry, current_field) = text;
+    field = Some(current_field);
+  }
+
+  if !entry.translations.is_empty() {
+    entries.push(entry);
+  }
+
+  Ok(entries)
+}
+
+fn field_text(entry: &mut PoEntry, field: Field) -> &mut String {
+  match field {
+    Field::Context => entry.context.get_or_insert_with(String::new),
+    Field::Id => &mut entry.id,
+    Field::PluralId => entry.plural_id.get_or_insert_with(String::new),
+    Field::Translation(index) => {
+      if entry.translations.len() <= index {
+        entry.translations.resize(index + 1, String::new());
+      }
+
+      &mut entry.translations[index]
+    }
+  }
+}
+
+/// Reads a C string literal, such as `"Hello\n"`.
+fn unquote(literal: &str) -> Option<String> {
+  let inner = literal.strip_prefix('"')?.strip_suffix('"')?;
+  let mut text = String::with_capacity(inner.len());
+  let mut characters = inner.chars();
+
+  while let Some(character) = characters.next() {
+    if character != '\\' {
+      text.push(character);
+      continue;
+    }
+
+    match characters.next()? {
+      'n' => text.push('\n'),
+      't' => text.push('\t'),
+      'r' => text.push('\r'),
+      escaped => text.push(escaped),
+    }
+  }
+
+  Some(text)
+}
+
+/// What the header entry, the translation of the empty `msgid`, says about the file.
+#[derive(Debug, Default)]
+struct Header {
+  language: Option<LanguageIdentifier>,
+  plural_expression: Option<String>,
+}
+
+impl Header {
+  fn parse(header: &str) -> Self {
+    let mut parsed = Self::default();
+
+    for line in header.lines() {
+      let Some((name, value)) = line.split_once(':') else {
+        continue;
+      };
+
+      match name.trim() {
+        "Language" => parsed.language = value.trim().replace('_', "-").parse().ok(),
+        "Plural-Forms" => {
+          parsed.plural_expression = value
+            .split(';')
+            .find_map(|part| part.trim().strip_prefix("plural="))
+            .map(str::to_string);
+        }
+        _ => {}
+      }
+    }
+
+    parsed
+  }
+}
+
+/// The CLDR category every plural form is written for, found by evaluating the gettext plural expression
+/// and the CLDR rules of the language for the same numbers. Forms which match no category are `None`.
+fn plural_variant_keys(
+  language: Option<&LanguageIdentifier>,
+  plural_expression: &PluralExpression,
+  forms: usize,
+) -> Vec<Option<&'static str>> {
+  let mut keys = vec![None; forms];
+
+  let Some(rules) = language.and_then(cardinal_rules) else {
+    debug!("Mapping plural forms by position as the gettext file does not declare its language");
+
+    if let Some(last) = keys.last_mut() {
+      *last = Some("other");
+    }
+    if forms > 1 {
+      keys[0] = Some("one");
+    }
+
+    return keys;
+  };
+
+  for number in (0..=200).chain(SAMPLE_LARGE_INTEGERS) {
+    let Ok(index) = usize::try_from(plural_expression.evaluate(number)) else {
+      continue;
+    };
+    let Ok(category) = rules.select(number) else {
+      continue;
+    };
+    let category = category_name(&category);
+
+    if index < forms && keys[index].is_none() && !keys.contains(&Some(category)) {
+      keys[index] = Some(category);
+    }
+  }
+
+  keys
+}
+
+fn write_plural_message(
+  ftl: &mut String,
+  id: &str,
+  translations: &[String],
+  variant_keys: &[Option<&'static str>],
+) {
+  let variants: Vec<(&str, &String)> = variant_keys
+    .iter()
+    .zip(translations)
+    .filter_map(|(key, translation)| key.map(|key| (key, translation)))
+    .collect();
+
+  if variants.len() < translations.len() {
+    warn!("Skipping plural forms of {id} which match no plural category of its language");
+  }
+
+  // The `other` category is the default, or the last form for languages which only use it for decimals.
+  let default_index = variants
+    .iter()
+    .position(|(key, _)| *key == "other")
+    .unwrap_or(variants.len().saturating_sub(1));
+
+  ftl.push_str(&format!("{id} = {{ ${COUNT_VARIABLE} ->\n"));
+
+  for (index, (key, translation)) in variants.iter().enumerate() {
+    let marker = if index == default_index {
+      "   *"
+    } else {
+      "    "
+    };
+    ftl.push_str(&format!(
+      "{marker}[{key}] {}\n",
+      gettext_pattern(translation, 8)
+    ));
+  }
+
+  ftl.push_str("}\n");
+}
+
+/// Turns text into a Fluent pattern, converting placeholders and `%%`. Lines after the first one are
+/// indented by `indent` spaces.
+fn gettext_pattern(text: &str, indent: usize) -> String {
+  let mut position = 0;
+
+  pattern(text, indent, |text| {
+    if let Some(rest) = text.strip_prefix("%%") {
+      return Some(("%".to_string(), rest));
+    }
+
+    placeholder(text, &mut position).map(|(name, rest)| (variable(&name), rest))
+  })
+}
+
+/// Reads a `%(name)s`, `{name}`, `%s` or `%2$s` placeholder at the start of the text, returning the name of
+/// its variable and the rest. Positional placeholders are named `arg` followed by their position.
+fn placeholder<'text>(text: &'text str, position: &mut usize) -> Option<(String, &'text str)> {
+  if let Some(inner) = text.strip_prefix('{') {
+    let end = inner.find('}')?;
+    let name = inner[..end].trim();
+
+    return is_identifier(name).then(|| (name.to_string(), &inner[end + 1..]));
+  }
+
+  let specification = text.strip_prefix('%')?;
+
+  if let Some(inner) = specification.strip_prefix('(') {
+    let end = inner.find(')')?;
+    let name = &inner[..end];
+    let rest = skip_conversion(&inner[end + 1..])?;
+
+    return is_identifier(name).then(|| (name.to_string(), rest));
+  }
+
+  let digits = specification
+    .find(|character: char| !character.is_ascii_digit())
+    .unwrap_or(specification.len());
+
+  let (explicit_position, specification) = match specification[digits..].strip_prefix('$') {
+    Some(rest) if digits > 0 => (specification[..digits].parse().ok(), rest),
+    _ => (None, specification),
+  };
+
+  let rest = skip_conversion(specification)?;
+  let position = explicit_position.unwrap_or_else(|| {
+    *position += 1;
+    *position
+  });
+
+  Some((format!("arg{position}"), rest))
+}
+
+/// Skips the flags, width, precision, length and conversion of a printf placeholder.
+fn skip_conversion(specification: &str) -> Option<&str> {
+  let specification =
+    specification.trim_start_matches(|character: char| "-+#0123456789.".contains(character));
+  let specification =
+    specification.trim_start_matches(|character: char| "hlLqjzt".contains(character));
+  let conversion = specification.chars().next()?;
+
+  "diouxXeEfFgGcs"
+    .contains(conversion)
+    .then(|| &specification[conversion.len_utf8()..])
+}
+
+/// The C expression of a `Plural-Forms` header, choosing the form used for the number `n`.
+#[derive(Debug)]
+enum PluralExpression {
+  N,
+  Number(u64),
+  Not(Box<Self>),
+  Binary(Box<Self>, &'static str, Box<Self>),
+  Conditional(Box<Self>, Box<Self>, Box<Self>),
+}
+
+const OPERATORS: [(&str, u8); 13] = [
+  ("||", 1),
+  ("&&", 2),
+  ("==", 3),
+  ("!=", 3),
+  ("<=", 4),
+  (">=", 4),
+  ("<", 4),
+  (">", 4),
+  ("+", 5),
+  ("-", 5),
+  ("*", 6),
+  ("/", 6),
+  ("%", 6),
+];
+
+impl PluralExpression {
+  fn parse(source: &str) -> Result<Self, String> {
+    let mut parser = PluralExpressionParser { rest: source };
+    let expression = parser.conditional()?;
+
+    if !parser.rest.trim().is_empty() {
+      return Err(format!("unexpected {}", parser.rest.trim()));
+    }
+
+    Ok(expression)
+  }
+
+  fn evaluate(&self, n: u64) -> u64 {
+    match self {
+      Self::N => n,
+      Self::Number(number) => *number,
+      Self::Not(expression) => u64::from(expression.evaluate(n) == 0),
+      Self::Conditional(condition, then, otherwise) => {
+        if condition.evaluate(n) != 0 {
+          then.evaluate(n)
+        } else {
+          otherwise.evaluate(n)
+        }
+      }
+      Self::Binary(left, operator, right) => {
+        let (left, right) = (left.evaluate(n), right.evaluate(n));
+
+        match *operator {
+          "||" => u64::from(left != 0 || right != 0),
+          "&&" => u64::from(left != 0 && right != 0),
+          "==" => u64::from(left == right),
+          "!=" => u64::from(left != right),
+          "<=" => u64::from(left <= right),
+          ">=" => u64::from(left >= right),
+          "<" => u64::from(left < right),
+          ">" => u64::from(left > right),
+          "+" => left.wrapping_add(right),
+          "-" => left.wrapping_sub(right),
+          "*" => left.wrapping_mul(right),
+          "/" => left.checked_div(right).unwrap_or(0),
+          _ => left.checked_rem(right).unwrap_or(0),
+        }
+      }
+    }
+  }
+}
+
+struct PluralExpressionParser<'source> {
+  rest: &'source str,
+}
+
+impl PluralExpressionParser<'_> {
+  fn eat(&mut self, token: &str) -> bool {
+    self.rest = self.rest.trim_start();
+
+    match self.rest.strip_prefix(token) {
+      Some(rest) => {
+        self.rest = rest;
+        true
+      }
+      None => false,
+    }
+  }
+
+  fn conditional(&mut self) -> Result<PluralExpression, String> {
+    let condition = self.binary(1)?;
+
+    if !self.eat("?") {
+      return Ok(condition);
+    }
+
+    let then = self.conditional()?;
+
+    if !self.eat(":") {
+      return Err("expected :".to_string());
+    }
+
+    let otherwise = self.conditional()?;
+
+    Ok(PluralExpression::Conditional(
+      Box::new(condition),
+      Box::new(then),
+      Box::new(otherwise),
+    ))
+  }
+
+  fn binary(&mut self, minimum_precedence: u8) -> Result<PluralExpression, String> {
+    let mut left = self.unary()?;
+
+    loop {
+      self.rest = self.rest.trim_start();
+
+      let Some((operator, precedence)) = OPERATORS
+        .iter()
+        .find(|(operator, _)| self.rest.starts_with(operator))
+        .copied()
+      else {
+        return Ok(left);
+      };
+
+      if precedence < minimum_precedence {
+        return Ok(left);
+      }
+
+      self.rest = &self.rest[operator.len()..];
+      let right = self.binary(precedence + 1)?;
+      left = PluralExpression::Binary(Box::new(left), operator, Box::new(right));
+    }
+  }
+
+  fn unary(&mut self) -> Result<PluralExpression, String> {
+    if self.eat("!") {
+      return Ok(PluralExpression::Not(Box::new(self.unary()?)));
+    }
+
+    if self.eat("(") {
+      let expression = self.conditional()?;
+
+      if !self.eat(")") {
+        return Err("expected )".to_string());
+      }
+
+      return Ok(expression);
+    }
+
+    if self.eat("n") {
+      return Ok(PluralExpression::N);
+    }
+
+    let digits = self
+      .rest
+      .find(|character: char| !character.is_ascii_digit())
+      .unwrap_or(self.rest.len());
+
+    let number = self.rest[..digits]
+      .parse()
+      .map_err(|_| format!("unexpected {}", self.rest))?;
+    self.rest = &self.rest[digits..];
+
+    Ok(PluralExpression::Number(number))
+  }
+}
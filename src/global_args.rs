@@ -0,0 +1,127 @@
+use crate::{escape_html, Bundle, Language, TranslationKey, Translator};
+
+use std::borrow::Cow;
+
+use fluent_bundle::{types::FluentNumber, FluentArgs, FluentError, FluentValue};
+use fluent_syntax::ast::Pattern;
+
+/// Arguments set with `with_global_arg` or `set_global_arg`, merged into the arguments of every message.
+/// Only strings and numbers are kept, as custom values can not be shared between threads.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct GlobalArgs {
+  values: Vec<(String, GlobalValue)>,
+  /// Whether string arguments of calls are HTML-escaped when merged, global arguments are trusted.
+  pub(crate) escape_html: bool,
+}
+
+#[derive(Debug, Clone)]
+enum GlobalValue {
+  String(String),
+  Number(FluentNumber),
+}
+
+impl GlobalArgs {
+  pub(crate) fn set(&mut self, name: &str, value: FluentValue<'static>) {
+    let value = match value {
+      FluentValue::String(text) => GlobalValue::String(text.into_owned()),
+      FluentValue::Number(number) => GlobalValue::Number(number),
+      other => {
+        warn!(
+          "Global argument {name} is ignored, only strings and numbers are supported: {other:?}"
+        );
+        return;
+      }
+    };
+
+    match self
+      .values
+      .iter_mut()
+      .find(|(global_name, _)| global_name == name)
+    {
+      Some((_, global_value)) => *global_value = value,
+      None => self.values.push((name.to_string(), value)),
+    }
+  }
+
+  /// Arguments of a call merged over the global ones, or `None` when there are no global arguments nor
+  /// escaping so the call arguments can be used as they are.
+  pub(crate) fn merge<'args>(
+    &'args self,
+    args: Option<&'args FluentArgs>,
+  ) -> Option<FluentArgs<'args>> {
+    if self.values.is_empty() && !self.escape_html {
+      return None;
+    }
+
+    let mut merged: FluentArgs = args
+      .into_iter()
+      .flat_map(FluentArgs::iter)
+      .map(|(name, value)| match value {
+        FluentValue::String(text) if self.escape_html => {
+          (name, FluentValue::from(escape_html(text).into_owned()))
+        }
+        value => (name, value.clone()),
+      })
+      .collect();
+
+    // `FluentArgs::set` keeps duplicated names, so only the global arguments the call does not pass are added.
+    for (name, value) in &self.values {
+      if merged.get(name.as_str()).is_some() {
+        continue;
+      }
+
+      let value = match value {
+        GlobalValue::String(text) => FluentValue::from(text.as_str()),
+        GlobalValue::Number(number) => FluentValue::Number(number.clone()),
+      };
+      merged.set(name.as_str(), value);
+    }
+
+    Some(merged)
+  }
+
+  /// Formats a pattern with the arguments of a call merged over the global ones. The text is owned when
+  /// there are global arguments or escaping, as it may borrow from the merged arguments.
+  pub(crate) fn format_pattern<'bundle>(
+    &self,
+    bundle: &'bundle Bundle,
+    pattern: &'bundle Pattern<&'bundle str>,
+    args: Option<&'bundle FluentArgs>,
+    errors: &mut Vec<FluentError>,
+  ) -> Cow<'bundle, str> {
+    match self.merge(args) {
+      Some(merged_args) => Cow::Owned(
+        bundle
+          .format_pattern(pattern, Some(&merged_args), errors)
+          .into_owned(),
+      ),
+      None => bundle.format_pattern(pattern, args, errors),
+    }
+  }
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Sets an argument available to every message, such as a brand name or a support email, unless the call
+  /// passes an argument with the same name. Replaces the previous value of the argument. Only strings and
+  /// numbers are supported, other values are ignored with a warning.
+  /// ### Usage
+  /// ```ignore
+  /// // contact = Write to { $support-email } for help
+  /// ...
+  /// translator.set_global_arg("support-email", "help@example.com");
+  ///
+  /// assert_eq!(translator.translate(&Languages::English, TranslationKeys::Contact).build(), "Write to help@example.com for help");
+  /// ```
+  pub fn set_global_arg<Value>(&mut self, name: &str, value: Value)
+  where
+    Value: Into<FluentValue<'static>>,
+  {
+    self.options.global_args.set(name, value.into());
+    self.clear_format_cache();
+  }
+}
@@ -0,0 +1,30 @@
+use std::borrow::Cow;
+
+/// ### Description
+/// Escapes the characters HTML gives a meaning to, `&`, `<`, `>`, `"` and `'`, so text can be placed inside
+/// elements and quoted attributes. Text without them is borrowed as it is.
+/// ### Usage
+/// ```ignore
+/// ...
+/// assert_eq!(escape_html("<b>Tom & Jerry</b>"), "&lt;b&gt;Tom &amp; Jerry&lt;/b&gt;");
+/// ```
+pub fn escape_html(text: &str) -> Cow<'_, str> {
+  if !text.contains(['&', '<', '>', '"', '\'']) {
+    return Cow::Borrowed(text);
+  }
+
+  let mut escaped = String::with_capacity(text.len() + 16);
+
+  for character in text.chars() {
+    match character {
+      '&' => escaped.push_str("&amp;"),
+      '<' => escaped.push_str("&lt;"),
+      '>' => escaped.push_str("&gt;"),
+      '"' => escaped.push_str("&quot;"),
+      '\'' => escaped.push_str("&#x27;"),
+      character => escaped.push(character),
+    }
+  }
+
+  Cow::Owned(escaped)
+}
@@ -0,0 +1,245 @@
+use crate::{loader::LoadedResource, ConflictPolicy, Language, TranslationKey, Translator};
+
+use std::{collections::BTreeSet, path::PathBuf};
+
+use fluent_syntax::{ast::Entry, parser};
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Returns the codes of the loaded languages in alphabetical order, such as to fill a language picker.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// for language in translator.languages() {
+  ///   println!("{language}");
+  /// }
+  /// ```
+  pub fn languages(&self) -> impl Iterator<Item = &str> {
+    let mut languages: Vec<&str> = self.translations.keys().collect();
+    languages.sort_unstable();
+    languages.into_iter()
+  }
+
+  /// ### Description
+  /// Whether a language was loaded, comparing its code exactly without falling back.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// if !translator.has_language(user.language_code()) {
+  ///   println!("Showing the default language instead");
+  /// }
+  /// ```
+  pub fn has_language(&self, language: &str) -> bool {
+    self.translations.contains_key(language)
+  }
+
+  /// ### Description
+  /// Whether a language defines a message, without falling back to other languages, formatting it nor
+  /// logging anything. Common files and runtime overrides count as defined.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// if translator.has_message(&Languages::English, TranslationKeys::SeasonalBanner) {
+  ///   render_banner(translator.translate_without_arguments(&Languages::English, TranslationKeys::SeasonalBanner));
+  /// }
+  /// ```
+  pub fn has_message(&self, language: &LanguageGeneric, key: TranslationKeyGeneric) -> bool {
+    self
+      .translations
+      .bundle(language.as_str())
+      .is_some_and(|bundle| bundle.has_message(key.as_str()))
+  }
+
+  /// ### Description
+  /// Whether any loaded language defines a message, without formatting it nor logging anything.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// if !translator.has_message_in_any_language(TranslationKeys::SeasonalBanner) {
+  ///   println!("The seasonal banner has not been written yet");
+  /// }
+  /// ```
+  pub fn has_message_in_any_language(&self, key: TranslationKeyGeneric) -> bool {
+    self
+      .translations
+      .iter()
+      .any(|(_, loaded)| loaded.bundle.has_message(key.as_str()))
+  }
+
+  /// ### Description
+  /// Returns the identifiers of the messages a language defines in alphabetical order, including common
+  /// files and runtime overrides but not terms nor messages of fallback languages. Unknown languages have
+  /// no messages.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let keys: Vec<&str> = translator.message_keys("es-ES").collect();
+  /// ```
+  pub fn message_keys(&self, language: &str) -> impl Iterator<Item = &str> {
+    let resources = self
+      .translations
+      .resources(language)
+      .into_iter()
+      .flatten()
+      .map(|loaded_resource| &loaded_resource.resource)
+      .chain(
+        self
+          .overrides
+          .get(language)
+          .into_iter()
+          .flat_map(|overrides| overrides.values()),
+      );
+
+    let keys: BTreeSet<&str> = resources
+      .flat_map(|resource| resource.entries())
+      .filter_map(|entry| match entry {
+        Entry::Message(message) => Some(message.id.name),
+        _ => None,
+      })
+      .collect();
+
+    keys.into_iter()
+  }
+
+  /// ### Description
+  /// Returns the `#` comment written above a message, which usually carries context for translators.
+  /// Falls back to the default language when the language or the message is not available.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let comment = translator.message_comment(&Languages::Spanish, &TranslationKeys::Hello);
+  ///
+  /// assert_eq!(comment.as_deref(), Some("Greeting shown on the home page"));
+  /// ```
+  pub fn message_comment(
+    &self,
+    language: &LanguageGeneric,
+    key: &TranslationKeyGeneric,
+  ) -> Option<String> {
+    self
+      .find_message_comment(language.as_str(), key.as_str())
+      .or_else(|| self.find_message_comment(self.default_language, key.as_str()))
+  }
+
+  /// Resources are parsed for runtime use, which strips comments, so the source is parsed again in full.
+  /// The first resource defining the message wins, the same way the bundle resolves it.
+  pub(crate) fn find_message_comment(&self, language: &str, key: &str) -> Option<String> {
+    for loaded_resource in self.translations.resources(language)? {
+      let ast = match parser::parse(loaded_resource.resource.source()) {
+        Ok(ast) => ast,
+        Err((ast, _)) => ast,
+      };
+
+      let message = ast.body.into_iter().find_map(|entry| match entry {
+        Entry::Message(message) if message.id.name == key => Some(message),
+        _ => None,
+      });
+
+      if let Some(message) = message {
+        return message.comment.map(|comment| comment.content.join("\n"));
+      }
+    }
+
+    None
+  }
+
+  /// ### Description
+  /// Returns the file defining the message a key is translated with, along with the line it starts at,
+  /// following the same fallbacks as translating. Returns `None` when no language defines it, when it is a
+  /// runtime override, when it was not loaded from a directory, such as with `from_sources`, or when its
+  /// definition cannot be found in the source. Lines of key-value files are the ones of their conversion into
+  /// Fluent.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// if let Some((path, line)) = translator.message_origin(&Languages::Spanish, &TranslationKeys::Hello) {
+  ///   println!("hello is defined in {}:{line}", path.display());
+  /// }
+  /// ```
+  pub fn message_origin(
+    &self,
+    language: &LanguageGeneric,
+    key: &TranslationKeyGeneric,
+  ) -> Option<(PathBuf, usize)> {
+    let key = key.as_str();
+
+    let (effective_language, _) = self
+      .candidate_bundles(language.as_str())
+      .into_iter()
+      .find(|(_, bundle)| bundle.has_message(key))?;
+
+    let is_overridden = self
+      .overrides
+      .get(effective_language)
+      .is_some_and(|overrides| overrides.contains_key(key));
+
+    if is_overridden {
+      return None;
+    }
+
+    let resources = self.translations.resources(effective_language)?;
+    let loaded_resource = defining_resource(resources, key, self.options.conflict_policy)?;
+    let source = loaded_resource.resource.source();
+
+    let is_definition = |line: &str| {
+      line
+        .strip_prefix(key)
+        .is_some_and(|rest| rest.trim_start().starts_with('='))
+    };
+
+    let mut definition_lines = source
+      .lines()
+      .enumerate()
+      .filter(|(_, line)| is_definition(line))
+      .map(|(index, _)| index + 1);
+
+    // Within a resource, the bundle keeps the first definition, or the last one with `KeepLast`.
+    let line = if self.options.conflict_policy == ConflictPolicy::KeepLast {
+      definition_lines.last()
+    } else {
+      definition_lines.next()
+    }?;
+
+    Some((loaded_resource.path.clone()?, line))
+  }
+}
+
+/// The resource whose definition of a message, or of a term when the id starts with `-`, the bundle keeps,
+/// mirroring how bundles are built: the first definition wins, unless a later resource overrides it or the
+/// conflict policy keeps the last one.
+pub(crate) fn defining_resource<'resources>(
+  resources: &'resources [LoadedResource],
+  id: &str,
+  conflict_policy: ConflictPolicy,
+) -> Option<&'resources LoadedResource> {
+  let mut definitions = resources.iter().filter(|loaded_resource| {
+    loaded_resource
+      .resource
+      .entries()
+      .any(|entry| entry_id(entry).as_deref() == Some(id))
+  });
+
+  if conflict_policy == ConflictPolicy::KeepLast {
+    return definitions
+      .max_by_key(|loaded_resource| (loaded_resource.overriding, !loaded_resource.common));
+  }
+
+  let first = definitions.next();
+
+  definitions
+    .rfind(|loaded_resource| loaded_resource.overriding)
+    .or(first)
+}
+
+/// Identifier of a message, or of a term starting with `-`, as conflicts report them.
+pub(crate) fn entry_id(entry: &Entry<&str>) -> Option<String> {
+  match entry {
+    Entry::Message(message) => Some(message.id.name.to_string()),
+    Entry::Term(term) => Some(format!("-{}", term.id.name)),
+    _ => None,
+  }
+}
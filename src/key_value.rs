@@ -0,0 +1,138 @@
+use crate::{
+  conversion::{is_identifier, pattern, variable},
+  TranslatorError,
+};
+
+use std::path::Path;
+
+use serde_json::Value;
+
+/// Formats of key-value translation files, such as `{ "hello": "Hello {name}" }`, converted into Fluent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyValueFormat {
+  Json,
+  Yaml,
+  Toml,
+}
+
+impl KeyValueFormat {
+  /// Extensions of the files loaded as key-value files, accepted by the default file filter.
+  pub(crate) const EXTENSIONS: [&'static str; 4] = ["json", "yaml", "yml", "toml"];
+
+  /// Detects the format from the extension of a file name.
+  pub fn from_file_name(file_name: &str) -> Option<Self> {
+    let extension = Path::new(file_name).extension()?.to_str()?;
+
+    match extension {
+      "json" => Some(Self::Json),
+      "yaml" | "yml" => Some(Self::Yaml),
+      "toml" => Some(Self::Toml),
+      _ => None,
+    }
+  }
+}
+
+/// ### Description
+/// Converts a key-value translation file into Fluent, so projects can migrate to Fluent file by file.
+/// Nested objects are flattened joining keys with `-`, dots in keys become `-` as well, and `{name}` or
+/// `{{name}}` placeholders become `{ $name }`. Files with these extensions inside translations directories
+/// are converted when loaded, this is only needed for other sources.
+/// ### Usage
+/// ```ignore
+/// ...
+/// let ftl = key_value_to_ftl(r#"{ "menu": { "save": "Save {name}" } }"#, KeyValueFormat::Json)?;
+///
+/// assert_eq!(ftl, "menu-save = Save { $name }\n");
+/// ```
+pub fn key_value_to_ftl(content: &str, format: KeyValueFormat) -> Result<String, TranslatorError> {
+  let invalid_file = |detail: String| TranslatorError::InvalidKeyValueFile { format, detail };
+
+  let value: Value = match format {
+    KeyValueFormat::Json => {
+      serde_json::from_str(content).map_err(|error| invalid_file(error.to_string()))?
+    }
+    KeyValueFormat::Yaml => {
+      serde_yaml::from_str(content).map_err(|error| invalid_file(error.to_string()))?
+    }
+    KeyValueFormat::Toml => {
+      toml::from_str(content).map_err(|error| invalid_file(error.to_string()))?
+    }
+  };
+
+  let Value::Object(entries) = value else {
+    return Err(invalid_file(
+      "the file must contain a map of keys".to_string(),
+    ));
+  };
+
+  let mut ftl = String::new();
+
+  for (key, value) in &entries {
+    write_entry(&mut ftl, &message_id(key), value);
+  }
+
+  Ok(ftl)
+}
+
+/// Converts a loaded file when its extension is a key-value format, keeping Fluent files as they are.
+/// Files which can not be converted are loaded empty.
+pub(crate) fn ftl_source(file_name: &str, content: String) -> String {
+  let Some(format) = KeyValueFormat::from_file_name(file_name) else {
+    return content;
+  };
+
+  key_value_to_ftl(&content, format).unwrap_or_else(|error| {
+    warn!("Ignoring {file_name}: {error}");
+    String::new()
+  })
+}
+
+fn write_entry(ftl: &mut String, id: &str, value: &Value) {
+  let text = match value {
+    Value::Object(entries) => {
+      for (key, value) in entries {
+        write_entry(ftl, &format!("{id}-{}", message_id(key)), value);
+      }
+      return;
+    }
+    Value::String(text) => text.clone(),
+    Value::Number(number) => number.to_string(),
+    Value::Bool(boolean) => boolean.to_string(),
+    Value::Null => return,
+    Value::Array(_) => {
+      warn!("Skipping {id} as lists can not be converted into messages");
+      return;
+    }
+  };
+
+  if !is_identifier(id) {
+    warn!("Skipping {id} as it is not a valid message identifier");
+    return;
+  }
+
+  ftl.push_str(id);
+  ftl.push_str(" = ");
+  ftl.push_str(&pattern(&text, 4, |text| {
+    placeholder(text).map(|(name, rest)| (variable(name), rest))
+  }));
+  ftl.push('\n');
+}
+
+fn message_id(key: &str) -> String {
+  key.replace('.', "-")
+}
+
+/// Reads a `{name}` or `{{name}}` placeholder at the start of the text, returning its name and the rest.
+fn placeholder(text: &str) -> Option<(&str, &str)> {
+  let (opening, closing) = if text.starts_with("{{") {
+    ("{{", "}}")
+  } else {
+    ("{", "}")
+  };
+
+  let inner = text.strip_prefix(opening)?;
+  let end = inner.find(closing)?;
+  let name = inner[..end].trim();
+
+  is_identifier(name).then(|| (name, &inner[end + closing.len()..]))
+}
@@ -0,0 +1,154 @@
+use crate::{Bundle, Language, TranslationKey, Translator};
+
+use unic_langid::{CharacterDirection, LanguageIdentifier};
+
+/// Names of languages by their language subtag, in English and in the language itself.
+const LANGUAGE_NAMES: &[(&str, &str, &str)] = &[
+  ("af", "Afrikaans", "Afrikaans"),
+  ("ar", "Arabic", "العربية"),
+  ("bg", "Bulgarian", "Български"),
+  ("bn", "Bengali", "বাংলা"),
+  ("ca", "Catalan", "Català"),
+  ("cs", "Czech", "Čeština"),
+  ("da", "Danish", "Dansk"),
+  ("de", "German", "Deutsch"),
+  ("el", "Greek", "Ελληνικά"),
+  ("en", "English", "English"),
+  ("es", "Spanish", "Español"),
+  ("et", "Estonian", "Eesti"),
+  ("eu", "Basque", "Euskara"),
+  ("fa", "Persian", "فارسی"),
+  ("fi", "Finnish", "Suomi"),
+  ("fil", "Filipino", "Filipino"),
+  ("fr", "French", "Français"),
+  ("ga", "Irish", "Gaeilge"),
+  ("gl", "Galician", "Galego"),
+  ("he", "Hebrew", "עברית"),
+  ("hi", "Hindi", "हिन्दी"),
+  ("hr", "Croatian", "Hrvatski"),
+  ("hu", "Hungarian", "Magyar"),
+  ("id", "Indonesian", "Bahasa Indonesia"),
+  ("is", "Icelandic", "Íslenska"),
+  ("it", "Italian", "Italiano"),
+  ("ja", "Japanese", "日本語"),
+  ("ko", "Korean", "한국어"),
+  ("lt", "Lithuanian", "Lietuvių"),
+  ("lv", "Latvian", "Latviešu"),
+  ("ms", "Malay", "Bahasa Melayu"),
+  ("nb", "Norwegian Bokmål", "Norsk bokmål"),
+  ("nl", "Dutch", "Nederlands"),
+  ("nn", "Norwegian Nynorsk", "Norsk nynorsk"),
+  ("no", "Norwegian", "Norsk"),
+  ("pl", "Polish", "Polski"),
+  ("pt", "Portuguese", "Português"),
+  ("ro", "Romanian", "Română"),
+  ("ru", "Russian", "Русский"),
+  ("sk", "Slovak", "Slovenčina"),
+  ("sl", "Slovenian", "Slovenščina"),
+  ("sr", "Serbian", "Српски"),
+  ("sv", "Swedish", "Svenska"),
+  ("sw", "Swahili", "Kiswahili"),
+  ("ta", "Tamil", "தமிழ்"),
+  ("th", "Thai", "ไทย"),
+  ("tr", "Turkish", "Türkçe"),
+  ("uk", "Ukrainian", "Українська"),
+  ("ur", "Urdu", "اردو"),
+  ("vi", "Vietnamese", "Tiếng Việt"),
+  ("zh", "Chinese", "中文"),
+];
+
+/// Messages a language can define, usually in a `meta.ftl` file, to replace the metadata derived from its code.
+const NATIVE_NAME_MESSAGE: &str = "language-native-name";
+const ENGLISH_NAME_MESSAGE: &str = "language-english-name";
+const DIRECTION_MESSAGE: &str = "language-direction";
+
+/// Direction text of a language is written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextDirection {
+  LeftToRight,
+  RightToLeft,
+  TopToBottom,
+}
+
+impl TextDirection {
+  /// Value of the HTML `dir` attribute, `ltr` for vertical scripts which are laid out with CSS instead.
+  pub fn html_dir(&self) -> &'static str {
+    match self {
+      Self::RightToLeft => "rtl",
+      Self::LeftToRight | Self::TopToBottom => "ltr",
+    }
+  }
+}
+
+/// Names and text direction of a loaded language, for language pickers and layouts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageMetadata {
+  pub code: String,
+  /// Name of the language in the language itself, such as `Español`.
+  pub native_name: Option<String>,
+  pub english_name: Option<String>,
+  pub direction: TextDirection,
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Returns the names and text direction of a loaded language, derived from its identifier. A language can
+  /// replace them defining `language-native-name`, `language-english-name` and `language-direction` (`ltr`
+  /// or `rtl`) messages, such as in a `meta.ftl` file. Returns `None` when the language is not loaded.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// for language in translator.languages() {
+  ///   let metadata = translator.language_metadata(language).unwrap();
+  ///   let name = metadata.native_name.unwrap_or(metadata.code);
+  ///
+  ///   println!("<option dir=\"{}\">{name}</option>", metadata.direction.html_dir());
+  /// }
+  /// ```
+  pub fn language_metadata(&self, language: &str) -> Option<LanguageMetadata> {
+    let (code, loaded) = self.translations.get_key_value(language)?;
+    let bundle = &loaded.bundle;
+    let identifier = bundle.locales.first();
+
+    let names = identifier.and_then(|identifier| {
+      LANGUAGE_NAMES
+        .iter()
+        .find(|(subtag, _, _)| identifier.language.as_str() == *subtag)
+    });
+
+    let direction = match metadata_message(bundle, DIRECTION_MESSAGE).as_deref() {
+      Some("rtl") => TextDirection::RightToLeft,
+      Some("ltr") => TextDirection::LeftToRight,
+      _ => identifier.map_or(TextDirection::LeftToRight, direction),
+    };
+
+    Some(LanguageMetadata {
+      code: code.to_string(),
+      native_name: metadata_message(bundle, NATIVE_NAME_MESSAGE)
+        .or_else(|| names.map(|(_, _, native_name)| native_name.to_string())),
+      english_name: metadata_message(bundle, ENGLISH_NAME_MESSAGE)
+        .or_else(|| names.map(|(_, english_name, _)| english_name.to_string())),
+      direction,
+    })
+  }
+}
+
+fn direction(identifier: &LanguageIdentifier) -> TextDirection {
+  match identifier.character_direction() {
+    CharacterDirection::RTL => TextDirection::RightToLeft,
+    CharacterDirection::TTB => TextDirection::TopToBottom,
+    CharacterDirection::LTR => TextDirection::LeftToRight,
+  }
+}
+
+fn metadata_message(bundle: &Bundle, id: &str) -> Option<String> {
+  let value = bundle.get_message(id)?.value()?;
+  let mut errors = Vec::new();
+  let text = bundle.format_pattern(value, None, &mut errors);
+
+  errors.is_empty().then(|| text.trim().to_string())
+}
@@ -1,7 +1,107 @@
+#[macro_use]
+mod macros;
+
+mod arguments;
+mod ast;
+#[cfg(feature = "tokio")]
+mod async_loader;
+mod audit;
+#[cfg(feature = "axum")]
+mod axum_extractor;
+mod bidi;
+mod builder;
+mod conflicts;
+#[cfg(any(feature = "serde", feature = "gettext"))]
+mod conversion;
+mod coverage;
+#[cfg(feature = "discord")]
+mod discord;
 mod error;
+mod export;
+mod format_cache;
+mod formatted_message;
+mod formatting;
+#[cfg(feature = "gettext")]
+mod gettext;
+mod global_args;
+mod html;
+mod introspection;
+#[cfg(feature = "serde")]
+mod key_value;
+mod language_metadata;
+mod list_format;
+mod loaded_languages;
+mod loader;
+mod logging;
 mod message_translator;
+mod namespaces;
+mod number_options;
+mod observer;
+mod overrides;
+mod plurals;
+mod pseudo;
+#[cfg(feature = "relative-time")]
+mod relative_time;
+mod reload;
+#[cfg(feature = "reqwest")]
+mod remote_source;
+mod runtime_languages;
+mod scoped_translator;
+mod shared_translator;
+mod source;
+mod stats;
+mod terms;
+mod translatable;
 mod translator;
+mod unknown_arguments;
+mod unused_keys;
+mod validation;
+#[cfg(feature = "notify")]
+mod watch;
+#[cfg(feature = "xliff")]
+mod xliff;
+
+pub use fluent_bundle::{FluentArgs, FluentError, FluentValue};
 
+pub use arguments::*;
+pub use audit::*;
+#[cfg(feature = "axum")]
+pub use axum_extractor::*;
+pub use bidi::*;
+pub use builder::*;
+pub use conflicts::*;
+pub use coverage::*;
+#[cfg(feature = "discord")]
+pub use discord::*;
 pub use error::*;
+pub use format_cache::*;
+pub use formatted_message::*;
+#[cfg(feature = "gettext")]
+pub use gettext::*;
+pub use html::*;
+#[cfg(feature = "serde")]
+pub use key_value::*;
+pub use language_metadata::*;
+pub use list_format::*;
+pub use logging::*;
 pub use message_translator::*;
+pub use number_options::*;
+pub use observer::*;
+pub use plurals::*;
+#[cfg(feature = "relative-time")]
+pub use relative_time::*;
+#[cfg(feature = "reqwest")]
+pub use remote_source::*;
+pub use scoped_translator::*;
+pub use shared_translator::*;
+pub use source::*;
+pub use stats::*;
+pub use translatable::*;
+#[cfg(feature = "derive")]
+pub use translate_derive::{validate_keys, IntoFluentArgs, TranslationKey};
 pub use translator::*;
+pub use validation::*;
+#[cfg(feature = "notify")]
+pub use watch::*;
+#[cfg(feature = "xliff")]
+pub use xliff::*;
@@ -0,0 +1,387 @@
+use crate::{
+  formatting::{format_number_with_options, number_symbols, NumberSymbols},
+  Language, TranslationKey, Translator,
+};
+
+use std::borrow::Cow;
+
+use fluent_bundle::{
+  types::{FluentNumber, FluentType},
+  FluentArgs, FluentValue,
+};
+use intl_memoizer::{concurrent, IntlLangMemoizer, Memoizable};
+use unic_langid::LanguageIdentifier;
+
+/// ### Description
+/// Whether a list joins its items as all of them, "A, B and C", or as any of them, "A, B or C".
+/// ### Usage
+/// ```ignore
+/// ...
+/// let list = translator.format_list_with_style(&Languages::English, &items, ListStyle::Or);
+///
+/// assert_eq!(list, "apples, oranges, or bananas");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ListStyle {
+  /// CLDR conjunction lists.
+  #[default]
+  And,
+  /// CLDR disjunction lists.
+  Or,
+}
+
+/// Separators of a CLDR conjunction list pattern, each placed between `{0}` and `{1}`.
+struct ListPatterns {
+  pair: &'static str,
+  start: &'static str,
+  middle: &'static str,
+  end: &'static str,
+}
+
+const fn patterns(pair: &'static str, end: &'static str) -> ListPatterns {
+  ListPatterns {
+    pair,
+    start: ", ",
+    middle: ", ",
+    end,
+  }
+}
+
+/// CLDR standard (conjunction) list patterns, looked up by full locale first and then by language.
+const CONJUNCTION_PATTERNS: &[(&str, ListPatterns)] = &[
+  ("en", patterns(" and ", ", and ")),
+  ("en-GB", patterns(" and ", " and ")),
+  ("en-AU", patterns(" and ", " and ")),
+  ("es", patterns(" y ", " y ")),
+  ("fr", patterns(" et ", " et ")),
+  ("de", patterns(" und ", " und ")),
+  ("it", patterns(" e ", " e ")),
+  ("pt", patterns(" e ", " e ")),
+  ("nl", patterns(" en ", " en ")),
+  ("ca", patterns(" i ", " i ")),
+  ("pl", patterns(" i ", " i ")),
+  ("cs", patterns(" a ", " a ")),
+  ("ru", patterns(" и ", " и ")),
+  ("uk", patterns(" і ", " і ")),
+  ("sv", patterns(" och ", " och ")),
+  ("da", patterns(" og ", " og ")),
+  ("nb", patterns(" og ", " og ")),
+  ("fi", patterns(" ja ", " ja ")),
+  ("tr", patterns(" ve ", " ve ")),
+  ("hi", patterns(" और ", ", और ")),
+  ("ko", patterns(" 및 ", " 및 ")),
+  (
+    "ja",
+    ListPatterns {
+      pair: "、",
+      start: "、",
+      middle: "、",
+      end: "、",
+    },
+  ),
+  (
+    "zh",
+    ListPatterns {
+      pair: "和",
+      start: "、",
+      middle: "、",
+      end: "和",
+    },
+  ),
+  (
+    "ar",
+    ListPatterns {
+      pair: " و",
+      start: " و",
+      middle: " و",
+      end: " و",
+    },
+  ),
+  (
+    "he",
+    ListPatterns {
+      pair: " ו",
+      start: ", ",
+      middle: ", ",
+      end: " ו",
+    },
+  ),
+];
+
+/// CLDR standard disjunction list patterns, looked up by full locale first and then by language.
+const DISJUNCTION_PATTERNS: &[(&str, ListPatterns)] = &[
+  ("en", patterns(" or ", ", or ")),
+  ("en-GB", patterns(" or ", " or ")),
+  ("en-AU", patterns(" or ", " or ")),
+  ("es", patterns(" o ", " o ")),
+  ("fr", patterns(" ou ", " ou ")),
+  ("de", patterns(" oder ", " oder ")),
+  ("it", patterns(" o ", " o ")),
+  ("pt", patterns(" ou ", " ou ")),
+  ("nl", patterns(" of ", " of ")),
+  ("ca", patterns(" o ", " o ")),
+  ("pl", patterns(" lub ", " lub ")),
+  ("cs", patterns(" nebo ", " nebo ")),
+  ("ru", patterns(" или ", " или ")),
+  ("uk", patterns(" або ", " або ")),
+  ("sv", patterns(" eller ", " eller ")),
+  ("da", patterns(" eller ", " eller ")),
+  ("nb", patterns(" eller ", " eller ")),
+  ("fi", patterns(" tai ", " tai ")),
+  ("tr", patterns(" veya ", " veya ")),
+  ("hi", patterns(" या ", ", या ")),
+  ("ko", patterns(" 또는 ", " 또는 ")),
+  (
+    "ja",
+    ListPatterns {
+      pair: "または",
+      start: "、",
+      middle: "、",
+      end: "、または",
+    },
+  ),
+  (
+    "zh",
+    ListPatterns {
+      pair: "或",
+      start: "、",
+      middle: "、",
+      end: "或",
+    },
+  ),
+  (
+    "ar",
+    ListPatterns {
+      pair: " أو ",
+      start: " أو ",
+      middle: " أو ",
+      end: " أو ",
+    },
+  ),
+  (
+    "he",
+    ListPatterns {
+      pair: " או ",
+      start: ", ",
+      middle: ", ",
+      end: " או ",
+    },
+  ),
+];
+
+const FALLBACK_PATTERNS: ListPatterns = patterns(", ", ", ");
+
+/// List patterns and number symbols of a locale, memoized by the bundle for the `LIST` function.
+struct ListFormats {
+  conjunction: &'static ListPatterns,
+  disjunction: &'static ListPatterns,
+  number_symbols: &'static NumberSymbols,
+}
+
+impl Memoizable for ListFormats {
+  type Args = ();
+  type Error = ();
+
+  fn construct(language_identifier: LanguageIdentifier, _args: ()) -> Result<Self, ()> {
+    Ok(Self {
+      conjunction: list_patterns(ListStyle::And, &language_identifier),
+      disjunction: list_patterns(ListStyle::Or, &language_identifier),
+      number_symbols: number_symbols(&language_identifier),
+    })
+  }
+}
+
+impl ListFormats {
+  fn patterns(&self, style: ListStyle) -> &'static ListPatterns {
+    match style {
+      ListStyle::And => self.conjunction,
+      ListStyle::Or => self.disjunction,
+    }
+  }
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Joins already translated items following the CLDR list formatting rules of a language.
+  /// Languages without list data are joined with commas.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let items = [Cow::from("manzanas"), Cow::from("naranjas"), Cow::from("plátanos")];
+  ///
+  /// let list = translator.format_list(&Languages::Spanish, &items);
+  ///
+  /// assert_eq!(list, "manzanas, naranjas y plátanos");
+  /// ```
+  pub fn format_list(&self, language: &LanguageGeneric, items: &[Cow<str>]) -> String {
+    self.format_list_with_style(language, items, ListStyle::And)
+  }
+
+  /// ### Description
+  /// Joins already translated items like `format_list`, as all of them or any of them.
+  /// Messages can join their arguments the same way with the `LIST` function, such as
+  /// `{ LIST($first, $second, $third, type: "disjunction") }`.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let items = [Cow::from("Rust"), Cow::from("Go")];
+  ///
+  /// let list = translator.format_list_with_style(&Languages::French, &items, ListStyle::Or);
+  ///
+  /// assert_eq!(list, "Rust ou Go");
+  /// ```
+  pub fn format_list_with_style(
+    &self,
+    language: &LanguageGeneric,
+    items: &[Cow<str>],
+    style: ListStyle,
+  ) -> String {
+    let language = language.as_str();
+
+    let patterns = match self.options.language_parser.parse(language) {
+      Some(language_identifier) => list_patterns(style, &language_identifier),
+      None => {
+        debug!("No list formatting data for {language}, joining with commas");
+        &FALLBACK_PATTERNS
+      }
+    };
+
+    join_list(patterns, items)
+  }
+}
+
+fn join_list<Item: AsRef<str>>(patterns: &ListPatterns, items: &[Item]) -> String {
+  match items {
+    [] => String::new(),
+    [item] => item.as_ref().to_string(),
+    [first, second] => format!("{}{}{}", first.as_ref(), patterns.pair, second.as_ref()),
+    [first, middle @ .., last] => {
+      let mut list = first.as_ref().to_string();
+
+      for (index, item) in middle.iter().enumerate() {
+        list.push_str(if index == 0 {
+          patterns.start
+        } else {
+          patterns.middle
+        });
+        list.push_str(item.as_ref());
+      }
+
+      list.push_str(patterns.end);
+      list.push_str(last.as_ref());
+      list
+    }
+  }
+}
+
+fn list_patterns(
+  style: ListStyle,
+  language_identifier: &LanguageIdentifier,
+) -> &'static ListPatterns {
+  let table = match style {
+    ListStyle::And => CONJUNCTION_PATTERNS,
+    ListStyle::Or => DISJUNCTION_PATTERNS,
+  };
+
+  let find = |locale: &str| {
+    table
+      .iter()
+      .find(|(known_locale, _)| *known_locale == locale)
+      .map(|(_, patterns)| patterns)
+  };
+
+  find(&language_identifier.to_string())
+    .or_else(|| find(language_identifier.language.as_str()))
+    .unwrap_or_else(|| {
+      debug!("No list formatting data for {language_identifier}, joining with commas");
+      &FALLBACK_PATTERNS
+    })
+}
+
+/// `LIST($first, $second, type: "disjunction")`, joining its positional arguments following the list
+/// formatting rules of the language. `type` is `conjunction` by default, like JavaScript list formats.
+pub(crate) fn list<'value>(
+  positional: &[FluentValue<'value>],
+  named: &FluentArgs,
+) -> FluentValue<'value> {
+  let style = match named.get("type") {
+    Some(FluentValue::String(style)) if style == "disjunction" => ListStyle::Or,
+    _ => ListStyle::And,
+  };
+
+  let items = positional
+    .iter()
+    .map(|value| match value {
+      FluentValue::String(text) => Some(ListItem::Text(text.to_string())),
+      FluentValue::Number(number) => Some(ListItem::Number(number.clone())),
+      _ => None,
+    })
+    .collect::<Option<Vec<ListItem>>>();
+
+  match items {
+    Some(items) => FluentValue::Custom(Box::new(FluentList { style, items })),
+    None => FluentValue::Error,
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ListItem {
+  Text(String),
+  Number(FluentNumber),
+}
+
+/// The result of `LIST`, joined once the language it is formatted for is known.
+#[derive(Debug, Clone, PartialEq)]
+struct FluentList {
+  style: ListStyle,
+  items: Vec<ListItem>,
+}
+
+impl FluentList {
+  fn join(&self, formats: &ListFormats) -> String {
+    let items: Vec<Cow<str>> = self
+      .items
+      .iter()
+      .map(|item| match item {
+        ListItem::Text(text) => Cow::Borrowed(text.as_str()),
+        ListItem::Number(number) => format_number_with_options(number, formats.number_symbols)
+          .map_or_else(|| number.as_string(), Cow::Owned),
+      })
+      .collect();
+
+    join_list(formats.patterns(self.style), &items)
+  }
+
+  /// Joins the list with commas when the formats of its language are not available.
+  fn join_with_fallback(&self) -> String {
+    self.join(&ListFormats {
+      conjunction: &FALLBACK_PATTERNS,
+      disjunction: &FALLBACK_PATTERNS,
+      number_symbols: number_symbols(&LanguageIdentifier::default()),
+    })
+  }
+}
+
+impl FluentType for FluentList {
+  fn duplicate(&self) -> Box<dyn FluentType + Send> {
+    Box::new(self.clone())
+  }
+
+  fn as_string(&self, memoizer: &IntlLangMemoizer) -> Cow<'static, str> {
+    memoizer
+      .with_try_get::<ListFormats, _, _>((), |formats| self.join(formats))
+      .unwrap_or_else(|()| self.join_with_fallback())
+      .into()
+  }
+
+  fn as_string_threadsafe(&self, memoizer: &concurrent::IntlLangMemoizer) -> Cow<'static, str> {
+    memoizer
+      .with_try_get::<ListFormats, _, _>((), |formats| self.join(formats))
+      .unwrap_or_else(|()| self.join_with_fallback())
+      .into()
+  }
+}
@@ -0,0 +1,420 @@
+use crate::{
+  loader::{
+    build_bundle, locate_files, log_conflicts, parse_common_files, parse_files,
+    read_single_language_files, DirectoryIndex, FileData, LoadedResource,
+  },
+  namespaces::{check_namespace_separator, namespace_files},
+  terms::{log_missing_terms, missing_terms},
+  Bundle, TranslatorError, TranslatorOptions,
+};
+
+use std::{collections::HashMap, fmt, path::PathBuf, sync::OnceLock};
+
+use unic_langid::LanguageIdentifier;
+
+/// The bundle of a language along with the resources it was built from.
+pub(crate) struct LoadedLanguage {
+  pub(crate) bundle: Bundle,
+  pub(crate) resources: Vec<LoadedResource>,
+}
+
+/// The languages of a translator. With lazy loading, languages are indexed when the translator is built and
+/// their files are only read and parsed the first time they are used.
+/// The default language is held apart from the others, so it is always there.
+pub(crate) struct LoadedLanguages {
+  default_language: String,
+  default_slot: LanguageSlot,
+  languages: HashMap<String, LanguageSlot>,
+  lazy_loader: Option<LazyLoader>,
+}
+
+/// Languages loaded or indexed while building a translator, before the default one is set apart.
+#[derive(Default)]
+pub(crate) struct LanguageSlots {
+  languages: HashMap<String, LanguageSlot>,
+}
+
+struct LanguageSlot {
+  identifier: LanguageIdentifier,
+  loaded: OnceLock<LoadedLanguage>,
+}
+
+impl LanguageSlot {
+  fn loaded(bundle: Bundle, resources: Vec<LoadedResource>) -> Self {
+    Self {
+      identifier: bundle.locales.first().cloned().unwrap_or_default(),
+      loaded: OnceLock::from(LoadedLanguage { bundle, resources }),
+    }
+  }
+}
+
+/// What is needed to load an indexed language: where its files are, the options the translator was built
+/// with and the common resources, which are parsed once for every language.
+pub(crate) struct LazyLoader {
+  pub(crate) directory_path: PathBuf,
+  pub(crate) overlay_directories: Vec<PathBuf>,
+  /// Common resources of the translations directory, then of every overlay directory.
+  pub(crate) common_resources: Vec<Vec<LoadedResource>>,
+  pub(crate) options: TranslatorOptions,
+}
+
+impl fmt::Debug for LoadedLanguages {
+  fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut languages: Vec<(&str, bool)> = self
+      .slots()
+      .map(|(name, slot)| (name, slot.loaded.get().is_some()))
+      .collect();
+    languages.sort_unstable();
+
+    formatter.debug_map().entries(languages).finish()
+  }
+}
+
+/// Indexes the languages of a translations directory and of the directories loaded on top of it, given in
+/// that order, without loading any of them.
+pub(crate) fn index_languages(
+  indexes: Vec<DirectoryIndex>,
+  options: &TranslatorOptions,
+) -> Result<(LanguageSlots, LazyLoader), TranslatorError> {
+  check_namespace_separator(options)?;
+
+  let mut language_slots = LanguageSlots::default();
+  let mut directories = Vec::new();
+  let mut common_resources = Vec::new();
+
+  for (index, directory_index) in indexes.into_iter().enumerate() {
+    let common_files = namespace_files(None, directory_index.common, options);
+    common_resources.push(locate_files(
+      parse_common_files(common_files, index > 0),
+      &directory_index.directory,
+      None,
+    ));
+
+    for language_name in directory_index.language_names {
+      if language_slots.contains_key(&language_name) {
+        continue;
+      }
+
+      if let Some(language_identifier) = options.language_parser.parse(&language_name) {
+        language_slots.index(language_name, language_identifier);
+      }
+    }
+
+    directories.push(directory_index.directory);
+  }
+
+  info!("Successfully indexed {} languages", language_slots.len());
+
+  let mut directories = directories.into_iter();
+
+  let lazy_loader = LazyLoader {
+    directory_path: directories.next().unwrap_or_default(),
+    overlay_directories: directories.collect(),
+    common_resources,
+    options: options.clone(),
+  };
+
+  Ok((language_slots, lazy_loader))
+}
+
+impl LanguageSlots {
+  pub(crate) fn insert(
+    &mut self,
+    language: String,
+    bundle: Bundle,
+    resources: Vec<LoadedResource>,
+  ) {
+    self
+      .languages
+      .insert(language, LanguageSlot::loaded(bundle, resources));
+  }
+
+  /// Adds a language which is loaded on first use by the lazy loader.
+  pub(crate) fn index(&mut self, language: String, identifier: LanguageIdentifier) {
+    self.languages.insert(
+      language,
+      LanguageSlot {
+        identifier,
+        loaded: OnceLock::new(),
+      },
+    );
+  }
+
+  pub(crate) fn contains_key(&self, language: &str) -> bool {
+    self.languages.contains_key(language)
+  }
+
+  /// Identifier of a language which was loaded or indexed.
+  #[cfg(feature = "tokio")]
+  pub(crate) fn identifier(&self, language: &str) -> Option<LanguageIdentifier> {
+    self
+      .languages
+      .get(language)
+      .map(|slot| slot.identifier.clone())
+  }
+
+  pub(crate) fn len(&self) -> usize {
+    self.languages.len()
+  }
+
+  /// Sets the default language apart, returning `None` when it is neither loaded nor indexed.
+  /// With a lazy loader, the default language is loaded right away.
+  pub(crate) fn into_languages(
+    mut self,
+    default_language: &str,
+    lazy_loader: Option<LazyLoader>,
+  ) -> Option<LoadedLanguages> {
+    let (default_language, default_slot) = self.languages.remove_entry(default_language)?;
+
+    let languages = LoadedLanguages {
+      default_language,
+      default_slot,
+      languages: self.languages,
+      lazy_loader,
+    };
+
+    languages.default_bundle();
+
+    Some(languages)
+  }
+}
+
+impl LoadedLanguages {
+  /// Languages with only the default one, already loaded.
+  pub(crate) fn with_default(
+    language: String,
+    bundle: Bundle,
+    resources: Vec<LoadedResource>,
+  ) -> Self {
+    Self {
+      default_language: language,
+      default_slot: LanguageSlot::loaded(bundle, resources),
+      languages: HashMap::new(),
+      lazy_loader: None,
+    }
+  }
+
+  /// Adds or replaces a language, the default one included.
+  pub(crate) fn insert(
+    &mut self,
+    language: String,
+    bundle: Bundle,
+    resources: Vec<LoadedResource>,
+  ) {
+    let slot = LanguageSlot::loaded(bundle, resources);
+
+    if language == self.default_language {
+      self.default_slot = slot;
+    } else {
+      self.languages.insert(language, slot);
+    }
+  }
+
+  /// Removes a language, loaded or only indexed, returning whether it existed.
+  /// The default language is never removed.
+  pub(crate) fn remove(&mut self, language: &str) -> bool {
+    self.languages.remove(language).is_some()
+  }
+
+  /// Keeps the options used to load indexed languages in sync with the ones of the translator.
+  pub(crate) fn set_options(&mut self, options: &TranslatorOptions) {
+    if let Some(lazy_loader) = &mut self.lazy_loader {
+      lazy_loader.options = options.clone();
+    }
+  }
+
+  pub(crate) fn contains_key(&self, language: &str) -> bool {
+    self.slot(language).is_some()
+  }
+
+  /// The code of a language as stored, so it can be borrowed for as long as the translator.
+  pub(crate) fn key(&self, language: &str) -> Option<&str> {
+    self.slot(language).map(|(name, _)| name)
+  }
+
+  /// Codes of every language, loaded or only indexed, in no particular order.
+  pub(crate) fn keys(&self) -> impl Iterator<Item = &str> {
+    self.slots().map(|(name, _)| name)
+  }
+
+  /// Identifiers of every language, loaded or only indexed, without loading them.
+  pub(crate) fn identifiers(&self) -> impl Iterator<Item = (&str, &LanguageIdentifier)> {
+    self.slots().map(|(name, slot)| (name, &slot.identifier))
+  }
+
+  /// Returns a language, loading it first when it was only indexed.
+  pub(crate) fn get(&self, language: &str) -> Option<&LoadedLanguage> {
+    self.get_key_value(language).map(|(_, loaded)| loaded)
+  }
+
+  pub(crate) fn get_key_value(&self, language: &str) -> Option<(&str, &LoadedLanguage)> {
+    let (name, slot) = self.slot(language)?;
+
+    Some((name, self.load_slot(name, slot)))
+  }
+
+  /// The code and bundle of the default language, loading it first when it was only indexed.
+  pub(crate) fn default_bundle(&self) -> (&str, &Bundle) {
+    let name = self.default_language.as_str();
+
+    (name, &self.load_slot(name, &self.default_slot).bundle)
+  }
+
+  /// Returns a language only when it was already loaded.
+  pub(crate) fn loaded(&self, language: &str) -> Option<&LoadedLanguage> {
+    self.slot(language)?.1.loaded.get()
+  }
+
+  pub(crate) fn bundle(&self, language: &str) -> Option<&Bundle> {
+    self.get(language).map(|loaded| &loaded.bundle)
+  }
+
+  pub(crate) fn resources(&self, language: &str) -> Option<&[LoadedResource]> {
+    self.get(language).map(|loaded| loaded.resources.as_slice())
+  }
+
+  /// Every language, loading the ones which were only indexed, in no particular order.
+  pub(crate) fn iter(&self) -> impl Iterator<Item = (&str, &LoadedLanguage)> {
+    self
+      .keys()
+      .filter_map(|language| self.get_key_value(language))
+  }
+
+  /// Bundles of the languages loaded so far. Indexed languages are built with the current options instead.
+  pub(crate) fn loaded_bundles_mut(&mut self) -> impl Iterator<Item = &mut Bundle> {
+    std::iter::once(&mut self.default_slot)
+      .chain(self.languages.values_mut())
+      .filter_map(|slot| slot.loaded.get_mut())
+      .map(|loaded| &mut loaded.bundle)
+  }
+
+  /// Replaces the bundle of a language, keeping its resources.
+  pub(crate) fn set_bundle(&mut self, language: &str, bundle: Bundle) {
+    if self.get(language).is_none() {
+      return;
+    }
+
+    let slot = if language == self.default_language {
+      Some(&mut self.default_slot)
+    } else {
+      self.languages.get_mut(language)
+    };
+
+    if let Some(loaded) = slot.and_then(|slot| slot.loaded.get_mut()) {
+      loaded.bundle = bundle;
+    }
+  }
+
+  fn slot(&self, language: &str) -> Option<(&str, &LanguageSlot)> {
+    if language == self.default_language {
+      return Some((self.default_language.as_str(), &self.default_slot));
+    }
+
+    self
+      .languages
+      .get_key_value(language)
+      .map(|(name, slot)| (name.as_str(), slot))
+  }
+
+  fn slots(&self) -> impl Iterator<Item = (&str, &LanguageSlot)> {
+    std::iter::once((self.default_language.as_str(), &self.default_slot)).chain(
+      self
+        .languages
+        .iter()
+        .map(|(name, slot)| (name.as_str(), slot)),
+    )
+  }
+
+  fn load_slot<'slot>(
+    &'slot self,
+    language: &str,
+    slot: &'slot LanguageSlot,
+  ) -> &'slot LoadedLanguage {
+    slot
+      .loaded
+      .get_or_init(|| self.load(language, slot.identifier.clone()))
+  }
+
+  fn load(&self, language: &str, identifier: LanguageIdentifier) -> LoadedLanguage {
+    let Some(lazy_loader) = &self.lazy_loader else {
+      warn!("Language {language} was indexed without a way to load it");
+
+      return LoadedLanguage {
+        bundle: Bundle::new_concurrent(vec![identifier]),
+        resources: Vec::new(),
+      };
+    };
+
+    info!("Loading language {language} on first use");
+
+    lazy_loader.load_files(language, identifier, lazy_loader.read_files(language))
+  }
+}
+
+impl LazyLoader {
+  /// Translations directory, then every overlay directory, in the order their files are loaded.
+  pub(crate) fn directories(&self) -> impl Iterator<Item = &PathBuf> {
+    std::iter::once(&self.directory_path).chain(&self.overlay_directories)
+  }
+
+  /// Reads the files of a language from every directory, a directory which cannot be read providing none.
+  fn read_files(&self, language: &str) -> Vec<Vec<FileData>> {
+    self
+      .directories()
+      .map(|directory| {
+        read_single_language_files(directory, language, &self.options).unwrap_or_else(|error| {
+          warn!(
+            "Could not read language {language} from {}: {error}",
+            directory.display()
+          );
+          Vec::new()
+        })
+      })
+      .collect()
+  }
+
+  /// Parses the files of a language read from every directory, in the order of `directories`, and builds
+  /// its bundle.
+  pub(crate) fn load_files(
+    &self,
+    language: &str,
+    identifier: LanguageIdentifier,
+    directory_files: Vec<Vec<FileData>>,
+  ) -> LoadedLanguage {
+    let options = &self.options;
+    let mut resources = Vec::new();
+
+    for (index, ((directory, common_resources), files)) in self
+      .directories()
+      .zip(&self.common_resources)
+      .zip(directory_files)
+      .enumerate()
+    {
+      let overriding = index > 0;
+
+      let files = namespace_files(Some(language), files, options);
+      let language_resources = locate_files(
+        parse_files(language, files, overriding),
+        directory,
+        Some(language),
+      );
+
+      // The common files of the translations directory fill the gaps of the language, while the ones of
+      // overlays are loaded before the language files of the overlay, like when loading every language.
+      if overriding {
+        resources.extend(common_resources.iter().cloned());
+        resources.extend(language_resources);
+      } else {
+        resources.extend(language_resources);
+        resources.extend(common_resources.iter().cloned());
+      }
+    }
+
+    let (bundle, conflicts) = build_bundle(identifier, &resources, language, options);
+
+    log_conflicts(&conflicts);
+    log_missing_terms(&missing_terms(language, &resources));
+
+    LoadedLanguage { bundle, resources }
+  }
+}
@@ -0,0 +1,794 @@
+use crate::{
+  formatting::add_builtin_functions,
+  terms::{is_term_file, log_missing_terms, missing_terms},
+  Bundle, ConflictPolicy, FileFilter, LoadError, TranslatorError, TranslatorOptions,
+};
+
+use std::{
+  collections::{HashMap, HashSet},
+  env,
+  fs::{self, DirEntry, ReadDir},
+  io::{Error as IoError, ErrorKind},
+  num::NonZeroUsize,
+  panic,
+  path::{Path, PathBuf},
+  sync::Arc,
+  thread,
+};
+
+use fluent_bundle::{FluentError, FluentResource};
+use fluent_syntax::{ast::Entry, parser::ParserError};
+use unic_langid::LanguageIdentifier;
+
+pub(crate) type FileData = (String, String);
+pub(crate) type LanguageFiles = HashMap<String, (LanguageIdentifier, Vec<FileData>)>;
+
+/// Files read from a translations directory.
+#[derive(Debug, Default)]
+pub(crate) struct TranslationFiles {
+  /// Directory the files were read from.
+  pub(crate) directory: PathBuf,
+  pub(crate) languages: LanguageFiles,
+  /// Files of the common directory, shared by every language.
+  pub(crate) common: Vec<FileData>,
+}
+
+impl TranslationFiles {
+  /// Adds the files of the common directory, see `common_file_names`.
+  pub(crate) fn add_common_files(&mut self, common_directory: &str, files: Vec<FileData>) {
+    self
+      .common
+      .extend(common_file_names(common_directory, files));
+  }
+
+  /// Adds files of a language, from its directory or from its top level file. The top level file goes
+  /// first, see `locate_files`.
+  pub(crate) fn add_language_files(
+    &mut self,
+    language_name: String,
+    language_identifier: LanguageIdentifier,
+    files: Vec<FileData>,
+    top_level: bool,
+  ) {
+    let (_, language_files) = self
+      .languages
+      .entry(language_name)
+      .or_insert_with(|| (language_identifier, Vec::new()));
+
+    if top_level {
+      language_files.splice(0..0, files);
+    } else {
+      language_files.extend(files);
+    }
+  }
+}
+
+/// A parsed translation file kept by the translator so bundles can be rebuilt and inspected.
+#[derive(Debug, Clone)]
+pub(crate) struct LoadedResource {
+  pub(crate) resource: Arc<FluentResource>,
+  pub(crate) file_name: String,
+  /// Path of the file on disk, `None` for sources which do not come from a directory.
+  pub(crate) path: Option<PathBuf>,
+  /// Whether the resource replaces messages defined by earlier resources instead of being ignored for them.
+  pub(crate) overriding: bool,
+  /// Whether the resource comes from the common directory, so its messages only fill the gaps left by the
+  /// files of the language and are not reported as conflicts.
+  pub(crate) common: bool,
+  pub(crate) parse_errors: Vec<ParserError>,
+}
+
+/// Reads every translation file of a translations directory, grouped by language, along with the files of
+/// the common directory. Language and common directories may be nested, their files are named by their path
+/// relative to the directory, such as `settings/profile.ftl`.
+pub(crate) fn read_language_files(
+  directory_path: &Path,
+  options: &TranslatorOptions,
+) -> Result<TranslationFiles, TranslatorError> {
+  let translations_directory = read_directory(directory_path)?;
+
+  let mut translation_files = TranslationFiles {
+    directory: directory_path.to_path_buf(),
+    ..TranslationFiles::default()
+  };
+
+  for directory_entry_result in translations_directory {
+    let Some((entry, entry_name, is_dir)) = read_entry(directory_entry_result) else {
+      continue;
+    };
+
+    match classify_entry(&entry_name, is_dir, options) {
+      Some(TopLevelEntry::Common) => {
+        let common_files = read_nested_files(&entry.path(), &options.file_filter)?;
+        translation_files.add_common_files(&entry_name, common_files);
+      }
+      Some(TopLevelEntry::LanguageDirectory(language_name, language_identifier)) => {
+        let files = read_nested_files(&entry.path(), &options.file_filter)?;
+        translation_files.add_language_files(language_name, language_identifier, files, false);
+      }
+      Some(TopLevelEntry::LanguageFile(language_name, language_identifier)) => {
+        let files = get_file_data(&entry.path(), entry_name)
+          .into_iter()
+          .collect();
+        translation_files.add_language_files(language_name, language_identifier, files, true);
+      }
+      None => {}
+    }
+  }
+
+  Ok(translation_files)
+}
+
+/// Names of the languages of a translations directory in alphabetical order, leaving out the common
+/// directory and names which are not valid language identifiers.
+pub(crate) fn read_language_names(
+  directory_path: &Path,
+  options: &TranslatorOptions,
+) -> Result<Vec<String>, TranslatorError> {
+  let mut language_names = Vec::new();
+
+  for directory_entry_result in read_directory(directory_path)? {
+    let Some((_, entry_name, is_dir)) = read_entry(directory_entry_result) else {
+      continue;
+    };
+
+    language_names.extend(language_name(classify_entry(&entry_name, is_dir, options)));
+  }
+
+  Ok(sorted_language_names(language_names))
+}
+
+/// Reads the files of a single language of a translations directory, its directory and its top level file.
+pub(crate) fn read_single_language_files(
+  directory_path: &Path,
+  language: &str,
+  options: &TranslatorOptions,
+) -> Result<Vec<FileData>, TranslatorError> {
+  let mut files = Vec::new();
+
+  for directory_entry_result in read_directory(directory_path)? {
+    let Some((entry, entry_name, is_dir)) = read_entry(directory_entry_result) else {
+      continue;
+    };
+
+    match classify_entry(&entry_name, is_dir, options) {
+      Some(TopLevelEntry::LanguageDirectory(language_name, _)) if language_name == language => {
+        files.extend(read_nested_files(&entry.path(), &options.file_filter)?);
+      }
+      Some(TopLevelEntry::LanguageFile(language_name, _)) if language_name == language => {
+        files.splice(0..0, get_file_data(&entry.path(), entry_name));
+      }
+      _ => {}
+    }
+  }
+
+  Ok(files)
+}
+
+/// What lazy loading needs to know about a translations directory before any language is used.
+#[derive(Debug, Default)]
+pub(crate) struct DirectoryIndex {
+  pub(crate) directory: PathBuf,
+  /// Names of the languages of the directory, see `read_language_names`.
+  pub(crate) language_names: Vec<String>,
+  /// Files of the common directory, shared by every language.
+  pub(crate) common: Vec<FileData>,
+}
+
+/// Reads the language names and common files of a translations directory to index its languages.
+pub(crate) fn read_directory_index(
+  directory_path: &Path,
+  options: &TranslatorOptions,
+) -> Result<DirectoryIndex, TranslatorError> {
+  Ok(DirectoryIndex {
+    directory: directory_path.to_path_buf(),
+    language_names: read_language_names(directory_path, options)?,
+    common: read_common_files(directory_path, options)?,
+  })
+}
+
+/// Reads the files of the common directory of a translations directory, if it has one.
+pub(crate) fn read_common_files(
+  directory_path: &Path,
+  options: &TranslatorOptions,
+) -> Result<Vec<FileData>, TranslatorError> {
+  let Some(common_directory) = &options.common_directory else {
+    return Ok(Vec::new());
+  };
+
+  let common_path = directory_path.join(common_directory);
+
+  if !common_path.is_dir() {
+    return Ok(Vec::new());
+  }
+
+  let files = read_nested_files(&common_path, &options.file_filter)?;
+
+  Ok(common_file_names(common_directory, files))
+}
+
+/// Names the files of the common directory by their path relative to the translations directory.
+pub(crate) fn common_file_names(common_directory: &str, files: Vec<FileData>) -> Vec<FileData> {
+  files
+    .into_iter()
+    .map(|(content, file_name)| (content, format!("{common_directory}/{file_name}")))
+    .collect()
+}
+
+/// Parses the files of the common directory, once for every language.
+pub(crate) fn parse_common_files(files: Vec<FileData>, overriding: bool) -> Vec<LoadedResource> {
+  parse_files("common", files, overriding)
+    .into_iter()
+    .map(|loaded_resource| LoadedResource {
+      common: true,
+      ..loaded_resource
+    })
+    .collect()
+}
+
+/// Remembers where the files of a language, or the common files with `None`, were read from. Language
+/// files are named relative to their language directory, except for the top level `<language>.ftl` file,
+/// and common files relative to the translations directory. A `<language>.ftl` file may also be inside the
+/// language directory, the top level one is read first so it is the first resource with that name.
+pub(crate) fn locate_files(
+  mut resources: Vec<LoadedResource>,
+  directory: &Path,
+  language_name: Option<&str>,
+) -> Vec<LoadedResource> {
+  let mut is_top_level_file_located = false;
+
+  for loaded_resource in &mut resources {
+    let file_name = loaded_resource.file_name.as_str();
+
+    let path = match language_name {
+      Some(language_name)
+        if top_level_language_name(file_name) == Some(language_name)
+          && !is_top_level_file_located
+          && directory.join(file_name).is_file() =>
+      {
+        is_top_level_file_located = true;
+        directory.join(file_name)
+      }
+      Some(language_name) => directory.join(language_name).join(file_name),
+      None => directory.join(file_name),
+    };
+
+    loaded_resource.path = Some(path);
+  }
+
+  resources
+}
+
+/// Parses the files of a language in file name order, term files first. Corrupt entries are skipped while
+/// the rest of the file is kept, and the parser errors are remembered for auditing.
+pub(crate) fn parse_files(
+  language_name: &str,
+  mut files: Vec<FileData>,
+  overriding: bool,
+) -> Vec<LoadedResource> {
+  files.sort_by(|(_, first_name), (_, second_name)| {
+    (!is_term_file(first_name), first_name).cmp(&(!is_term_file(second_name), second_name))
+  });
+
+  files
+    .into_iter()
+    .map(|(content, file_name)| {
+      let (resource, parse_errors) = match FluentResource::try_new(content) {
+        Ok(resource) => (resource, Vec::new()),
+        Err((resource, parse_errors)) => {
+          warn!("Corrupt entry found in file {file_name} from langauge {language_name}");
+          (resource, parse_errors)
+        }
+      };
+
+      LoadedResource {
+        resource: Arc::new(resource),
+        file_name,
+        path: None,
+        overriding,
+        common: false,
+        parse_errors,
+      }
+    })
+    .collect()
+}
+
+/// Applies `load` to every language, keeping their order. When parallel, the languages are split in chunks
+/// loaded on scoped threads, one per available core.
+pub(crate) fn map_languages<Input, Output, Load>(
+  languages: Vec<Input>,
+  parallel: bool,
+  load: Load,
+) -> Vec<Output>
+where
+  Input: Send,
+  Output: Send,
+  Load: Fn(Input) -> Output + Sync,
+{
+  let workers = thread::available_parallelism()
+    .map_or(1, NonZeroUsize::get)
+    .min(languages.len());
+
+  if !parallel || workers <= 1 {
+    return languages.into_iter().map(load).collect();
+  }
+
+  let chunk_size = languages.len().div_ceil(workers);
+  let mut languages = languages.into_iter().peekable();
+  let mut chunks = Vec::with_capacity(workers);
+
+  while languages.peek().is_some() {
+    chunks.push(languages.by_ref().take(chunk_size).collect::<Vec<Input>>());
+  }
+
+  debug!("Loading languages on {} threads", chunks.len());
+
+  thread::scope(|scope| {
+    let handles: Vec<_> = chunks
+      .into_iter()
+      .map(|chunk| scope.spawn(|| chunk.into_iter().map(&load).collect::<Vec<Output>>()))
+      .collect();
+
+    handles
+      .into_iter()
+      .flat_map(|handle| {
+        handle
+          .join()
+          .unwrap_or_else(|payload| panic::resume_unwind(payload))
+      })
+      .collect()
+  })
+}
+
+/// Creates a bundle holding the given resources, in order, along with the messages and terms which were
+/// ignored because an earlier resource already defined them.
+pub(crate) fn build_bundle(
+  language_identifier: LanguageIdentifier,
+  resources: &[LoadedResource],
+  language_name: &str,
+  options: &TranslatorOptions,
+) -> (Bundle, Vec<LoadError>) {
+  let mut bundle = new_bundle(language_identifier, options);
+  let mut conflicts = Vec::new();
+
+  if options.conflict_policy == ConflictPolicy::KeepLast {
+    let mut resources: Vec<&LoadedResource> = resources.iter().collect();
+    // Common resources come last to fill gaps, so they have to be added first to be overridden instead.
+    resources.sort_by_key(|loaded_resource| (loaded_resource.overriding, !loaded_resource.common));
+
+    for loaded_resource in resources {
+      bundle.add_resource_overriding(Arc::clone(&loaded_resource.resource));
+    }
+
+    return (bundle, conflicts);
+  }
+
+  for loaded_resource in resources {
+    let resource = Arc::clone(&loaded_resource.resource);
+
+    if loaded_resource.overriding {
+      bundle.add_resource_overriding(resource);
+      continue;
+    }
+
+    let Err(errors) = bundle.add_resource(resource) else {
+      continue;
+    };
+
+    if loaded_resource.common {
+      continue;
+    }
+
+    let mut overridden_ids = Vec::new();
+
+    for error in &errors {
+      match error {
+        FluentError::Overriding { id, .. } => overridden_ids.push(id.as_str()),
+        error => warn!(
+          "Could not add resource from file {} from language {language_name}: {error}",
+          loaded_resource.file_name
+        ),
+      }
+    }
+
+    conflicts.extend(
+      ignored_entry_ids(&loaded_resource.resource, &overridden_ids)
+        .into_iter()
+        .map(|id| LoadError::Conflict {
+          language: language_name.to_string(),
+          file_name: loaded_resource.file_name.clone(),
+          id,
+        }),
+    );
+  }
+
+  (bundle, conflicts)
+}
+
+/// Identifiers of the entries of a resource a bundle ignored, terms starting with `-`, in the order they
+/// are defined. Fluent reports them by name only, and messages and terms share names in a bundle, so the
+/// kind of every ignored entry is read from the resource: all the entries of a name are ignored when an
+/// earlier resource defined it, and all but the first otherwise.
+fn ignored_entry_ids(resource: &FluentResource, overridden_ids: &[&str]) -> Vec<String> {
+  let entries: Vec<(&str, bool)> = resource
+    .entries()
+    .filter_map(|entry| match entry {
+      Entry::Message(message) => Some((message.id.name, false)),
+      Entry::Term(term) => Some((term.id.name, true)),
+      _ => None,
+    })
+    .filter(|(id, _)| overridden_ids.contains(id))
+    .collect();
+
+  let mut kept_ids = HashSet::new();
+
+  entries
+    .iter()
+    .filter(|(id, _)| {
+      let ignored_count = overridden_ids
+        .iter()
+        .filter(|ignored| *ignored == id)
+        .count();
+      let defined_count = entries.iter().filter(|(other, _)| other == id).count();
+
+      ignored_count == defined_count || !kept_ids.insert(*id)
+    })
+    .map(|(id, is_term)| {
+      if *is_term {
+        format!("-{id}")
+      } else {
+        id.to_string()
+      }
+    })
+    .collect()
+}
+
+/// Creates an empty bundle for a language, configured with the translator options.
+pub(crate) fn new_bundle(
+  language_identifier: LanguageIdentifier,
+  options: &TranslatorOptions,
+) -> Bundle {
+  let use_isolating = options.bidi_isolation.isolates(&language_identifier);
+  let mut bundle = Bundle::new_concurrent(vec![language_identifier]);
+
+  bundle.set_use_isolating(use_isolating);
+  bundle.set_transform(options.transform);
+
+  for (name, function) in &options.functions.0 {
+    let function = Arc::clone(function);
+
+    if let Err(error) =
+      bundle.add_function(name, move |positional, named| function(positional, named))
+    {
+      warn!("Could not add function {name}: {error}");
+    }
+  }
+
+  add_builtin_functions(&mut bundle);
+
+  bundle
+}
+
+/// Lists the syntax errors of the given resources.
+pub(crate) fn parse_load_errors(
+  language_name: &str,
+  resources: &[LoadedResource],
+) -> Vec<LoadError> {
+  resources
+    .iter()
+    .flat_map(|loaded_resource| {
+      loaded_resource.parse_errors.iter().map(|parse_error| {
+        let (line, column) = line_column(loaded_resource.resource.source(), parse_error.pos.start);
+
+        LoadError::Parse {
+          language: language_name.to_string(),
+          file_name: loaded_resource.file_name.clone(),
+          line,
+          column,
+          source: parse_error.clone(),
+        }
+      })
+    })
+    .collect()
+}
+
+/// Line and column, both starting at 1, of a byte offset of a source.
+fn line_column(source: &str, offset: usize) -> (usize, usize) {
+  let before = source.get(..offset).unwrap_or(source);
+  let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+  (
+    before.matches('\n').count() + 1,
+    before[line_start..].chars().count() + 1,
+  )
+}
+
+/// Logs the messages and terms which were ignored because an earlier resource already defined them.
+pub(crate) fn log_conflicts(conflicts: &[LoadError]) {
+  for conflict in conflicts {
+    if let LoadError::Conflict {
+      language,
+      file_name,
+      id,
+    } = conflict
+    {
+      warn!(
+        "Ignoring {id} from file {file_name} from language {language} as it was already defined by a previous file"
+      );
+    }
+  }
+}
+
+/// Errors found while loading a language which must fail the load with the translator options. The ones
+/// which do not, conflicts and missing terms, are logged instead.
+pub(crate) fn language_load_errors(
+  language_name: &str,
+  resources: &[LoadedResource],
+  conflicts: Vec<LoadError>,
+  options: &TranslatorOptions,
+) -> Vec<LoadError> {
+  let mut load_errors = Vec::new();
+
+  if options.strict {
+    load_errors.extend(parse_load_errors(language_name, resources));
+  }
+
+  if options.strict || options.conflict_policy == ConflictPolicy::Error {
+    load_errors.extend(conflicts);
+  } else {
+    log_conflicts(&conflicts);
+  }
+
+  let missing_terms = missing_terms(language_name, resources);
+
+  if options.strict {
+    load_errors.extend(missing_terms);
+  } else {
+    log_missing_terms(&missing_terms);
+  }
+
+  load_errors
+}
+
+/// Directories loaded on top of the translations directory, in priority order: the layers, then the
+/// development overlay.
+pub(crate) fn overlay_directories(options: &TranslatorOptions) -> Vec<PathBuf> {
+  let mut directories: Vec<PathBuf> = options.layers.iter().map(PathBuf::from).collect();
+
+  if let Some(overlay_directory) = options
+    .dev_overlay_env
+    .as_deref()
+    .and_then(dev_overlay_directory)
+  {
+    info!(
+      "Loading development overlay from {}",
+      overlay_directory.display()
+    );
+
+    directories.push(overlay_directory);
+  }
+
+  directories
+}
+
+/// Resolves the development overlay directory from an environment variable, if it is set.
+fn dev_overlay_directory(variable: &str) -> Option<PathBuf> {
+  let directory = PathBuf::from(env::var_os(variable)?);
+
+  if directory.is_dir() {
+    return Some(directory);
+  }
+
+  warn!(
+    "Ignoring development overlay as {variable} points to {}, which is not a directory",
+    directory.display()
+  );
+
+  None
+}
+
+fn read_directory(path: &Path) -> Result<ReadDir, TranslatorError> {
+  fs::read_dir(path).map_err(|error| directory_error(path, error))
+}
+
+/// Turns an error reading a directory into the matching translator error.
+pub(crate) fn directory_error(path: &Path, error: IoError) -> TranslatorError {
+  let path = path.to_string_lossy().to_string();
+
+  match error.kind() {
+    ErrorKind::NotFound => TranslatorError::DirectoryNotFound { path },
+    ErrorKind::PermissionDenied => TranslatorError::DirectoryNotReadable { path },
+    _ => TranslatorError::ReadDirError {
+      directory_path: path,
+      source: error,
+    },
+  }
+}
+
+/// A top level entry of a translations directory, classified from its name alone so the synchronous and
+/// asynchronous loaders read the same entries.
+pub(crate) enum TopLevelEntry {
+  /// The common directory, holding files shared by every language.
+  Common,
+  /// `<language>/`, a directory holding any number of translation files.
+  LanguageDirectory(String, LanguageIdentifier),
+  /// `<language>.ftl`, a single translation file placed next to the language directories.
+  LanguageFile(String, LanguageIdentifier),
+}
+
+/// Classifies a top level entry of a translations directory, returning `None` for the entries which are
+/// skipped: stray files, files left out by the file filter, languages left out by the language filter and
+/// names which are not valid language identifiers.
+pub(crate) fn classify_entry(
+  entry_name: &str,
+  is_dir: bool,
+  options: &TranslatorOptions,
+) -> Option<TopLevelEntry> {
+  if is_dir && options.common_directory.as_deref() == Some(entry_name) {
+    debug!("Found common translations directory {entry_name}");
+    return Some(TopLevelEntry::Common);
+  }
+
+  let language_name = if is_dir {
+    entry_name
+  } else if let Some(language_name) = top_level_language_name(entry_name) {
+    if !options.file_filter.accepts(entry_name) {
+      debug!("Skipping {entry_name} as it is not a translation file");
+      return None;
+    }
+
+    language_name
+  } else {
+    debug!("Skipping {entry_name} as it is neither a language directory nor a translation file");
+    return None;
+  };
+
+  if !options.language_filter.accepts(language_name) {
+    debug!("Skipping language {language_name} as it is filtered out");
+    return None;
+  }
+
+  let Some(language_identifier) = options.language_parser.parse(language_name) else {
+    warn!(
+      "Ignoring {} as it is not a valid langugae identifier",
+      language_name
+    );
+    return None;
+  };
+
+  let language_name = language_name.to_string();
+
+  if is_dir {
+    Some(TopLevelEntry::LanguageDirectory(
+      language_name,
+      language_identifier,
+    ))
+  } else {
+    debug!("Found top level translation file for {language_name}");
+    Some(TopLevelEntry::LanguageFile(
+      language_name,
+      language_identifier,
+    ))
+  }
+}
+
+/// Name of the language a classified entry provides translations for.
+pub(crate) fn language_name(entry: Option<TopLevelEntry>) -> Option<String> {
+  match entry? {
+    TopLevelEntry::LanguageDirectory(language_name, _)
+    | TopLevelEntry::LanguageFile(language_name, _) => Some(language_name),
+    TopLevelEntry::Common => None,
+  }
+}
+
+/// Sorts language names, a language having both a directory and a top level file being listed once.
+pub(crate) fn sorted_language_names(mut language_names: Vec<String>) -> Vec<String> {
+  language_names.sort();
+  language_names.dedup();
+  language_names
+}
+
+/// Name of an entry of a directory and whether it is a directory, logging the entries which cannot be read.
+fn read_entry(
+  directory_entry_result: Result<DirEntry, IoError>,
+) -> Option<(DirEntry, String, bool)> {
+  let Ok(entry) = directory_entry_result else {
+    warn!("One directory could not be read");
+    return None;
+  };
+
+  let entry_name = entry.file_name().to_string_lossy().to_string();
+
+  let Ok(is_dir) = is_directory(&entry) else {
+    warn!("Could not check if {entry_name} is a directory");
+    return None;
+  };
+
+  Some((entry, entry_name, is_dir))
+}
+
+/// Language of a top level `<language>.ftl` file, or of a key-value file with the `serde` feature, or of
+/// a `<language>.po` file with the `gettext` feature.
+pub(crate) fn top_level_language_name(file_name: &str) -> Option<&str> {
+  #[cfg(feature = "gettext")]
+  if crate::gettext::is_po_file(file_name) {
+    return file_name.strip_suffix(".po");
+  }
+
+  #[cfg(feature = "serde")]
+  if crate::KeyValueFormat::from_file_name(file_name).is_some() {
+    return file_name
+      .rsplit_once('.')
+      .map(|(language_name, _)| language_name);
+  }
+
+  file_name.strip_suffix(".ftl")
+}
+
+fn is_directory(directory: &DirEntry) -> Result<bool, TranslatorError> {
+  let file_type = directory
+    .file_type()
+    .map_err(|source| TranslatorError::DirEntryError { source })?;
+
+  Ok(file_type.is_dir())
+}
+
+/// Reads every file below a directory, naming them by their path relative to it.
+pub(crate) fn read_nested_files(
+  directory_path: &Path,
+  file_filter: &FileFilter,
+) -> Result<Vec<FileData>, TranslatorError> {
+  let mut files = Vec::new();
+  let mut pending_directories = vec![(directory_path.to_path_buf(), String::new())];
+
+  while let Some((directory, prefix)) = pending_directories.pop() {
+    for entry_result in read_directory(&directory)? {
+      let Ok(entry) = entry_result else {
+        warn!("One file could not be read");
+        continue;
+      };
+
+      let file_name = format!("{prefix}{}", entry.file_name().to_string_lossy());
+
+      match is_directory(&entry) {
+        Ok(true) => pending_directories.push((entry.path(), format!("{file_name}/"))),
+        Ok(false) if !file_filter.accepts(&file_name) => {
+          debug!("Skipping {file_name} as it is not a translation file");
+        }
+        Ok(false) => files.extend(get_file_data(&entry.path(), file_name)),
+        Err(_) => warn!("Could not check if {file_name} is a directory"),
+      }
+    }
+  }
+
+  Ok(files)
+}
+
+/// Key-value files with the `serde` feature and gettext files with the `gettext` feature are converted
+/// into Fluent as they are read.
+pub(crate) fn ftl_source(file_name: &str, content: String) -> String {
+  let converters: &[fn(&str, String) -> String] = &[
+    #[cfg(feature = "serde")]
+    crate::key_value::ftl_source,
+    #[cfg(feature = "gettext")]
+    crate::gettext::ftl_source,
+  ];
+
+  converters
+    .iter()
+    .fold(content, |content, convert| convert(file_name, content))
+}
+
+fn get_file_data(path: &Path, file_name: String) -> Option<FileData> {
+  trace!("Loading file {file_name}");
+
+  let file_data_result = fs::read_to_string(path);
+
+  match file_data_result {
+    Ok(file_content) => Some((ftl_source(&file_name, file_content), file_name)),
+    Err(error) => {
+      warn!("Could not read file {file_name} because of the following error: {error}");
+      None
+    }
+  }
+}
@@ -0,0 +1,36 @@
+/// Level of a log event, emitted through `tracing` by default or through `log` with the `log` feature.
+/// Levels of either crate convert into it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogLevel {
+  Error,
+  Warn,
+  Info,
+  Debug,
+  Trace,
+}
+
+#[cfg(feature = "tracing")]
+impl From<tracing::Level> for LogLevel {
+  fn from(level: tracing::Level) -> Self {
+    match level {
+      tracing::Level::ERROR => Self::Error,
+      tracing::Level::WARN => Self::Warn,
+      tracing::Level::INFO => Self::Info,
+      tracing::Level::DEBUG => Self::Debug,
+      tracing::Level::TRACE => Self::Trace,
+    }
+  }
+}
+
+#[cfg(feature = "log")]
+impl From<log::Level> for LogLevel {
+  fn from(level: log::Level) -> Self {
+    match level {
+      log::Level::Error => Self::Error,
+      log::Level::Warn => Self::Warn,
+      log::Level::Info => Self::Info,
+      log::Level::Debug => Self::Debug,
+      log::Level::Trace => Self::Trace,
+    }
+  }
+}
@@ -0,0 +1,45 @@
+/// Defines a logging macro which emits through `tracing` by default, through `log` when only the `log`
+/// feature is enabled, and compiles to nothing, while still checking its format arguments, without either.
+macro_rules! logging_macro {
+  ($dollar:tt $name:ident) => {
+    #[cfg(feature = "tracing")]
+    macro_rules! $name {
+                                              ($dollar($dollar argument:tt)+) => {
+                                                tracing::$name!($dollar($dollar argument)+)
+                                              };
+                                            }
+
+    #[cfg(all(feature = "log", not(feature = "tracing")))]
+    macro_rules! $name {
+                                              ($dollar($dollar argument:tt)+) => {
+                                                log::$name!($dollar($dollar argument)+)
+                                              };
+                                            }
+
+    #[cfg(not(any(feature = "tracing", feature = "log")))]
+    macro_rules! $name {
+                                              ($dollar($dollar argument:tt)+) => {{
+                                                let _ = format_args!($dollar($dollar argument)+);
+                                              }};
+                                            }
+  };
+}
+
+logging_macro!($ error);
+logging_macro!($ warn);
+logging_macro!($ info);
+logging_macro!($ debug);
+logging_macro!($ trace);
+
+/// Emits a log event at a level only known at runtime.
+macro_rules! log_at {
+  ($level:expr, $($argument:tt)+) => {
+    match $level {
+      $crate::LogLevel::Error => error!($($argument)+),
+      $crate::LogLevel::Warn => warn!($($argument)+),
+      $crate::LogLevel::Info => info!($($argument)+),
+      $crate::LogLevel::Debug => debug!($($argument)+),
+      $crate::LogLevel::Trace => trace!($($argument)+),
+    }
+  };
+}
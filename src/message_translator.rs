@@ -1,65 +1,641 @@
-use crate::translator::{Bundle, TranslationKey};
+use crate::{
+  escape_html,
+  global_args::GlobalArgs,
+  observer::Observer,
+  translator::{write_pattern, Bundle, TranslationKey},
+  unknown_arguments::check_unknown_arguments,
+  IntoFluentArgs, LogLevel, MissingMessageHandler, MissingMessagePolicy, NumberOptions,
+  TranslationError, UnknownArgumentPolicy,
+};
 
-use fluent_bundle::{FluentArgs, FluentMessage, FluentValue};
-use std::borrow::Cow;
-use tracing::warn;
+use fluent_bundle::{FluentArgs, FluentError, FluentMessage, FluentValue};
+use fluent_syntax::ast::Pattern;
+use std::{borrow::Cow, fmt};
 
 pub const TRANSLATION_FAILED: &str = "An error has ocurred while trying to translate the message";
 
-pub struct MessageTranslator<'lifetime, TranslationKeyGeneric>
+/// Resolves the text of a message which can not be translated, following the missing message options.
+#[derive(Clone, Copy)]
+pub(crate) struct MissingText<'bundle> {
+  pub(crate) policy: MissingMessagePolicy,
+  pub(crate) handler: Option<&'bundle MissingMessageHandler>,
+  pub(crate) default_bundle: &'bundle Bundle,
+  pub(crate) language: &'bundle str,
+  pub(crate) global_args: &'bundle GlobalArgs,
+}
+
+impl<'bundle> MissingText<'bundle> {
+  pub(crate) fn text(
+    &self,
+    key: &'static str,
+    attribute: Option<&str>,
+    args: Option<&FluentArgs>,
+  ) -> Cow<'bundle, str> {
+    let id = || match attribute {
+      Some(attribute) => Cow::Owned(format!("{key}.{attribute}")),
+      None => Cow::Borrowed(key),
+    };
+
+    if let Some(handler) = self.handler {
+      return Cow::Owned(handler.handle(self.language, &id()));
+    }
+
+    match self.policy {
+      MissingMessagePolicy::FailureText => Cow::Borrowed(TRANSLATION_FAILED),
+      MissingMessagePolicy::Key => id(),
+      MissingMessagePolicy::Empty => Cow::Borrowed(""),
+      MissingMessagePolicy::DefaultLanguage => self
+        .default_language_text(key, attribute, args)
+        .map_or_else(id, Cow::Owned),
+    }
+  }
+
+  fn default_language_text(
+    &self,
+    key: &str,
+    attribute: Option<&str>,
+    args: Option<&FluentArgs>,
+  ) -> Option<String> {
+    let message = self.default_bundle.get_message(key)?;
+
+    let pattern = match attribute {
+      Some(attribute) => message.get_attribute(attribute)?.value(),
+      None => message.value()?,
+    };
+
+    let merged_args = self.global_args.merge(args);
+    let mut errors = Vec::new();
+    let text =
+      self
+        .default_bundle
+        .format_pattern(pattern, merged_args.as_ref().or(args), &mut errors);
+
+    errors.is_empty().then(|| text.into_owned())
+  }
+}
+
+pub struct MessageTranslator<'bundle, 'args, TranslationKeyGeneric>
 where
   TranslationKeyGeneric: TranslationKey,
 {
   pub key: TranslationKeyGeneric,
-  pub bundle: &'lifetime Bundle,
-  pub message: Option<FluentMessage<'lifetime>>,
-  pub args: Option<FluentArgs<'lifetime>>,
+  pub bundle: &'bundle Bundle,
+  pub message: Option<FluentMessage<'bundle>>,
+  pub args: Option<FluentArgs<'args>>,
+  pub identity: bool,
+  pub miss_level: LogLevel,
+  pub variant: Option<String>,
+  pub attribute: Option<String>,
+  pub fallback_attribute: Option<String>,
+  pub(crate) missing_text: MissingText<'bundle>,
+  /// Language of the bundle the message was found in.
+  pub(crate) language: &'bundle str,
+  pub(crate) observer: Option<&'bundle Observer>,
+  pub(crate) global_args: &'bundle GlobalArgs,
+  pub(crate) unknown_argument_policy: UnknownArgumentPolicy,
 }
 
-impl<'lifetime, Key> MessageTranslator<'lifetime, Key>
+/// Shows the key and the pending arguments, without the bundle.
+impl<Key> fmt::Debug for MessageTranslator<'_, '_, Key>
 where
   Key: TranslationKey,
 {
-  pub fn add_argument<P>(mut self, key: &'lifetime str, value: P) -> Self
+  fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    formatter
+      .debug_struct("MessageTranslator")
+      .field("key", &self.key.as_str())
+      .field("language", &self.language)
+      .field("found", &self.message.is_some())
+      .field("args", &self.args)
+      .field("variant", &self.variant)
+      .field("attribute", &self.attribute)
+      .field("fallback_attribute", &self.fallback_attribute)
+      .finish_non_exhaustive()
+  }
+}
+
+/// Formats the message like `build`, so it can be used directly in `format!` or `to_string`.
+impl<Key> fmt::Display for MessageTranslator<'_, '_, Key>
+where
+  Key: TranslationKey,
+{
+  fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    formatter.write_str(&self.build())
+  }
+}
+
+impl<'bundle, 'args, Key> MessageTranslator<'bundle, 'args, Key>
+where
+  Key: TranslationKey,
+{
+  /// ### Description
+  /// Adds an argument to the message, replacing a previous one with the same name. Names may be borrowed or
+  /// owned, so names built at runtime do not need to outlive the message.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let message = items.iter().enumerate().fold(
+  ///   translator.translate(language, TranslationKeys::Items),
+  ///   |message, (index, item)| message.add_argument(format!("item{index}"), item.name.clone()),
+  /// );
+  /// ```
+  pub fn add_argument<K, P>(mut self, key: K, value: P) -> Self
+  where
+    K: Into<Cow<'args, str>>,
+    P: Into<FluentValue<'args>>,
+  {
+    let args = self.args.unwrap_or_default();
+    self.args = Some(set_argument(args, key.into(), value.into()));
+    self
+  }
+
+  /// ### Description
+  /// Adds a string argument HTML-escaped, for web pages where user supplied values end up in translated
+  /// templates. Not needed when the translator was built with `escape_html_arguments`, which escapes every
+  /// string argument already.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let message = translator.translate(language, TranslationKeys::Welcome).add_argument_escaped("name", "<b>Alex</b>");
+  ///
+  /// assert_eq!(message.build(), "Welcome, \u{2068}&lt;b&gt;Alex&lt;/b&gt;\u{2069}");
+  /// ```
+  pub fn add_argument_escaped<K>(self, key: K, value: &str) -> Self
+  where
+    K: Into<Cow<'args, str>>,
+  {
+    self.add_argument(key, escape_html(value).into_owned())
+  }
+
+  /// ### Description
+  /// Adds a number argument formatted with the given options, such as a fixed number of fraction digits for
+  /// prices, without building Fluent number types by hand.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let options = NumberOptions { minimum_fraction_digits: Some(2), ..Default::default() };
+  ///
+  /// let message = translator.translate(language, TranslationKeys::Price).add_number_argument("price", 1234.5, options);
+  /// ```
+  pub fn add_number_argument<K>(self, key: K, value: impl Into<f64>, options: NumberOptions) -> Self
   where
-    P: Into<FluentValue<'lifetime>>,
+    K: Into<Cow<'args, str>>,
   {
-    let mut args = self.args.unwrap_or(FluentArgs::new());
-    args.set(key, value.into());
+    self.add_argument(key, options.value(value))
+  }
+
+  /// ### Description
+  /// Adds every argument of a `FluentArgs`, a `HashMap` or any list of name and value pairs at once, so
+  /// arguments can be built ahead of time. Arguments with the same name replace previous ones.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let arguments = HashMap::from([("name", "Alex"), ("city", "Lima")]);
+  ///
+  /// let message = translator.translate(language, TranslationKeys::Welcome).with_args(arguments);
+  /// ```
+  pub fn with_args<Args, Name, Value>(mut self, arguments: Args) -> Self
+  where
+    Args: IntoIterator<Item = (Name, Value)>,
+    Name: Into<Cow<'args, str>>,
+    Value: Into<FluentValue<'args>>,
+  {
+    let mut args = self.args.unwrap_or_default();
+    for (name, value) in arguments {
+      args = set_argument(args, name.into(), value.into());
+    }
     self.args = Some(args);
     self
   }
 
-  pub fn build(&self) -> Cow<str> {
+  /// ### Description
+  /// Adds the arguments of a value implementing `IntoFluentArgs`, such as a struct deriving it.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let message = translator.translate(language, key).with(UserContext { name: "Alex", count: 3 });
+  /// ```
+  pub fn with<Args>(self, arguments: Args) -> Self
+  where
+    Args: IntoFluentArgs<'args>,
+  {
+    self.with_args(arguments.into_fluent_args())
+  }
+
+  /// ### Description
+  /// Prefers the `{key}.{suffix}` variant, written in Fluent as the `suffix` attribute of the message,
+  /// and falls back to the message value when the variant does not exist.
+  /// ### Usage
+  /// ```ignore
+  /// // greeting = Hello
+  /// //     .formal = Good day
+  /// ...
+  /// let message = translator.translate(language, TranslationKeys::Greeting).variant("formal");
+  ///
+  /// assert_eq!(message.build(), "Good day");
+  /// ```
+  pub fn variant(mut self, suffix: &str) -> Self {
+    self.variant = Some(suffix.to_string());
+    self
+  }
+
+  /// ### Description
+  /// Formats the `attribute` of the message instead of its value, such as a tooltip or a placeholder
+  /// defined next to a label. Unlike `variant`, building fails when the attribute does not exist.
+  /// ### Usage
+  /// ```ignore
+  /// // login-input = Email
+  /// //     .placeholder = email@example.com
+  /// ...
+  /// let message = translator.translate(language, TranslationKeys::LoginInput).attribute("placeholder");
+  ///
+  /// assert_eq!(message.build(), "email@example.com");
+  /// ```
+  pub fn attribute(mut self, attribute: &str) -> Self {
+    self.attribute = Some(attribute.to_string());
+    self
+  }
+
+  /// ### Description
+  /// Formats the `attribute` of the message when it has no value, so messages made only of attributes can
+  /// be built without knowing how they are written.
+  /// ### Usage
+  /// ```ignore
+  /// // login-button =
+  /// //     .label = Log in
+  /// ...
+  /// let message = translator.translate(language, TranslationKeys::LoginButton).or_attribute("label");
+  ///
+  /// assert_eq!(message.build(), "Log in");
+  /// ```
+  pub fn or_attribute(mut self, attribute: &str) -> Self {
+    self.fallback_attribute = Some(attribute.to_string());
+    self
+  }
+
+  pub fn build(&self) -> Cow<'_, str> {
+    self.build_opt().unwrap_or_else(|| {
+      self.missing_text.text(
+        self.key.as_str(),
+        self.attribute.as_deref(),
+        self.args.as_ref(),
+      )
+    })
+  }
+
+  /// ### Description
+  /// Builds the message like `build`, consuming the builder so the text can outlive it.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let title: String = translator.translate(language, TranslationKeys::Title).into_string();
+  /// ```
+  pub fn into_string(self) -> String {
+    self.build().into_owned()
+  }
+
+  /// ### Description
+  /// Builds the message, returning `None` instead of the fallback text when the key does not exist,
+  /// the message has no value or formatting fails.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let message = translator.translate(language, key).add_argument("name", "Alex");
+  ///
+  /// if let Some(built_message) = message.build_opt() {
+  ///   println!("{built_message}");
+  /// }
+  /// ```
+  pub fn build_opt(&self) -> Option<Cow<'_, str>> {
+    self.try_build().ok()
+  }
+
+  /// ### Description
+  /// Builds the message, returning why building failed instead of the fallback text, so applications can
+  /// decide what to show.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let message = translator.translate(language, key).add_argument("name", "Alex");
+  ///
+  /// match message.try_build() {
+  ///   Ok(built_message) => println!("{built_message}"),
+  ///   Err(TranslationError::FormattingErrors { errors, .. }) => eprintln!("{errors:?}"),
+  ///   Err(error) => eprintln!("{error}"),
+  /// }
+  /// ```
+  pub fn try_build(&self) -> Result<Cow<'_, str>, TranslationError> {
+    if self.identity {
+      return Ok(self.build_identity());
+    }
+
+    let message_value = self.message_value()?;
+    self.check_unknown_arguments(message_value)?;
     let mut errors = Vec::new();
 
-    let Some(message) = &self.message else {
+    let translated =
+      self
+        .global_args
+        .format_pattern(self.bundle, message_value, self.args.as_ref(), &mut errors);
+
+    if errors.is_empty() {
+      Ok(translated)
+    } else {
       warn!(
-        "Tried to translate a non existing language key: {}",
-        self.key.as_str()
+        "Translation failure(s) when translating {} with args {:?}: {:?}",
+        self.key.as_str(),
+        self.args,
+        errors
       );
-      return Cow::Borrowed(TRANSLATION_FAILED);
+      self.report_format_error(&errors);
+
+      Err(TranslationError::FormattingErrors {
+        key: self.key.as_str().to_string(),
+        errors,
+      })
+    }
+  }
+
+  /// ### Description
+  /// Builds the message as well as possible along with every problem found, instead of discarding the text
+  /// on the first one. Placeables which can not be formatted, such as a missing argument, are written as
+  /// Fluent does, like `{$name}`, and mismatched arguments are reported without stopping formatting. The
+  /// fallback text is returned when there is no pattern to format at all.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let (text, errors) = translator.translate(language, key).build_lossy();
+  ///
+  /// for error in &errors {
+  ///   eprintln!("{error}");
+  /// }
+  /// render(&text);
+  /// ```
+  pub fn build_lossy(&self) -> (Cow<'_, str>, Vec<TranslationError>) {
+    if self.identity {
+      return (self.build_identity(), Vec::new());
+    }
+
+    let fallback_text = || {
+      self.missing_text.text(
+        self.key.as_str(),
+        self.attribute.as_deref(),
+        self.args.as_ref(),
+      )
     };
 
-    let Some(message_value) = message.value() else {
-      return Cow::Borrowed(TRANSLATION_FAILED);
+    let message = match self.found_message() {
+      Ok(message) => message,
+      Err(error) => return (fallback_text(), vec![error]),
     };
 
-    let translated = self
-      .bundle
-      .format_pattern(message_value, self.args.as_ref(), &mut errors);
+    let mut problems: Vec<TranslationError> = self.check_arguments().err().into_iter().collect();
 
-    if errors.is_empty() {
-      translated
-    } else {
+    let message_value = match self.pattern(message) {
+      Ok(message_value) => message_value,
+      Err(error) => {
+        problems.push(error);
+        return (fallback_text(), problems);
+      }
+    };
+
+    problems.extend(self.check_unknown_arguments(message_value).err());
+
+    let mut errors = Vec::new();
+    let translated =
+      self
+        .global_args
+        .format_pattern(self.bundle, message_value, self.args.as_ref(), &mut errors);
+
+    if !errors.is_empty() {
       warn!(
         "Translation failure(s) when translating {} with args {:?}: {:?}",
         self.key.as_str(),
         self.args,
         errors
       );
+      self.report_format_error(&errors);
 
-      Cow::Borrowed(TRANSLATION_FAILED)
+      problems.push(TranslationError::FormattingErrors {
+        key: self.key.as_str().to_string(),
+        errors,
+      });
     }
+
+    (translated, problems)
   }
+
+  /// ### Description
+  /// Appends the built message to `out` instead of allocating a new string, so the same buffer can be
+  /// reused across many messages. Returns `false` and leaves `out` untouched when the key does not exist,
+  /// the message has no value or formatting fails.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let mut html = String::with_capacity(4096);
+  ///
+  /// for user in users {
+  ///   let message = translator.translate(&language, TranslationKeys::Welcome).add_argument("name", user.name());
+  ///   message.build_into(&mut html);
+  /// }
+  /// ```
+  pub fn build_into(&self, out: &mut String) -> bool {
+    if self.identity {
+      out.push_str(&self.build_identity());
+      return true;
+    }
+
+    let Ok(message_value) = self.message_value() else {
+      return false;
+    };
+
+    if self.check_unknown_arguments(message_value).is_err() {
+      return false;
+    }
+
+    let merged_args = self.global_args.merge(self.args.as_ref());
+
+    match write_pattern(
+      self.bundle,
+      message_value,
+      merged_args.as_ref().or(self.args.as_ref()),
+      out,
+    ) {
+      Ok(()) => true,
+      Err(errors) => {
+        warn!(
+          "Translation failure(s) when translating {} with args {:?}: {:?}",
+          self.key.as_str(),
+          self.args,
+          errors
+        );
+        self.report_format_error(&errors);
+        false
+      }
+    }
+  }
+
+  /// Compares the arguments against the ones the key declares with `required_args`. Default arguments of the
+  /// key are merged into the arguments, so they are not reported as unexpected.
+  fn check_arguments(&self) -> Result<(), TranslationError> {
+    let required_args = self.key.required_args();
+
+    if required_args.is_empty() {
+      return Ok(());
+    }
+
+    let default_args = self.key.default_args();
+    let default_names: Vec<&str> = default_args.iter().map(|(name, _)| name).collect();
+
+    let passed_args: Vec<&str> = self
+      .args
+      .iter()
+      .flat_map(|args| args.iter())
+      .map(|(name, _)| name)
+      .filter(|name| !default_names.contains(name) || required_args.contains(name))
+      .collect();
+
+    let missing: Vec<String> = required_args
+      .iter()
+      .filter(|required_arg| !passed_args.contains(required_arg))
+      .map(ToString::to_string)
+      .collect();
+    let extra: Vec<String> = passed_args
+      .iter()
+      .filter(|passed_arg| !required_args.contains(passed_arg))
+      .map(ToString::to_string)
+      .collect();
+
+    if missing.is_empty() && extra.is_empty() {
+      return Ok(());
+    }
+
+    warn!(
+      "Arguments of {} do not match, missing {:?} and unexpected {:?}",
+      self.key.as_str(),
+      missing,
+      extra
+    );
+
+    Err(TranslationError::ArgumentMismatch {
+      key: self.key.as_str().to_string(),
+      missing,
+      extra,
+    })
+  }
+
+  fn check_unknown_arguments(&self, message_value: &Pattern<&str>) -> Result<(), TranslationError> {
+    check_unknown_arguments(
+      self.unknown_argument_policy,
+      self.bundle,
+      message_value,
+      self.key.as_str(),
+      self.args.as_ref(),
+    )
+  }
+
+  fn report_format_error(&self, errors: &[FluentError]) {
+    if let Some(Observer(observer)) = self.observer {
+      observer.on_format_error(self.language, self.key.as_str(), errors);
+    }
+  }
+
+  /// Picks the pattern to format: the requested attribute, or the requested variant when it exists, the
+  /// message value otherwise and the fallback attribute when the message has no value.
+  fn message_value(&self) -> Result<&'bundle Pattern<&'bundle str>, TranslationError> {
+    let message = self.found_message()?;
+    self.check_arguments()?;
+    self.pattern(message)
+  }
+
+  fn found_message(&self) -> Result<&FluentMessage<'bundle>, TranslationError> {
+    self.message.as_ref().ok_or_else(|| {
+      log_at!(
+        self.miss_level,
+        "Tried to translate a non existing language key: {}",
+        self.key.as_str()
+      );
+      TranslationError::MessageNotFound {
+        key: self.key.as_str().to_string(),
+      }
+    })
+  }
+
+  fn pattern(
+    &self,
+    message: &FluentMessage<'bundle>,
+  ) -> Result<&'bundle Pattern<&'bundle str>, TranslationError> {
+    if let Some(attribute) = &self.attribute {
+      return message
+        .get_attribute(attribute)
+        .map(|attribute| attribute.value())
+        .ok_or_else(|| TranslationError::AttributeNotFound {
+          key: self.key.as_str().to_string(),
+          attribute: attribute.clone(),
+        });
+    }
+
+    let variant_value = self
+      .variant
+      .as_deref()
+      .and_then(|variant| message.get_attribute(variant))
+      .map(|attribute| attribute.value());
+
+    let fallback_value = || {
+      self
+        .fallback_attribute
+        .as_deref()
+        .and_then(|attribute| message.get_attribute(attribute))
+        .map(|attribute| attribute.value())
+    };
+
+    variant_value
+      .or(message.value())
+      .or_else(fallback_value)
+      .ok_or_else(|| TranslationError::NoValue {
+        key: self.key.as_str().to_string(),
+      })
+  }
+
+  fn build_identity(&self) -> Cow<'_, str> {
+    let name = match &self.attribute {
+      Some(attribute) => Cow::Owned(format!("{}.{attribute}", self.key.as_str())),
+      None => Cow::Borrowed(self.key.as_str()),
+    };
+
+    let Some(args) = &self.args else {
+      return name;
+    };
+
+    let formatted_args: Vec<String> = args
+      .iter()
+      .map(|(name, value)| match value {
+        FluentValue::String(value) => format!("{name}: {value}"),
+        FluentValue::Number(number) => format!("{name}: {}", number.as_string()),
+        other => format!("{name}: {other:?}"),
+      })
+      .collect();
+
+    Cow::Owned(format!("{name}({})", formatted_args.join(", ")))
+  }
+}
+
+/// `FluentArgs::set` keeps duplicated names, so an argument passed again, or replacing a default argument of
+/// the key, is removed first.
+fn set_argument<'args>(
+  args: FluentArgs<'args>,
+  name: Cow<'args, str>,
+  value: FluentValue<'args>,
+) -> FluentArgs<'args> {
+  let mut args = if args.get(name.as_ref()).is_some() {
+    args
+      .into_iter()
+      .filter(|(existing_name, _)| *existing_name != name)
+      .collect()
+  } else {
+    args
+  };
+
+  args.set(name, value);
+  args
 }
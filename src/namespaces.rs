@@ -0,0 +1,164 @@
+use crate::{
+  ast::{message_patterns, walk_pattern, Node},
+  loader::{top_level_language_name, FileData},
+  terms::is_term_file,
+  TranslatorError, TranslatorOptions,
+};
+
+use std::collections::HashSet;
+
+use fluent_syntax::{
+  ast::{Entry, InlineExpression, Pattern},
+  parser,
+};
+
+/// Prefixes the messages of every file with the namespace of its file name when file namespaces are
+/// enabled. The top level `<language>.ftl` file of the language and term files are kept as they are, and
+/// common files are named by their path inside the common directory.
+pub(crate) fn namespace_files(
+  language_name: Option<&str>,
+  files: Vec<FileData>,
+  options: &TranslatorOptions,
+) -> Vec<FileData> {
+  let Some(separator) = &options.namespace_separator else {
+    return files;
+  };
+
+  files
+    .into_iter()
+    .map(|(content, file_name)| {
+      if language_name.is_some() && top_level_language_name(&file_name) == language_name
+        || is_term_file(&file_name)
+      {
+        return (content, file_name);
+      }
+
+      let relative_name = options
+        .common_directory
+        .as_deref()
+        .and_then(|common_directory| file_name.strip_prefix(&format!("{common_directory}/")))
+        .unwrap_or(&file_name);
+
+      let Some(namespace) = file_namespace(relative_name, separator) else {
+        warn!("Not namespacing {file_name} as its name is not a valid message identifier");
+        return (content, file_name);
+      };
+
+      (namespace_source(&content, &namespace, separator), file_name)
+    })
+    .collect()
+}
+
+/// Fails when the separator would make message identifiers invalid, as they may only contain letters,
+/// digits, `-` and `_`.
+pub(crate) fn check_namespace_separator(
+  options: &TranslatorOptions,
+) -> Result<(), TranslatorError> {
+  match &options.namespace_separator {
+    Some(separator)
+      if separator.is_empty()
+        || !separator
+          .chars()
+          .all(|character| character == '-' || character == '_') =>
+    {
+      Err(TranslatorError::InvalidNamespaceSeparator {
+        separator: separator.clone(),
+      })
+    }
+    _ => Ok(()),
+  }
+}
+
+/// `errors.ftl` becomes `errors` and `settings/profile.ftl` becomes `settings-profile` with a `-` separator.
+fn file_namespace(file_name: &str, separator: &str) -> Option<String> {
+  let without_extension = file_name
+    .rsplit_once('.')
+    .map_or(file_name, |(name, _)| name);
+
+  let namespace = without_extension.replace('/', separator);
+
+  let mut characters = namespace.chars();
+  let is_identifier = characters
+    .next()
+    .is_some_and(|first| first.is_ascii_alphabetic())
+    && characters
+      .all(|character| character.is_ascii_alphanumeric() || character == '-' || character == '_');
+
+  is_identifier.then_some(namespace)
+}
+
+/// Prefixes the messages a file defines and the references to them. Terms are left shared between files,
+/// as are references to messages of other files, which already have to use their namespaced identifier.
+fn namespace_source(source: &str, namespace: &str, separator: &str) -> String {
+  let resource = match parser::parse(source) {
+    Ok(resource) => resource,
+    Err((resource, _)) => resource,
+  };
+
+  let defined: HashSet<&str> = resource
+    .body
+    .iter()
+    .filter_map(|entry| match entry {
+      Entry::Message(message) => Some(message.id.name),
+      _ => None,
+    })
+    .collect();
+
+  let mut identifiers = Vec::new();
+
+  for entry in &resource.body {
+    match entry {
+      Entry::Message(message) => {
+        identifiers.push(message.id.name);
+
+        for pattern in message_patterns(message) {
+          collect_pattern_references(pattern, &defined, &mut identifiers);
+        }
+      }
+      Entry::Term(term) => {
+        let patterns = std::iter::once(&term.value)
+          .chain(term.attributes.iter().map(|attribute| &attribute.value));
+
+        for pattern in patterns {
+          collect_pattern_references(pattern, &defined, &mut identifiers);
+        }
+      }
+      _ => {}
+    }
+  }
+
+  // Identifiers borrow from the source, so their position is where they start in it.
+  let mut offsets: Vec<usize> = identifiers
+    .iter()
+    .map(|identifier| identifier.as_ptr() as usize - source.as_ptr() as usize)
+    .collect();
+  offsets.sort_unstable();
+  offsets.dedup();
+
+  let prefix = format!("{namespace}{separator}");
+  let mut namespaced = String::with_capacity(source.len() + offsets.len() * prefix.len());
+  let mut copied = 0;
+
+  for offset in offsets {
+    namespaced.push_str(&source[copied..offset]);
+    namespaced.push_str(&prefix);
+    copied = offset;
+  }
+
+  namespaced.push_str(&source[copied..]);
+  namespaced
+}
+
+fn collect_pattern_references<'source>(
+  pattern: &Pattern<&'source str>,
+  defined: &HashSet<&str>,
+  identifiers: &mut Vec<&'source str>,
+) {
+  walk_pattern(pattern, &mut |node| {
+    if let Node::Inline(InlineExpression::MessageReference { id, .. }) = node {
+      if defined.contains(id.name) {
+        identifiers.push(id.name);
+      }
+    }
+  });
+}
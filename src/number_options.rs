@@ -0,0 +1,87 @@
+use fluent_bundle::{
+  types::{FluentNumber, FluentNumberOptions, FluentNumberStyle},
+  FluentValue,
+};
+
+/// How a number argument is presented, the same styles `NUMBER` accepts in Fluent.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum NumberStyle {
+  #[default]
+  Decimal,
+  /// Multiplies the number by 100 and adds the percent sign.
+  Percent,
+  /// Shows the number with the given ISO 4217 currency code, such as `EUR`, with 2 fraction digits.
+  Currency(String),
+}
+
+/// ### Description
+/// Formatting options of a number argument, formatted with the separators of the language of the message.
+/// Unset digits use the defaults of the style and grouping is enabled by default, like Fluent does.
+/// ### Usage
+/// ```ignore
+/// ...
+/// let options = NumberOptions {
+///   minimum_fraction_digits: Some(2),
+///   ..Default::default()
+/// };
+///
+/// let message = translator.translate(language, TranslationKeys::Price).add_number_argument("price", 1234.5, options);
+///
+/// assert_eq!(message.build(), "Price: 1,234.50");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberOptions {
+  pub style: NumberStyle,
+  pub use_grouping: bool,
+  pub minimum_integer_digits: Option<usize>,
+  pub minimum_fraction_digits: Option<usize>,
+  pub maximum_fraction_digits: Option<usize>,
+}
+
+impl Default for NumberOptions {
+  fn default() -> Self {
+    Self {
+      style: NumberStyle::Decimal,
+      use_grouping: true,
+      minimum_integer_digits: None,
+      minimum_fraction_digits: None,
+      maximum_fraction_digits: None,
+    }
+  }
+}
+
+impl NumberOptions {
+  /// ### Description
+  /// Creates the value of a number argument with these options, for arguments passed with `with_args`.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let percent = NumberOptions { style: NumberStyle::Percent, ..Default::default() };
+  ///
+  /// let message = translator.translate(language, key).with_args([("progress", percent.value(0.25))]);
+  /// ```
+  pub fn value(&self, number: impl Into<f64>) -> FluentValue<'static> {
+    FluentValue::Number(FluentNumber::new(number.into(), self.into()))
+  }
+}
+
+impl From<&NumberOptions> for FluentNumberOptions {
+  fn from(options: &NumberOptions) -> Self {
+    let (style, currency) = match &options.style {
+      NumberStyle::Decimal => (FluentNumberStyle::Decimal, None),
+      NumberStyle::Percent => (FluentNumberStyle::Percent, None),
+      NumberStyle::Currency(currency) => (FluentNumberStyle::Currency, Some(currency.clone())),
+    };
+
+    FluentNumberOptions {
+      style,
+      currency,
+      use_grouping: options.use_grouping,
+      // Numbers with default options are left to Fluent, 1 is already the default.
+      minimum_integer_digits: Some(options.minimum_integer_digits.unwrap_or(1)),
+      minimum_fraction_digits: options.minimum_fraction_digits,
+      maximum_fraction_digits: options.maximum_fraction_digits,
+      ..Default::default()
+    }
+  }
+}
@@ -0,0 +1,62 @@
+use crate::{Language, TranslationKey, Translator};
+
+use std::{fmt, sync::Arc};
+
+use fluent_bundle::FluentError;
+
+/// ### Description
+/// Receives translation problems as they happen, so applications can count them in their metrics or send
+/// them as events. Every method does nothing by default. Languages are always loaded languages, so they
+/// can be used as metric labels without the cardinality of user input.
+/// ### Usage
+/// ```ignore
+/// struct MissingCounter(IntCounterVec);
+///
+/// impl TranslationObserver for MissingCounter {
+///   fn on_missing(&self, language: &str, key: &str) {
+///     self.0.with_label_values(&[language]).inc();
+///   }
+/// }
+/// ...
+/// let translator = Translator::<Languages, TranslationKeys>::builder()
+///   .directory("path/to/translations")
+///   .default_language(&Languages::English)
+///   .with_observer(MissingCounter(counter))
+///   .build()?;
+/// ```
+pub trait TranslationObserver: Send + Sync {
+  /// Called when the language a message is requested in does not define it, before falling back to other
+  /// languages. Requests for languages which are not loaded report the closest loaded language.
+  fn on_missing(&self, _language: &str, _key: &str) {}
+
+  /// Called when formatting a message fails, with the language of the bundle it was formatted with.
+  fn on_format_error(&self, _language: &str, _key: &str, _errors: &[FluentError]) {}
+}
+
+/// The observer set with `with_observer`.
+#[derive(Clone)]
+pub(crate) struct Observer(pub(crate) Arc<dyn TranslationObserver>);
+
+impl fmt::Debug for Observer {
+  fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    formatter.write_str("Observer")
+  }
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  pub(crate) fn report_missing(&self, language: &str, key: &str) {
+    if let Some(Observer(observer)) = &self.options.observer {
+      observer.on_missing(language, key);
+    }
+  }
+
+  pub(crate) fn report_format_error(&self, language: &str, key: &str, errors: &[FluentError]) {
+    if let Some(Observer(observer)) = &self.options.observer {
+      observer.on_format_error(language, key, errors);
+    }
+  }
+}
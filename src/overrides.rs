@@ -0,0 +1,112 @@
+use crate::{loader::build_bundle, Language, TranslationKey, Translator, TranslatorError};
+
+use std::sync::Arc;
+
+use fluent_bundle::FluentResource;
+use fluent_syntax::ast::Entry;
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Overrides the text of a single message at runtime, replacing the value loaded from files until
+  /// the override is cleared. The pattern uses Fluent syntax, so it may reference arguments and terms.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// translator.set_override(&Languages::English, "hello_with_arguments", "Welcome back, { $name }!")?;
+  /// ```
+  pub fn set_override(
+    &mut self,
+    language: &LanguageGeneric,
+    key: &str,
+    ftl_pattern: &str,
+  ) -> Result<(), TranslatorError> {
+    let language = language.as_str();
+
+    if !self.translations.contains_key(language) {
+      return Err(TranslatorError::UnknownLanguage {
+        language: language.to_string(),
+      });
+    }
+
+    let invalid_override = |detail: String| TranslatorError::InvalidOverride {
+      key: key.to_string(),
+      detail,
+    };
+
+    let resource = FluentResource::try_new(format!("{key} = {ftl_pattern}\n"))
+      .map_err(|(_, errors)| invalid_override(format!("{errors:?}")))?;
+
+    let entries: Vec<&Entry<&str>> = resource.entries().collect();
+    let defines_only_key =
+      matches!(entries.as_slice(), [Entry::Message(message)] if message.id.name == key);
+
+    if !defines_only_key {
+      return Err(invalid_override(
+        "the pattern must define exactly one message with the given key".to_string(),
+      ));
+    }
+
+    info!("Overriding {key} for language {language}");
+
+    self
+      .overrides
+      .entry(language.to_string())
+      .or_default()
+      .insert(key.to_string(), Arc::new(resource));
+
+    self.rebuild_bundle(language);
+
+    Ok(())
+  }
+
+  /// ### Description
+  /// Removes a runtime override, restoring the message loaded from files.
+  /// Returns whether an override existed for the key.
+  pub fn clear_override(&mut self, language: &LanguageGeneric, key: &str) -> bool {
+    let language = language.as_str();
+
+    let removed = self
+      .overrides
+      .get_mut(language)
+      .and_then(|overrides| overrides.remove(key))
+      .is_some();
+
+    if removed {
+      info!("Cleared override of {key} for language {language}");
+      self.rebuild_bundle(language);
+    }
+
+    removed
+  }
+
+  /// Recreates the bundle of a language from its loaded resources, then applies its overrides on top.
+  pub(crate) fn rebuild_bundle(&mut self, language: &str) {
+    let Some(loaded) = self.translations.get(language) else {
+      return;
+    };
+
+    debug!("Rebuilding bundle for {language}");
+
+    let language_identifier = loaded.bundle.locales.first().cloned().unwrap_or_default();
+    let resources = loaded.resources.as_slice();
+
+    // Conflicts were already reported when the resources were first loaded.
+    let (mut bundle, _) = build_bundle(language_identifier, resources, language, &self.options);
+
+    for resource in self
+      .overrides
+      .get(language)
+      .into_iter()
+      .flat_map(|overrides| overrides.values())
+    {
+      bundle.add_resource_overriding(Arc::clone(resource));
+    }
+
+    self.translations.set_bundle(language, bundle);
+    self.clear_format_cache();
+  }
+}
@@ -0,0 +1,257 @@
+use crate::{
+  ast::{walk_pattern, Node},
+  Language, TranslationError, TranslationKey, Translator,
+};
+
+use fluent_bundle::{FluentArgs, FluentValue};
+use fluent_langneg::{negotiate_languages, NegotiationStrategy};
+use fluent_syntax::ast::{Entry, Pattern, VariantKey};
+use intl_pluralrules::{PluralCategory, PluralRuleType, PluralRules};
+use unic_langid::LanguageIdentifier;
+
+const PLURAL_CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+const SAMPLE_DECIMALS: [&str; 6] = ["0.0", "0.5", "1.0", "1.5", "2.5", "10.5"];
+const SAMPLE_LARGE_INTEGERS: [u64; 3] = [1_000, 10_000, 1_000_000];
+
+/// A plural select expression which does not define a variant for every category its locale needs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluralIssue {
+  pub language: String,
+  pub key: String,
+  pub missing_categories: Vec<&'static str>,
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Returns the CLDR cardinal plural categories used by the given language, in canonical order
+  /// (`zero`, `one`, `two`, `few`, `many`, `other`).
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let categories = translator.plural_categories(&Languages::English);
+  ///
+  /// assert_eq!(categories, vec!["one", "other"]);
+  /// ```
+  pub fn plural_categories(&self, language: &LanguageGeneric) -> Vec<&'static str> {
+    let Some(language_identifier) = self.options.language_parser.parse(language.as_str()) else {
+      return vec!["other"];
+    };
+
+    plural_categories_for(&language_identifier)
+  }
+
+  /// ### Description
+  /// Formats a message once per value of a numeric argument and returns the distinct texts in the order
+  /// they first appear, so tests can check every plural variant of a message is reachable. Fails with the
+  /// first error a value runs into, such as a missing message or a variable the message can not format.
+  /// ### Usage
+  /// ```ignore
+  /// // unread = { $count -> [one] One unread message *[other] { $count } unread messages }
+  /// ...
+  /// let forms = translator.plural_forms(&Languages::English, TranslationKeys::Unread, "count", [1, 2])?;
+  ///
+  /// assert_eq!(forms, ["One unread message", "\u{2068}2\u{2069} unread messages"]);
+  /// ```
+  pub fn plural_forms<Values, Value>(
+    &self,
+    language: &LanguageGeneric,
+    key: TranslationKeyGeneric,
+    argument_name: &str,
+    values: Values,
+  ) -> Result<Vec<String>, TranslationError>
+  where
+    Values: IntoIterator<Item = Value>,
+    Value: Into<FluentValue<'static>>,
+  {
+    let key = key.as_str();
+
+    if self.identity {
+      return Ok(vec![key.to_string()]);
+    }
+
+    let (_, bundle, effective_language) = self.resolve_message(language.as_str(), key);
+    let mut forms: Vec<String> = Vec::new();
+
+    for value in values {
+      let mut args = FluentArgs::new();
+      args.set(argument_name.to_string(), value);
+
+      let text = self
+        .format_message_value(
+          bundle.get_message(key),
+          bundle,
+          effective_language,
+          key,
+          Some(&args),
+        )?
+        .into_owned();
+
+      if !forms.contains(&text) {
+        forms.push(text);
+      }
+    }
+
+    Ok(forms)
+  }
+
+  /// ### Description
+  /// Scans every loaded message and reports plural select expressions missing a variant for
+  /// a category required by the language they are written in.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let issues = translator.validate_plurals();
+  ///
+  /// assert!(issues.is_empty(), "{issues:?}");
+  /// ```
+  pub fn validate_plurals(&self) -> Vec<PluralIssue> {
+    let mut languages: Vec<&str> = self.translations.keys().collect();
+    languages.sort_unstable();
+
+    let mut issues = Vec::new();
+
+    for language in languages {
+      let Some(loaded) = self.translations.get(language) else {
+        continue;
+      };
+      let Some(language_identifier) = loaded.bundle.locales.first() else {
+        continue;
+      };
+
+      let required_categories = plural_categories_for(language_identifier);
+
+      debug!("Validating plural selects for {language} against {required_categories:?}");
+
+      for loaded_resource in &loaded.resources {
+        for entry in loaded_resource.resource.entries() {
+          let Entry::Message(message) = entry else {
+            continue;
+          };
+
+          let mut patterns = Vec::new();
+
+          if let Some(value) = &message.value {
+            patterns.push((message.id.name.to_string(), value));
+          }
+
+          for attribute in &message.attributes {
+            patterns.push((
+              format!("{}.{}", message.id.name, attribute.id.name),
+              &attribute.value,
+            ));
+          }
+
+          for (key, pattern) in patterns {
+            let mut missing_categories = Vec::new();
+            collect_missing_categories(pattern, &required_categories, &mut missing_categories);
+
+            if !missing_categories.is_empty() {
+              issues.push(PluralIssue {
+                language: language.to_string(),
+                key,
+                missing_categories,
+              });
+            }
+          }
+        }
+      }
+    }
+
+    issues
+  }
+}
+
+/// The cardinal plural rules of the closest locale known to CLDR, English when none is close enough.
+pub(crate) fn cardinal_rules(language_identifier: &LanguageIdentifier) -> Option<PluralRules> {
+  let default_language: LanguageIdentifier = "en".parse().unwrap();
+  let available_locales = PluralRules::get_locales(PluralRuleType::CARDINAL);
+  let negotiated = negotiate_languages(
+    &[language_identifier],
+    &available_locales,
+    Some(&default_language),
+    NegotiationStrategy::Lookup,
+  );
+
+  negotiated
+    .first()
+    .and_then(|locale| PluralRules::create((*locale).clone(), PluralRuleType::CARDINAL).ok())
+}
+
+fn plural_categories_for(language_identifier: &LanguageIdentifier) -> Vec<&'static str> {
+  let Some(rules) = cardinal_rules(language_identifier) else {
+    return vec!["other"];
+  };
+
+  let integers = (0..=200).chain(SAMPLE_LARGE_INTEGERS);
+  let mut found: Vec<&'static str> = integers
+    .filter_map(|number| rules.select(number).ok())
+    .chain(
+      SAMPLE_DECIMALS
+        .iter()
+        .filter_map(|number| rules.select(*number).ok()),
+    )
+    .map(|category| category_name(&category))
+    .collect();
+
+  found.sort_by_key(|category| PLURAL_CATEGORIES.iter().position(|known| known == category));
+  found.dedup();
+  found
+}
+
+pub(crate) fn category_name(category: &PluralCategory) -> &'static str {
+  match category {
+    PluralCategory::ZERO => "zero",
+    PluralCategory::ONE => "one",
+    PluralCategory::TWO => "two",
+    PluralCategory::FEW => "few",
+    PluralCategory::MANY => "many",
+    PluralCategory::OTHER => "other",
+  }
+}
+
+fn collect_missing_categories(
+  pattern: &Pattern<&str>,
+  required_categories: &[&'static str],
+  missing_categories: &mut Vec<&'static str>,
+) {
+  walk_pattern(pattern, &mut |node| {
+    let Node::Select(variants) = node else {
+      return;
+    };
+
+    let keys: Vec<&VariantKey<&str>> = variants.iter().map(|variant| &variant.key).collect();
+
+    if !is_plural_select(&keys) {
+      return;
+    }
+
+    for category in required_categories {
+      let is_covered = keys
+        .iter()
+        .any(|key| matches!(key, VariantKey::Identifier { name } if name == category));
+
+      if !is_covered && !missing_categories.contains(category) {
+        missing_categories.push(category);
+      }
+    }
+  });
+}
+
+/// A select is considered plural when every identifier key is a plural category and at least one
+/// key is something other than the catch-all `other`.
+fn is_plural_select(keys: &[&VariantKey<&str>]) -> bool {
+  let only_plural_identifiers = keys.iter().all(|key| match key {
+    VariantKey::Identifier { name } => PLURAL_CATEGORIES.contains(name),
+    VariantKey::NumberLiteral { .. } => true,
+  });
+
+  let has_specific_key = keys
+    .iter()
+    .any(|key| !matches!(key, VariantKey::Identifier { name } if *name == "other"));
+
+  only_plural_identifiers && has_specific_key
+}
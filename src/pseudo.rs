@@ -0,0 +1,200 @@
+use crate::{
+  loader::{build_bundle, LoadedResource},
+  Language, TranslationKey, Translator, TranslatorError,
+};
+
+use std::sync::Arc;
+
+use fluent_bundle::FluentResource;
+use fluent_syntax::{
+  ast::{Entry, Expression, InlineExpression, Pattern, PatternElement},
+  parser, serializer,
+};
+
+const ACCENTED_UPPERCASE: [char; 26] = [
+  'Ȧ', 'Ɓ', 'Ƈ', 'Ḓ', 'Ḗ', 'Ƒ', 'Ɠ', 'Ħ', 'Ī', 'Ĵ', 'Ķ', 'Ŀ', 'Ḿ', 'Ƞ', 'Ǿ', 'Ƥ', 'Ɋ', 'Ř', 'Ş',
+  'Ŧ', 'Ŭ', 'Ṽ', 'Ẇ', 'Ẋ', 'Ẏ', 'Ẑ',
+];
+const ACCENTED_LOWERCASE: [char; 26] = [
+  'ȧ', 'ƀ', 'ƈ', 'ḓ', 'ḗ', 'ƒ', 'ɠ', 'ħ', 'ī', 'ĵ', 'ķ', 'ŀ', 'ḿ', 'ƞ', 'ǿ', 'ƥ', 'ɋ', 'ř', 'ş',
+  'ŧ', 'ŭ', 'ṽ', 'ẇ', 'ẋ', 'ẏ', 'ẑ',
+];
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Adds a pseudo-language generated from the default language, such as `en-XA`, where every message is
+  /// accented, stretched by doubling its vowels and wrapped in brackets: `Hello { $name }` becomes
+  /// `[Ħḗḗŀŀǿǿ { $name }]`. Text which is not accented in the interface was not translated, and cut brackets
+  /// reveal layouts which can not fit longer languages. Pseudo-languages are kept when reloading.
+  /// Fails with `UnknownLanguage` when the code is not a valid language identifier.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// if cfg!(debug_assertions) {
+  ///   translator.enable_pseudo("en-XA")?;
+  /// }
+  /// ```
+  pub fn enable_pseudo(&mut self, language: &str) -> Result<(), TranslatorError> {
+    self.add_pseudo_language(language)?;
+
+    if !self
+      .options
+      .pseudo_languages
+      .iter()
+      .any(|pseudo_language| pseudo_language == language)
+    {
+      self.options.pseudo_languages.push(language.to_string());
+    }
+
+    Ok(())
+  }
+
+  /// Builds the bundle of a pseudo-language from the resources of the default language.
+  pub(crate) fn add_pseudo_language(&mut self, language: &str) -> Result<(), TranslatorError> {
+    let language_identifier = self
+      .options
+      .language_parser
+      .parse(language)
+      .ok_or_else(|| TranslatorError::UnknownLanguage {
+        language: language.to_string(),
+      })?;
+
+    let resources: Vec<LoadedResource> = self
+      .translations
+      .resources(self.default_language)
+      .into_iter()
+      .flatten()
+      .map(pseudo_resource)
+      .collect();
+
+    let (bundle, _) = build_bundle(language_identifier, &resources, language, &self.options);
+
+    info!(
+      "Generated pseudo-language {language} from {}",
+      self.default_language
+    );
+
+    self
+      .translations
+      .insert(language.to_string(), bundle, resources);
+    self.clear_format_cache();
+
+    Ok(())
+  }
+}
+
+fn pseudo_resource(loaded_resource: &LoadedResource) -> LoadedResource {
+  let mut resource = match parser::parse(loaded_resource.resource.source().to_string()) {
+    Ok(resource) => resource,
+    Err((resource, _)) => resource,
+  };
+
+  for entry in &mut resource.body {
+    match entry {
+      Entry::Message(message) => {
+        let patterns = message.value.iter_mut().chain(
+          message
+            .attributes
+            .iter_mut()
+            .map(|attribute| &mut attribute.value),
+        );
+
+        for pattern in patterns {
+          pseudo_pattern(pattern);
+          add_brackets(pattern);
+        }
+      }
+      // Terms are only accented, as they are placed inside messages which already get brackets.
+      Entry::Term(term) => {
+        let patterns = std::iter::once(&mut term.value).chain(
+          term
+            .attributes
+            .iter_mut()
+            .map(|attribute| &mut attribute.value),
+        );
+
+        for pattern in patterns {
+          pseudo_pattern(pattern);
+        }
+      }
+      _ => {}
+    }
+  }
+
+  let resource = FluentResource::try_new(serializer::serialize(&resource)).unwrap_or_else(
+    |(resource, errors)| {
+      warn!(
+        "Pseudo-localized {} has syntax errors: {errors:?}",
+        loaded_resource.file_name
+      );
+      resource
+    },
+  );
+
+  LoadedResource {
+    resource: Arc::new(resource),
+    parse_errors: Vec::new(),
+    ..loaded_resource.clone()
+  }
+}
+
+/// Accents the text of a pattern, including the variants of its selectors, leaving arguments, references and
+/// literals untouched.
+fn pseudo_pattern(pattern: &mut Pattern<String>) {
+  for element in &mut pattern.elements {
+    match element {
+      PatternElement::TextElement { value } => *value = pseudo_text(value),
+      PatternElement::Placeable { expression } => pseudo_expression(expression),
+    }
+  }
+}
+
+fn pseudo_expression(expression: &mut Expression<String>) {
+  match expression {
+    Expression::Select { variants, .. } => {
+      for variant in variants {
+        pseudo_pattern(&mut variant.value);
+      }
+    }
+    Expression::Inline(InlineExpression::Placeable { expression }) => {
+      pseudo_expression(expression);
+    }
+    Expression::Inline(_) => {}
+  }
+}
+
+/// Brackets are string literals so they can not be mistaken for syntax at the start of a line.
+fn add_brackets(pattern: &mut Pattern<String>) {
+  let literal = |value: &str| PatternElement::Placeable {
+    expression: Expression::Inline(InlineExpression::StringLiteral {
+      value: value.to_string(),
+    }),
+  };
+
+  pattern.elements.insert(0, literal("["));
+  pattern.elements.push(literal("]"));
+}
+
+fn pseudo_text(text: &str) -> String {
+  let mut pseudo = String::with_capacity(text.len() * 3);
+
+  for character in text.chars() {
+    let accented = match character {
+      'A'..='Z' => ACCENTED_UPPERCASE[character as usize - 'A' as usize],
+      'a'..='z' => ACCENTED_LOWERCASE[character as usize - 'a' as usize],
+      character => character,
+    };
+
+    pseudo.push(accented);
+
+    if "aeiouAEIOU".contains(character) {
+      pseudo.push(accented);
+    }
+  }
+
+  pseudo
+}
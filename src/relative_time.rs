@@ -0,0 +1,225 @@
+use crate::{Language, TranslationKey, Translator};
+
+use std::time::{Duration, SystemTime};
+
+use fluent_bundle::FluentArgs;
+
+/// A unit times are counted in along with its messages.
+struct TimeUnit {
+  seconds: u64,
+  duration: &'static str,
+  past: &'static str,
+  future: &'static str,
+}
+
+const fn time_unit(
+  seconds: u64,
+  duration: &'static str,
+  past: &'static str,
+  future: &'static str,
+) -> TimeUnit {
+  TimeUnit {
+    seconds,
+    duration,
+    past,
+    future,
+  }
+}
+
+/// Units from the largest, months and years are approximated as 30 and 365 days.
+const TIME_UNITS: &[TimeUnit] = &[
+  time_unit(
+    365 * 86_400,
+    "duration-year",
+    "relative-time-year-past",
+    "relative-time-year-future",
+  ),
+  time_unit(
+    30 * 86_400,
+    "duration-month",
+    "relative-time-month-past",
+    "relative-time-month-future",
+  ),
+  time_unit(
+    7 * 86_400,
+    "duration-week",
+    "relative-time-week-past",
+    "relative-time-week-future",
+  ),
+  time_unit(
+    86_400,
+    "duration-day",
+    "relative-time-day-past",
+    "relative-time-day-future",
+  ),
+  time_unit(
+    3_600,
+    "duration-hour",
+    "relative-time-hour-past",
+    "relative-time-hour-future",
+  ),
+  time_unit(
+    60,
+    "duration-minute",
+    "relative-time-minute-past",
+    "relative-time-minute-future",
+  ),
+  time_unit(
+    1,
+    "duration-second",
+    "relative-time-second-past",
+    "relative-time-second-future",
+  ),
+];
+
+/// Message used for times less than a second away, the seconds messages are used when it is missing.
+const RELATIVE_TIME_NOW: &str = "relative-time-now";
+
+/// A point in time relative to now, such as "3 minutes ago" or "in 2 days".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelativeTime {
+  Past(Duration),
+  Future(Duration),
+}
+
+impl RelativeTime {
+  /// ### Description
+  /// How far a time is from now, in the past or in the future.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let relative_time = RelativeTime::from_now(message.sent_at);
+  /// ```
+  pub fn from_now(time: SystemTime) -> Self {
+    Self::between(time, SystemTime::now())
+  }
+
+  /// How far a time is from another one, taken as now.
+  pub fn between(time: SystemTime, now: SystemTime) -> Self {
+    match now.duration_since(time) {
+      Ok(elapsed) => Self::Past(elapsed),
+      Err(error) => Self::Future(error.duration()),
+    }
+  }
+
+  fn duration(&self) -> Duration {
+    match self {
+      Self::Past(duration) | Self::Future(duration) => *duration,
+    }
+  }
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Formats a relative time in the largest unit it reaches, using the conventional messages
+  /// `relative-time-{unit}-past` and `relative-time-{unit}-future` with a `$count` argument, where the unit
+  /// is `second`, `minute`, `hour`, `day`, `week`, `month` or `year`. Times less than a second away use
+  /// `relative-time-now` when it is defined. Missing messages fall back like any other message.
+  /// ### Usage
+  /// ```ignore
+  /// // relative-time-minute-past = { $count ->
+  /// //     [one] a minute ago
+  /// //    *[other] { $count } minutes ago
+  /// // }
+  /// // relative-time-day-future = { $count ->
+  /// //     [one] tomorrow
+  /// //    *[other] in { $count } days
+  /// // }
+  /// ...
+  /// let text = translator.translate_relative_time(&Languages::English, RelativeTime::Past(Duration::from_secs(180)));
+  ///
+  /// assert_eq!(text, "3 minutes ago");
+  /// ```
+  pub fn translate_relative_time(
+    &self,
+    language: &LanguageGeneric,
+    relative_time: RelativeTime,
+  ) -> String {
+    let language = language.as_str();
+    let seconds = relative_time.duration().as_secs();
+
+    if seconds == 0 {
+      let (message, _, _) = self.resolve_message(language, RELATIVE_TIME_NOW);
+
+      if message.is_some() || self.identity {
+        return self.format_time_message(language, RELATIVE_TIME_NOW, None);
+      }
+    }
+
+    let (unit, count) = largest_unit(seconds);
+
+    let key = match relative_time {
+      RelativeTime::Past(_) => unit.past,
+      RelativeTime::Future(_) => unit.future,
+    };
+
+    self.format_time_message(language, key, Some(count))
+  }
+
+  /// ### Description
+  /// Formats a duration in the largest unit it reaches, using the conventional messages `duration-{unit}`
+  /// with a `$count` argument, with the same units as `translate_relative_time`.
+  /// ### Usage
+  /// ```ignore
+  /// // duration-hour = { $count ->
+  /// //     [one] an hour
+  /// //    *[other] { $count } hours
+  /// // }
+  /// ...
+  /// let text = translator.translate_duration(&Languages::English, Duration::from_secs(7_200));
+  ///
+  /// assert_eq!(text, "2 hours");
+  /// ```
+  pub fn translate_duration(&self, language: &LanguageGeneric, duration: Duration) -> String {
+    let (unit, count) = largest_unit(duration.as_secs());
+
+    self.format_time_message(language.as_str(), unit.duration, Some(count))
+  }
+
+  /// ### Description
+  /// Formats how long ago, or in how long, a time is from now, the same as `translate_relative_time`.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let sent = translator.translate_time_from_now(&language, message.sent_at);
+  /// ```
+  pub fn translate_time_from_now(&self, language: &LanguageGeneric, time: SystemTime) -> String {
+    self.translate_relative_time(language, RelativeTime::from_now(time))
+  }
+
+  fn format_time_message(&self, language: &str, key: &'static str, count: Option<u64>) -> String {
+    if self.identity {
+      return match count {
+        Some(count) => format!("{key}(count: {count})"),
+        None => key.to_string(),
+      };
+    }
+
+    let args = count.map(|count| FluentArgs::from_iter([("count", count)]));
+    let args = args.as_ref();
+
+    let (message, bundle, effective_language) = self.resolve_message(language, key);
+
+    match self.format_message_value(message, bundle, effective_language, key, args) {
+      Ok(text) => text.into_owned(),
+      Err(_) => self
+        .missing_text(language)
+        .text(key, None, args)
+        .into_owned(),
+    }
+  }
+}
+
+/// The largest unit a number of seconds reaches and how many of it there are, seconds for less than one.
+fn largest_unit(seconds: u64) -> (&'static TimeUnit, u64) {
+  let unit = TIME_UNITS
+    .iter()
+    .find(|unit| seconds >= unit.seconds)
+    .unwrap_or(&TIME_UNITS[TIME_UNITS.len() - 1]);
+
+  (unit, seconds / unit.seconds)
+}
@@ -0,0 +1,48 @@
+use crate::{Language, TranslationKey, Translator, TranslatorError};
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Reads the translations directory again with the options the translator was built with, keeping runtime
+  /// overrides. The current translations are only replaced once the new ones loaded successfully.
+  /// Translators created with `from_sources` or `from_source` have no directory and fail with `NoDirectory`.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// if let Err(error) = translator.reload() {
+  ///   eprintln!("Keeping previous translations: {error}");
+  /// }
+  /// ```
+  pub fn reload(&mut self) -> Result<(), TranslatorError> {
+    *self = self.reloaded()?;
+    Ok(())
+  }
+
+  /// Loads a fresh translator from the same directory and options, without modifying this one.
+  pub(crate) fn reloaded(&self) -> Result<Self, TranslatorError> {
+    if self.identity {
+      return Ok(Self::identity_for(self.default_language));
+    }
+
+    let Some(directory_path) = &self.directory_path else {
+      return Err(TranslatorError::NoDirectory);
+    };
+
+    info!("Reloading translations from {directory_path}");
+
+    let mut translator = Self::load(directory_path, self.default_language, self.options.clone())?;
+
+    translator.overrides = self.overrides.clone();
+
+    let overridden_languages: Vec<String> = translator.overrides.keys().cloned().collect();
+
+    for language in overridden_languages {
+      translator.rebuild_bundle(&language);
+    }
+
+    Ok(translator)
+  }
+}
@@ -0,0 +1,166 @@
+use crate::{TranslationSource, TranslatorError};
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use reqwest::{
+  blocking::Client,
+  header::{ETAG, IF_NONE_MATCH},
+  StatusCode,
+};
+
+/// ### Description
+/// A [`TranslationSource`] fetching one Fluent file per language over HTTP, such as the export endpoints
+/// of translation platforms. With a cache directory, files are stored on disk and revalidated with their
+/// `ETag`, and the cached copy is used when the request fails, e.g. while offline.
+/// ### Usage
+/// ```ignore
+/// ...
+/// let source = RemoteSource::new("https://cdn.example.com/translations/{language}.ftl", &["en-US", "es-ES"])
+///   .with_cache_directory("/var/cache/translations");
+///
+/// let translator = Translator::<Languages, TranslationKeys>::from_source(&source, &Languages::English)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct RemoteSource {
+  url_template: String,
+  languages: Vec<String>,
+  cache_directory: Option<PathBuf>,
+  client: Client,
+}
+
+impl RemoteSource {
+  /// `url_template` is the URL of a language file, with `{language}` replaced by the language code.
+  pub fn new(url_template: &str, languages: &[&str]) -> Self {
+    Self {
+      url_template: url_template.to_string(),
+      languages: languages.iter().map(ToString::to_string).collect(),
+      cache_directory: None,
+      client: Client::new(),
+    }
+  }
+
+  /// Stores the fetched files in `cache_directory`, creating it when needed.
+  pub fn with_cache_directory(mut self, cache_directory: impl Into<PathBuf>) -> Self {
+    self.cache_directory = Some(cache_directory.into());
+    self
+  }
+
+  /// Uses a client configured by the application, such as with authentication headers or timeouts.
+  pub fn with_client(mut self, client: Client) -> Self {
+    self.client = client;
+    self
+  }
+
+  fn fetch(&self, language: &str) -> Result<String, TranslatorError> {
+    let url = self.url_template.replace("{language}", language);
+    let cache = self
+      .cache_directory
+      .as_deref()
+      .map(|cache_directory| CachedFile::new(cache_directory, language));
+
+    let cached_content = cache.as_ref().and_then(CachedFile::content);
+
+    let mut request = self.client.get(&url);
+
+    if let (Some(etag), Some(_)) = (cache.as_ref().and_then(CachedFile::etag), &cached_content) {
+      request = request.header(IF_NONE_MATCH, etag);
+    }
+
+    let remote_error = |detail: String| TranslatorError::RemoteError {
+      url: url.clone(),
+      detail,
+    };
+
+    let response = request
+      .send()
+      .and_then(|response| response.error_for_status());
+
+    let response = match (response, cached_content) {
+      (Ok(response), Some(cached_content)) if response.status() == StatusCode::NOT_MODIFIED => {
+        debug!("Cached translations of {language} are up to date");
+        return Ok(cached_content);
+      }
+      (Ok(response), _) => response,
+      (Err(error), Some(cached_content)) => {
+        warn!("Using cached translations of {language} as {url} could not be fetched: {error}");
+        return Ok(cached_content);
+      }
+      (Err(error), None) => return Err(remote_error(error.to_string())),
+    };
+
+    let etag = response
+      .headers()
+      .get(ETAG)
+      .and_then(|etag| etag.to_str().ok())
+      .map(ToString::to_string);
+
+    let content = response
+      .text()
+      .map_err(|error| remote_error(error.to_string()))?;
+
+    info!("Fetched translations of {language} from {url}");
+
+    if let Some(cache) = &cache {
+      cache.store(&content, etag.as_deref());
+    }
+
+    Ok(content)
+  }
+}
+
+impl TranslationSource for RemoteSource {
+  fn languages(&self) -> Result<Vec<String>, TranslatorError> {
+    Ok(self.languages.clone())
+  }
+
+  fn resources(&self, language: &str) -> Result<Vec<(String, String)>, TranslatorError> {
+    let content = self.fetch(language)?;
+    Ok(vec![(format!("{language}.ftl"), content)])
+  }
+}
+
+/// `<language>.ftl` and its `<language>.etag` inside the cache directory.
+struct CachedFile {
+  content_path: PathBuf,
+  etag_path: PathBuf,
+}
+
+impl CachedFile {
+  fn new(cache_directory: &Path, language: &str) -> Self {
+    Self {
+      content_path: cache_directory.join(format!("{language}.ftl")),
+      etag_path: cache_directory.join(format!("{language}.etag")),
+    }
+  }
+
+  fn content(&self) -> Option<String> {
+    fs::read_to_string(&self.content_path).ok()
+  }
+
+  fn etag(&self) -> Option<String> {
+    fs::read_to_string(&self.etag_path).ok()
+  }
+
+  /// Failing to cache only costs a full download next time, so errors are logged and ignored.
+  fn store(&self, content: &str, etag: Option<&str>) {
+    let stored = self
+      .content_path
+      .parent()
+      .map_or(Ok(()), fs::create_dir_all)
+      .and_then(|()| fs::write(&self.content_path, content))
+      .and_then(|()| match etag {
+        Some(etag) => fs::write(&self.etag_path, etag),
+        None => fs::remove_file(&self.etag_path).or(Ok(())),
+      });
+
+    if let Err(error) = stored {
+      warn!(
+        "Could not cache translations in {}: {error}",
+        self.content_path.display()
+      );
+    }
+  }
+}
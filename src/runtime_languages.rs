@@ -0,0 +1,159 @@
+use crate::{
+  loader::{
+    build_bundle, language_load_errors, locate_files, parse_files, read_nested_files,
+    LoadedResource,
+  },
+  namespaces::namespace_files,
+  Language, TranslationKey, Translator, TranslatorError,
+};
+
+use std::path::Path;
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Adds a language at runtime from FTL contents, added in the given order, replacing the language when it
+  /// was already loaded. The common files of the translator fill its gaps and its runtime overrides are kept.
+  /// The language is only replaced once it loaded successfully, so the default language can be updated too,
+  /// regenerating the pseudo-languages.
+  /// Languages added at runtime are not kept when reloading from the translations directory.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// translator.add_language(&Languages::French, [include_str!("../translations/fr-FR/main.ftl")])?;
+  /// ```
+  pub fn add_language<Sources, Content>(
+    &mut self,
+    language: &LanguageGeneric,
+    ftl_sources: Sources,
+  ) -> Result<(), TranslatorError>
+  where
+    Sources: IntoIterator<Item = Content>,
+    Content: Into<String>,
+  {
+    let language = language.as_str();
+
+    // Zero padded so sorting by name keeps the order the sources were given in.
+    let files = ftl_sources
+      .into_iter()
+      .enumerate()
+      .map(|(index, content)| (content.into(), format!("source {index:06}")))
+      .collect();
+
+    let resources = parse_files(language, files, false);
+
+    self.insert_language(language, resources)
+  }
+
+  /// ### Description
+  /// Adds a language at runtime from the files of a directory, such as `translations/fr-FR`, read with the
+  /// file filter and namespaces of the translator. Behaves like `add_language` otherwise.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// translator.add_language_from_directory(&Languages::French, "translations/fr-FR")?;
+  /// ```
+  pub fn add_language_from_directory(
+    &mut self,
+    language: &LanguageGeneric,
+    directory_path: impl AsRef<Path>,
+  ) -> Result<(), TranslatorError> {
+    let language = language.as_str();
+    let directory_path = directory_path.as_ref();
+
+    let files = read_nested_files(directory_path, &self.options.file_filter)?;
+    let files = namespace_files(Some(language), files, &self.options);
+    let resources = locate_files(parse_files(language, files, false), directory_path, None);
+
+    self.insert_language(language, resources)
+  }
+
+  /// ### Description
+  /// Removes a language at runtime along with its overrides, returning whether it was loaded. Translating
+  /// into it afterwards falls back like for any other unknown language.
+  /// The default language can not be removed and fails with `DefaultLanguage`.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// translator.remove_language(&Languages::French)?;
+  /// ```
+  pub fn remove_language(&mut self, language: &LanguageGeneric) -> Result<bool, TranslatorError> {
+    let language = language.as_str();
+
+    if language == self.default_language {
+      return Err(TranslatorError::DefaultLanguage {
+        language: language.to_string(),
+      });
+    }
+
+    self.overrides.remove(language);
+
+    let removed = self.translations.remove(language);
+
+    if removed {
+      info!("Removed language {language}");
+      self.clear_format_cache();
+    }
+
+    Ok(removed)
+  }
+
+  /// Builds the bundle of a language added at runtime, failing without modifying the translator when the
+  /// language does not load with the translator options.
+  fn insert_language(
+    &mut self,
+    language: &str,
+    mut resources: Vec<LoadedResource>,
+  ) -> Result<(), TranslatorError> {
+    let language_identifier = self
+      .options
+      .language_parser
+      .parse(language)
+      .ok_or_else(|| TranslatorError::UnknownLanguage {
+        language: language.to_string(),
+      })?;
+
+    // Common resources are shared by every language, the default one always has them.
+    resources.extend(
+      self
+        .translations
+        .resources(self.default_language)
+        .into_iter()
+        .flatten()
+        .filter(|loaded_resource| loaded_resource.common)
+        .cloned(),
+    );
+
+    let (bundle, conflicts) =
+      build_bundle(language_identifier, &resources, language, &self.options);
+    let load_errors = language_load_errors(language, &resources, conflicts, &self.options);
+
+    if !load_errors.is_empty() {
+      return Err(TranslatorError::LoadErrors(load_errors));
+    }
+
+    info!("Added language {language}");
+
+    self
+      .translations
+      .insert(language.to_string(), bundle, resources);
+
+    if self.overrides.contains_key(language) {
+      self.rebuild_bundle(language);
+    } else {
+      self.clear_format_cache();
+    }
+
+    // Pseudo-languages are generated from the default language, so they follow its replacement.
+    if language == self.default_language {
+      for pseudo_language in self.options.pseudo_languages.clone() {
+        self.add_pseudo_language(&pseudo_language)?;
+      }
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,152 @@
+use crate::{
+  Language, MessageTranslator, Translation, TranslationError, TranslationKey, Translator,
+};
+
+use std::borrow::Cow;
+
+/// A translator bound to a single language, returned by [`Translator::for_language`] and
+/// [`Translator::resolve`], so code which already knows the language only passes keys and arguments.
+pub struct ScopedTranslator<'translator, LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+{
+  translator: &'translator Translator<LanguageGeneric, TranslationKeyGeneric>,
+  /// `None` when bound by `resolve` to the default language, as no candidate was loaded.
+  language: Option<&'translator LanguageGeneric>,
+  language_name: &'static str,
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Clone
+  for ScopedTranslator<'_, LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+{
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Copy
+  for ScopedTranslator<'_, LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+{
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Binds the translator to a language, such as the locale of the user of a request.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let scoped = translator.for_language(&Languages::Spanish);
+  ///
+  /// let title = scoped.translate_without_arguments(TranslationKeys::Title);
+  /// let greeting = scoped.translate(TranslationKeys::HelloWithArguments).add_argument("name", "Alex");
+  /// ```
+  pub fn for_language<'translator>(
+    &'translator self,
+    language: &'translator LanguageGeneric,
+  ) -> ScopedTranslator<'translator, LanguageGeneric, TranslationKeyGeneric> {
+    ScopedTranslator {
+      translator: self,
+      language: Some(language),
+      language_name: language.as_str(),
+    }
+  }
+
+  /// ### Description
+  /// Binds the translator to the first language of an ordered preference list which is loaded, or has a
+  /// loaded regional variant, such as the language of a user and then the one of their server. Binds the
+  /// default language when no candidate is loaded.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let scoped = translator.resolve(&[&user_language, &guild_language]);
+  ///
+  /// let reply = scoped.translate(TranslationKeys::Welcome).add_argument("name", user.name()).build();
+  /// ```
+  pub fn resolve<'translator>(
+    &'translator self,
+    candidates: &[&'translator LanguageGeneric],
+  ) -> ScopedTranslator<'translator, LanguageGeneric, TranslationKeyGeneric> {
+    let language = candidates
+      .iter()
+      .copied()
+      .find(|candidate| self.matches_loaded_language(candidate.as_str()));
+
+    ScopedTranslator {
+      translator: self,
+      language,
+      language_name: language.map_or(self.default_language, Language::as_str),
+    }
+  }
+}
+
+impl<'translator, LanguageGeneric, TranslationKeyGeneric>
+  ScopedTranslator<'translator, LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// The language the translator is bound to, `None` when `resolve` found no loaded candidate and bound the
+  /// default language.
+  pub fn language(&self) -> Option<&'translator LanguageGeneric> {
+    self.language
+  }
+
+  /// The language messages resolve to, which is the default language when the bound one is not loaded.
+  pub fn resolved_language(&self) -> &'translator str {
+    self.translator.resolve_language(self.language_name)
+  }
+
+  pub fn translate<'args>(
+    &self,
+    key: TranslationKeyGeneric,
+  ) -> MessageTranslator<'translator, 'args, TranslationKeyGeneric> {
+    self.translator.translate_str(self.language_name, key)
+  }
+
+  pub fn translate_without_arguments(&self, key: TranslationKeyGeneric) -> Cow<'translator, str> {
+    self
+      .translator
+      .translate_without_arguments_str(self.language_name, key)
+  }
+
+  pub fn translate_opt(&self, key: TranslationKeyGeneric) -> Option<Cow<'translator, str>> {
+    self.try_translate(key).ok()
+  }
+
+  pub fn try_translate(
+    &self,
+    key: TranslationKeyGeneric,
+  ) -> Result<Cow<'translator, str>, TranslationError> {
+    self.translator.try_translate_str(self.language_name, key)
+  }
+
+  pub fn translate_detailed(&self, key: TranslationKeyGeneric) -> Translation<'translator> {
+    self
+      .translator
+      .translate_detailed_str(self.language_name, key)
+  }
+
+  pub fn translate_attribute(
+    &self,
+    key: TranslationKeyGeneric,
+    attribute: &str,
+  ) -> Cow<'translator, str> {
+    self
+      .translator
+      .translate_attribute_str(self.language_name, key, attribute)
+  }
+
+  pub fn translate_into(&self, key: TranslationKeyGeneric, out: &mut String) -> bool {
+    self
+      .translator
+      .translate_into_str(self.language_name, key, out)
+  }
+}
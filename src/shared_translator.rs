@@ -0,0 +1,99 @@
+use crate::{Language, TranslationKey, Translator, TranslatorError};
+
+use std::{
+  mem,
+  sync::{Arc, Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+/// A translator which can be cloned cheaply across threads and reloaded while it is being used.
+pub struct SharedTranslator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+{
+  inner: Arc<RwLock<Translator<LanguageGeneric, TranslationKeyGeneric>>>,
+  /// Held while reloading or replacing, so a reload never overwrites a translator swapped in meanwhile.
+  swap_lock: Arc<Mutex<()>>,
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Clone
+  for SharedTranslator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+{
+  fn clone(&self) -> Self {
+    Self {
+      inner: Arc::clone(&self.inner),
+      swap_lock: Arc::clone(&self.swap_lock),
+    }
+  }
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric>
+  SharedTranslator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Wraps a translator so it can be shared and reloaded.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let shared = SharedTranslator::new(translator);
+  ///
+  /// let message = shared.read().translate_without_arguments(&language, key).into_owned();
+  /// ```
+  pub fn new(translator: Translator<LanguageGeneric, TranslationKeyGeneric>) -> Self {
+    Self {
+      inner: Arc::new(RwLock::new(translator)),
+      swap_lock: Arc::new(Mutex::new(())),
+    }
+  }
+
+  /// Borrows the current translator. Reloads wait until the guard is dropped, so keep it short lived.
+  pub fn read(&self) -> RwLockReadGuard<'_, Translator<LanguageGeneric, TranslationKeyGeneric>> {
+    self.inner.read().unwrap_or_else(PoisonError::into_inner)
+  }
+
+  pub(crate) fn write(
+    &self,
+  ) -> RwLockWriteGuard<'_, Translator<LanguageGeneric, TranslationKeyGeneric>> {
+    self.inner.write().unwrap_or_else(PoisonError::into_inner)
+  }
+
+  /// ### Description
+  /// Reloads the translations from disk. They are loaded without blocking readers and swapped in at once,
+  /// so requests see either the previous or the new translations, never a mix. Concurrent reloads and
+  /// replacements run one after the other.
+  pub fn reload(&self) -> Result<(), TranslatorError> {
+    let _swap_guard = self.swap_lock();
+    let translator = self.read().reloaded()?;
+    *self.write() = translator;
+    Ok(())
+  }
+
+  /// ### Description
+  /// Swaps in a translator built elsewhere, such as from another directory or from sources fetched at
+  /// runtime, and returns the previous one. Readers see either translator, never a mix.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::new(new_directory, &Languages::English)?;
+  ///
+  /// shared.replace(translator);
+  /// ```
+  pub fn replace(
+    &self,
+    translator: Translator<LanguageGeneric, TranslationKeyGeneric>,
+  ) -> Translator<LanguageGeneric, TranslationKeyGeneric> {
+    let _swap_guard = self.swap_lock();
+    mem::replace(&mut *self.write(), translator)
+  }
+
+  fn swap_lock(&self) -> MutexGuard<'_, ()> {
+    self
+      .swap_lock
+      .lock()
+      .unwrap_or_else(PoisonError::into_inner)
+  }
+}
@@ -0,0 +1,142 @@
+use crate::{
+  loader::{
+    parse_common_files, parse_files, read_common_files, read_language_names,
+    read_single_language_files, FileData,
+  },
+  Language, TranslationKey, Translator, TranslatorError, TranslatorOptions,
+};
+
+use std::{collections::HashMap, path::PathBuf};
+
+/// ### Description
+/// Where translations are loaded from, so they can come from a database, an object storage or a translation
+/// service instead of a directory. Resources are `(file_name, ftl_content)` pairs, added to the bundle of
+/// their language in file name order.
+/// ### Usage
+/// ```ignore
+/// struct DatabaseSource { pool: Pool }
+///
+/// impl TranslationSource for DatabaseSource {
+///   fn languages(&self) -> Result<Vec<String>, TranslatorError> {
+///     Ok(self.pool.query_languages())
+///   }
+///
+///   fn resources(&self, language: &str) -> Result<Vec<(String, String)>, TranslatorError> {
+///     Ok(self.pool.query_resources(language))
+///   }
+/// }
+///
+/// let translator = Translator::<Languages, TranslationKeys>::from_source(&source, &Languages::English)?;
+/// ```
+pub trait TranslationSource {
+  /// Codes of the languages the source provides.
+  fn languages(&self) -> Result<Vec<String>, TranslatorError>;
+
+  /// `(file_name, ftl_content)` pairs of a language.
+  fn resources(&self, language: &str) -> Result<Vec<(String, String)>, TranslatorError>;
+
+  /// `(file_name, ftl_content)` pairs shared by every language, which only fill the messages a language
+  /// does not define.
+  fn common_resources(&self) -> Result<Vec<(String, String)>, TranslatorError> {
+    Ok(Vec::new())
+  }
+}
+
+/// The translations directory layout read by [`Translator::new`] as a [`TranslationSource`], with the
+/// default loading options.
+#[derive(Debug, Clone)]
+pub struct FileSystemSource {
+  directory_path: PathBuf,
+  options: TranslatorOptions,
+}
+
+impl FileSystemSource {
+  pub fn new(directory_path: impl Into<PathBuf>) -> Self {
+    Self {
+      directory_path: directory_path.into(),
+      options: TranslatorOptions::default(),
+    }
+  }
+}
+
+impl TranslationSource for FileSystemSource {
+  fn languages(&self) -> Result<Vec<String>, TranslatorError> {
+    read_language_names(&self.directory_path, &self.options)
+  }
+
+  fn resources(&self, language: &str) -> Result<Vec<(String, String)>, TranslatorError> {
+    let files = read_single_language_files(&self.directory_path, language, &self.options)?;
+    Ok(
+      files
+        .into_iter()
+        .map(|(content, file_name)| (file_name, content))
+        .collect(),
+    )
+  }
+
+  fn common_resources(&self) -> Result<Vec<(String, String)>, TranslatorError> {
+    let files = read_common_files(&self.directory_path, &self.options)?;
+    Ok(
+      files
+        .into_iter()
+        .map(|(content, file_name)| (file_name, content))
+        .collect(),
+    )
+  }
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Creates a translator from any [`TranslationSource`]. Like `from_sources`, the translator has no
+  /// directory, so it can not be reloaded nor watched, build a new one from the source instead.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let source = FileSystemSource::new("translations");
+  ///
+  /// let translator = Translator::<Languages, TranslationKeys>::from_source(&source, &Languages::English)?;
+  /// ```
+  pub fn from_source<Source>(
+    source: &Source,
+    default_language: &LanguageGeneric,
+  ) -> Result<Translator<LanguageGeneric, TranslationKeyGeneric>, TranslatorError>
+  where
+    Source: TranslationSource + ?Sized,
+  {
+    info!("Loading languages from a translation source...");
+
+    let options = TranslatorOptions::default();
+    let common_resources = parse_common_files(file_data(source.common_resources()?), false);
+    let mut language_resources = HashMap::new();
+
+    for language_name in source.languages()? {
+      let Some(language_identifier) = options.language_parser.parse(&language_name) else {
+        warn!(
+          "Ignoring source language {} as it is not a valid language identifier",
+          language_name
+        );
+        continue;
+      };
+
+      let files = file_data(source.resources(&language_name)?);
+
+      let mut resources = parse_files(&language_name, files, false);
+      resources.extend(common_resources.iter().cloned());
+
+      language_resources.insert(language_name, (language_identifier, resources));
+    }
+
+    Self::from_language_resources(language_resources, None, default_language.as_str(), options)
+  }
+}
+
+fn file_data(resources: Vec<(String, String)>) -> Vec<FileData> {
+  resources
+    .into_iter()
+    .map(|(file_name, content)| (content, file_name))
+    .collect()
+}
@@ -0,0 +1,115 @@
+use crate::{Language, TranslationKey, Translator};
+
+use std::{collections::HashSet, mem, sync::Arc};
+
+use fluent_syntax::ast::Entry;
+
+/// What was loaded for a language, part of [`TranslatorStats`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageStats {
+  pub language: String,
+  /// Whether the language was loaded, being `false` for languages indexed by lazy loading and not used yet.
+  pub loaded: bool,
+  /// Messages the language defines, including common files and runtime overrides.
+  pub messages: usize,
+  /// Resources the language was built from, including common files and runtime overrides.
+  pub resources: usize,
+  /// Size of the sources of the resources of the language.
+  pub source_bytes: usize,
+}
+
+/// What a translator loaded, returned by [`Translator::stats`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslatorStats {
+  /// Statistics of every language, ordered by language.
+  pub languages: Vec<LanguageStats>,
+  /// Resources loaded, counting the ones shared by every language once.
+  pub resources: usize,
+  /// Size of the sources of the resources loaded, counting the ones shared by every language once.
+  pub source_bytes: usize,
+  /// Rough estimate of the memory held by the resources: their sources plus their parsed entries. Bundles
+  /// and caches are not included.
+  pub approximate_bytes: usize,
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Reports how many messages, resources and source bytes every language loaded, along with an estimate of
+  /// the memory they take. Languages indexed by lazy loading are reported without being loaded.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let stats = translator.stats();
+  ///
+  /// for language in &stats.languages {
+  ///   println!("{}: {} messages in {} files", language.language, language.messages, language.resources);
+  /// }
+  /// println!("About {} KiB of translations", stats.approximate_bytes / 1024);
+  /// ```
+  pub fn stats(&self) -> TranslatorStats {
+    let mut languages: Vec<&str> = self.translations.keys().collect();
+    languages.sort_unstable();
+
+    let mut seen_resources = HashSet::new();
+    let mut stats = TranslatorStats {
+      languages: Vec::with_capacity(languages.len()),
+      resources: 0,
+      source_bytes: 0,
+      approximate_bytes: 0,
+    };
+
+    for language in languages {
+      let Some(loaded) = self.translations.loaded(language) else {
+        stats.languages.push(LanguageStats {
+          language: language.to_string(),
+          loaded: false,
+          messages: 0,
+          resources: 0,
+          source_bytes: 0,
+        });
+        continue;
+      };
+
+      let overrides = self
+        .overrides
+        .get(language)
+        .into_iter()
+        .flat_map(|overrides| overrides.values());
+      let resources = loaded
+        .resources
+        .iter()
+        .map(|loaded_resource| &loaded_resource.resource)
+        .chain(overrides);
+
+      let mut resource_count = 0;
+      let mut source_bytes = 0;
+
+      for resource in resources {
+        let size = resource.source().len();
+        resource_count += 1;
+        source_bytes += size;
+
+        if seen_resources.insert(Arc::as_ptr(resource)) {
+          stats.resources += 1;
+          stats.source_bytes += size;
+          stats.approximate_bytes +=
+            size + resource.entries().count() * mem::size_of::<Entry<&'static str>>();
+        }
+      }
+
+      stats.languages.push(LanguageStats {
+        language: language.to_string(),
+        loaded: true,
+        messages: self.message_keys(language).count(),
+        resources: resource_count,
+        source_bytes,
+      });
+    }
+
+    stats
+  }
+}
@@ -0,0 +1,84 @@
+use crate::{
+  ast::{message_patterns, walk_pattern, Node},
+  loader::LoadedResource,
+  LoadError,
+};
+
+use std::collections::HashSet;
+
+use fluent_syntax::ast::{Entry, InlineExpression};
+
+/// File holding the terms of a language, loaded before its other files.
+const TERMS_FILE: &str = "_terms.ftl";
+/// Directory holding the terms of a language, loaded before its other files.
+const TERMS_DIRECTORY: &str = "terms";
+
+/// Whether a file holds terms, being named `_terms.ftl` or placed in a `terms/` directory, at any depth.
+/// Term files are loaded first, so their terms are the ones kept when a later file defines them again.
+pub(crate) fn is_term_file(file_name: &str) -> bool {
+  let mut components: Vec<&str> = file_name.split('/').collect();
+  let file = components.pop().unwrap_or_default();
+
+  file == TERMS_FILE || components.contains(&TERMS_DIRECTORY)
+}
+
+/// Lists the terms referenced by the messages of a language which none of its resources define, including
+/// the common ones shared by every language.
+pub(crate) fn missing_terms(language_name: &str, resources: &[LoadedResource]) -> Vec<LoadError> {
+  let defined_terms: HashSet<&str> = resources
+    .iter()
+    .flat_map(|loaded_resource| loaded_resource.resource.entries())
+    .filter_map(|entry| match entry {
+      Entry::Term(term) => Some(term.id.name),
+      _ => None,
+    })
+    .collect();
+
+  let mut missing_terms = Vec::new();
+
+  for loaded_resource in resources {
+    let messages = loaded_resource
+      .resource
+      .entries()
+      .filter_map(|entry| match entry {
+        Entry::Message(message) => Some(message),
+        _ => None,
+      });
+
+    for message in messages {
+      let mut terms = Vec::new();
+
+      for pattern in message_patterns(message) {
+        walk_pattern(pattern, &mut |node| {
+          if let Node::Inline(InlineExpression::TermReference { id, .. }) = node {
+            terms.push(id.name);
+          }
+        });
+      }
+
+      terms.sort_unstable();
+      terms.dedup();
+
+      missing_terms.extend(
+        terms
+          .into_iter()
+          .filter(|term| !defined_terms.contains(term))
+          .map(|term| LoadError::MissingTerm {
+            language: language_name.to_string(),
+            file_name: loaded_resource.file_name.clone(),
+            key: message.id.name.to_string(),
+            term: format!("-{term}"),
+          }),
+      );
+    }
+  }
+
+  missing_terms
+}
+
+/// Logs the terms referenced by messages which are not defined.
+pub(crate) fn log_missing_terms(missing_terms: &[LoadError]) {
+  for missing_term in missing_terms {
+    warn!("{missing_term}");
+  }
+}
@@ -0,0 +1,166 @@
+use crate::{Language, TranslationError, TranslationKey, Translator};
+
+use std::{fmt, marker::PhantomData};
+
+use fluent_bundle::{FluentArgs, FluentValue};
+
+/// A message captured with its arguments and optionally its language, to be translated later, such as
+/// errors of a library layer which are only rendered by the presentation layer. Bind it to a translator
+/// with [`Translatable::bind`] to display it.
+pub struct Translatable<TranslationKeyGeneric> {
+  key: &'static str,
+  language: Option<&'static str>,
+  args: FluentArgs<'static>,
+  phantom: PhantomData<TranslationKeyGeneric>,
+}
+
+impl<TranslationKeyGeneric> fmt::Debug for Translatable<TranslationKeyGeneric> {
+  fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    formatter
+      .debug_struct("Translatable")
+      .field("key", &self.key)
+      .field("language", &self.language)
+      .field("args", &self.args)
+      .finish()
+  }
+}
+
+impl<TranslationKeyGeneric> Translatable<TranslationKeyGeneric>
+where
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Captures a message, translated to the language it is bound with or the default language.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// fn validate(name: &str) -> Result<(), Translatable<TranslationKeys>> {
+  ///   if name.is_empty() {
+  ///     return Err(Translatable::new(TranslationKeys::NameRequired));
+  ///   }
+  ///   Ok(())
+  /// }
+  /// ```
+  pub fn new(key: TranslationKeyGeneric) -> Self {
+    Self {
+      key: key.as_str(),
+      language: None,
+      args: FluentArgs::new(),
+      phantom: PhantomData,
+    }
+  }
+
+  /// ### Description
+  /// Sets the language the message is translated to, unless a language is given when binding it.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let message = Translatable::new(TranslationKeys::Welcome).language(&user.language);
+  /// ```
+  pub fn language<LanguageGeneric>(mut self, language: &LanguageGeneric) -> Self
+  where
+    LanguageGeneric: Language,
+  {
+    self.language = Some(language.as_str());
+    self
+  }
+
+  /// ### Description
+  /// Adds an argument the message is formatted with.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let message = Translatable::new(TranslationKeys::HelloWithArguments).add_argument("name", "Alex");
+  /// ```
+  pub fn add_argument<Value>(mut self, name: &str, value: Value) -> Self
+  where
+    Value: Into<FluentValue<'static>>,
+  {
+    self.args.set(name.to_string(), value);
+    self
+  }
+
+  /// ### Description
+  /// Binds the message to a translator, rendering it in the language it was captured with or the default
+  /// language once displayed.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// if let Err(message) = validate(&name) {
+  ///   println!("{}", message.bind(&translator));
+  /// }
+  /// ```
+  pub fn bind<'translatable, LanguageGeneric>(
+    &'translatable self,
+    translator: &'translatable Translator<LanguageGeneric, TranslationKeyGeneric>,
+  ) -> BoundTranslatable<'translatable, LanguageGeneric, TranslationKeyGeneric>
+  where
+    LanguageGeneric: Language,
+  {
+    BoundTranslatable {
+      translatable: self,
+      translator,
+      language: self.language.unwrap_or(translator.default_language),
+    }
+  }
+
+  /// ### Description
+  /// Binds the message to a translator and a language, which takes precedence over the language the
+  /// message was captured with.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let text = message.bind_to(&translator, &request.language).to_string();
+  /// ```
+  pub fn bind_to<'translatable, LanguageGeneric>(
+    &'translatable self,
+    translator: &'translatable Translator<LanguageGeneric, TranslationKeyGeneric>,
+    language: &LanguageGeneric,
+  ) -> BoundTranslatable<'translatable, LanguageGeneric, TranslationKeyGeneric>
+  where
+    LanguageGeneric: Language,
+  {
+    BoundTranslatable {
+      translatable: self,
+      translator,
+      language: language.as_str(),
+    }
+  }
+}
+
+/// A [`Translatable`] bound to a translator and a language, translated when displayed. Messages which
+/// can not be translated are displayed like `translate` returns them.
+pub struct BoundTranslatable<'translatable, LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+{
+  translatable: &'translatable Translatable<TranslationKeyGeneric>,
+  translator: &'translatable Translator<LanguageGeneric, TranslationKeyGeneric>,
+  language: &'static str,
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> fmt::Display
+  for BoundTranslatable<'_, LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let Translatable { key, args, .. } = self.translatable;
+    let args = args.iter().next().is_some().then_some(args);
+
+    match self
+      .translator
+      .format_into_str(formatter, self.language, key, args)
+    {
+      Ok(()) | Err(TranslationError::FormattingErrors { .. }) => Ok(()),
+      Err(TranslationError::WriteError { .. }) => Err(fmt::Error),
+      Err(_) => formatter.write_str(
+        &self
+          .translator
+          .missing_text(self.language)
+          .text(key, None, args),
+      ),
+    }
+  }
+}
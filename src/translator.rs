@@ -1,38 +1,140 @@
-use crate::{MessageTranslator, TranslatorError, TRANSLATION_FAILED};
+use crate::{
+  format_cache::FormatCache,
+  loaded_languages::{index_languages, LanguageSlots, LazyLoader, LoadedLanguages},
+  loader::{
+    build_bundle, language_load_errors, locate_files, map_languages, new_bundle,
+    overlay_directories, parse_common_files, parse_files, read_directory_index,
+    read_language_files, LanguageFiles, LoadedResource, TranslationFiles,
+  },
+  message_translator::MissingText,
+  namespaces::{check_namespace_separator, namespace_files},
+  unknown_arguments::check_unknown_arguments,
+  BidiIsolation, FluentFunctionFn, MessageTranslator, TextTransform, TranslationError,
+  TranslatorBuilder, TranslatorError, TranslatorOptions,
+};
 
 use std::{
   borrow::Cow,
-  collections::HashMap,
-  fs::{self, DirEntry},
-  io::Error as IoError,
+  collections::{BTreeMap, HashMap},
+  fmt, io,
   marker::PhantomData,
+  path::{Path, PathBuf},
+  sync::Arc,
 };
 
-use fluent_bundle::{bundle::FluentBundle, FluentMessage, FluentResource};
+use fluent_bundle::{
+  bundle::FluentBundle, FluentArgs, FluentError, FluentMessage, FluentResource, FluentValue,
+};
+use fluent_langneg::{negotiate_languages, NegotiationStrategy};
+use fluent_syntax::ast::Pattern;
 use intl_memoizer::concurrent::IntlLangMemoizer;
-use tracing::{debug, info, trace, warn};
 use unic_langid::LanguageIdentifier;
 
-pub type Bundle = FluentBundle<FluentResource, IntlLangMemoizer>;
-type DirectoryData = (DirEntry, String);
+pub type Bundle = FluentBundle<Arc<FluentResource>, IntlLangMemoizer>;
+
+/// Translated text along with how it was resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Translation<'lifetime> {
+  pub text: Cow<'lifetime, str>,
+  /// Whether the text did not come from the requested language, because the language or the message was
+  /// missing, or whether translating failed altogether.
+  pub used_fallback: bool,
+  pub effective_language: String,
+}
 
 pub trait TranslationKey {
   fn as_str(&self) -> &'static str;
+
+  /// Resolves a key from its string form, needed by [`Translator::translate_by_str`].
+  fn from_str(_key: &str) -> Option<Self>
+  where
+    Self: Sized,
+  {
+    None
+  }
+
+  /// Arguments the message expects. When not empty, building fails with `ArgumentMismatch` if an argument
+  /// is missing or one not listed is passed, catching arguments renamed in the translation files only.
+  fn required_args(&self) -> &'static [&'static str] {
+    &[]
+  }
+
+  /// Arguments the message is formatted with unless the call passes an argument with the same name, such
+  /// as a `count` which is almost always `1`. Used by every method translating a `TranslationKey`.
+  fn default_args(&self) -> FluentArgs<'static> {
+    FluentArgs::new()
+  }
 }
 
 pub trait Language {
   fn as_str(&self) -> &'static str;
+
+  /// Resolves a language from its string form, needed by [`Translator::translate_by_str`].
+  fn from_str(_language: &str) -> Option<Self>
+  where
+    Self: Sized,
+  {
+    None
+  }
 }
 
 pub struct Translator<LanguageGeneric, TranslationKeyGeneric>
 where
   LanguageGeneric: Language,
 {
-  translations: HashMap<String, Bundle>,
-  default_language: &'static str,
+  pub(crate) translations: LoadedLanguages,
+  pub(crate) overrides: HashMap<String, BTreeMap<String, Arc<FluentResource>>>,
+  pub(crate) directory_path: Option<String>,
+  pub(crate) default_language: &'static str,
+  pub(crate) identity: bool,
+  pub(crate) options: TranslatorOptions,
+  pub(crate) format_cache: Option<FormatCache>,
   phantom: PhantomData<(LanguageGeneric, TranslationKeyGeneric)>,
 }
 
+/// Shows the loaded languages and where they were loaded from, without the messages.
+impl<LanguageGeneric, TranslationKeyGeneric> fmt::Debug
+  for Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+{
+  fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut languages: Vec<&str> = self.translations.keys().collect();
+    languages.sort_unstable();
+
+    formatter
+      .debug_struct("Translator")
+      .field("languages", &languages)
+      .field("default_language", &self.default_language)
+      .field("directory_path", &self.directory_path)
+      .field("identity", &self.identity)
+      .finish_non_exhaustive()
+  }
+}
+
+/// `Translator` holds no interior references to its languages or keys, so it is `Send` and `Sync` whenever
+/// they are, and can be shared across threads in an `Arc` or a `SharedTranslator`.
+const _: () = {
+  const fn assert_send_sync<Value: Send + Sync>() {}
+
+  struct AnyLanguage;
+  struct AnyKey;
+
+  impl Language for AnyLanguage {
+    fn as_str(&self) -> &'static str {
+      ""
+    }
+  }
+
+  impl TranslationKey for AnyKey {
+    fn as_str(&self) -> &'static str {
+      ""
+    }
+  }
+
+  assert_send_sync::<Translator<AnyLanguage, AnyKey>>();
+};
+
 impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
 where
   LanguageGeneric: Language,
@@ -40,8 +142,11 @@ where
 {
   /// ### Description
   /// Creates a new translator, loading translations from directory path and setting default language.
+  /// Each language is read from a `<language>/` directory, a top level `<language>.ftl` file, or both.
+  /// Term files, named `_terms.ftl` or placed in a `terms/` directory, are loaded before the other files of
+  /// their language, and terms in the common directory are shared by every language.
   /// ### Usage
-  /// ```
+  /// ```ignore
   /// use translate::{Translator, TranslationKey, Language};
   ///
   /// enum TranslationKeys {
@@ -80,244 +185,1646 @@ where
     directory_path: &str,
     default_language: &LanguageGeneric,
   ) -> Result<Translator<LanguageGeneric, TranslationKeyGeneric>, TranslatorError> {
+    TranslatorBuilder::new()
+      .directory(directory_path)
+      .default_language(default_language)
+      .build()
+  }
+
+  /// ### Description
+  /// Creates a new translator like `new`, but fails with `LoadErrors` listing every syntax error and redefined
+  /// message found in the translation files instead of logging and skipping them.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// match Translator::<Languages, TranslationKeys>::new_strict(path, &Languages::English) {
+  ///   Ok(translator) => serve(translator),
+  ///   Err(TranslatorError::LoadErrors(errors)) => errors.iter().for_each(|error| eprintln!("{error}")),
+  ///   Err(error) => eprintln!("{error}"),
+  /// }
+  /// ```
+  pub fn new_strict(
+    directory_path: &str,
+    default_language: &LanguageGeneric,
+  ) -> Result<Translator<LanguageGeneric, TranslationKeyGeneric>, TranslatorError> {
+    TranslatorBuilder::new()
+      .directory(directory_path)
+      .default_language(default_language)
+      .strict(true)
+      .build()
+  }
+
+  /// ### Description
+  /// Creates a builder to configure how translations are loaded and used.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::builder()
+  ///   .directory("path/to/translations")
+  ///   .default_language(&Languages::English)
+  ///   .build()?;
+  /// ```
+  pub fn builder() -> TranslatorBuilder<LanguageGeneric, TranslationKeyGeneric> {
+    TranslatorBuilder::new()
+  }
+
+  pub(crate) fn load(
+    directory_path: &str,
+    default_language: &'static str,
+    options: TranslatorOptions,
+  ) -> Result<Translator<LanguageGeneric, TranslationKeyGeneric>, TranslatorError> {
+    if options.lazy_loading {
+      return Self::load_lazily(directory_path, default_language, options);
+    }
+
     info!("Loading langauges...");
 
-    let translations_directory =
-      fs::read_dir(directory_path).map_err(|error| TranslatorError::ReadDirError {
-        directory_path: directory_path.to_string(),
-        detail: error.to_string(),
-      })?;
+    let language_files = read_language_files(Path::new(directory_path), &options)?;
 
-    let mut translations = HashMap::new();
+    let overlay_files = overlay_directories(&options)
+      .iter()
+      .map(|overlay_directory| read_language_files(overlay_directory, &options))
+      .collect::<Result<Vec<_>, _>>()?;
 
-    for directory_entry_result in translations_directory {
-      let directory_data = get_directory_data(directory_entry_result);
+    Self::from_language_files(
+      language_files,
+      overlay_files,
+      directory_path,
+      default_language,
+      options,
+    )
+  }
 
-      let Some(directory_data) = directory_data else {
-        continue;
-      };
+  /// Indexes the languages of a translations directory and the directories loaded on top of it, loading
+  /// only the default language. Other languages are loaded the first time they are used.
+  fn load_lazily(
+    directory_path: &str,
+    default_language: &'static str,
+    options: TranslatorOptions,
+  ) -> Result<Translator<LanguageGeneric, TranslationKeyGeneric>, TranslatorError> {
+    info!("Indexing languages...");
+
+    let indexes = std::iter::once(PathBuf::from(directory_path))
+      .chain(overlay_directories(&options))
+      .map(|directory| read_directory_index(&directory, &options))
+      .collect::<Result<Vec<_>, _>>()?;
+
+    let (language_slots, lazy_loader) = index_languages(indexes, &options)?;
+
+    Self::from_language_slots(
+      language_slots,
+      lazy_loader,
+      directory_path,
+      default_language,
+      options,
+    )
+  }
+
+  /// Creates a translator from indexed languages, loading the default one unless it already is.
+  pub(crate) fn from_language_slots(
+    language_slots: LanguageSlots,
+    lazy_loader: LazyLoader,
+    directory_path: &str,
+    default_language: &'static str,
+    options: TranslatorOptions,
+  ) -> Result<Translator<LanguageGeneric, TranslationKeyGeneric>, TranslatorError> {
+    let translations = language_slots
+      .into_languages(default_language, Some(lazy_loader))
+      .ok_or(TranslatorError::NoDefaultLanuage)?;
 
-      let (directory, directory_name) = directory_data;
+    Self::from_loaded_languages(
+      translations,
+      Some(directory_path.to_string()),
+      default_language,
+      options,
+    )
+  }
+
+  /// Parses the files read from a translations directory and the directories loaded on top of it.
+  /// Every language gets its own files, then the common files, then the common and language files of every
+  /// overlay in order.
+  pub(crate) fn from_language_files(
+    translation_files: TranslationFiles,
+    overlay_files: Vec<TranslationFiles>,
+    directory_path: &str,
+    default_language: &'static str,
+    options: TranslatorOptions,
+  ) -> Result<Translator<LanguageGeneric, TranslationKeyGeneric>, TranslatorError> {
+    check_namespace_separator(&options)?;
+
+    let directory = translation_files.directory;
+    let common_files = namespace_files(None, translation_files.common, &options);
+    let common_resources = locate_files(parse_common_files(common_files, false), &directory, None);
+
+    let mut language_resources: HashMap<_, _> = map_languages(
+      translation_files.languages.into_iter().collect(),
+      options.parallel_loading,
+      |(language_name, (language_identifier, files))| {
+        debug!("Loading translations for {}", language_name);
+
+        let files = namespace_files(Some(&language_name), files, &options);
+
+        let mut resources = locate_files(
+          parse_files(&language_name, files, false),
+          &directory,
+          Some(&language_name),
+        );
+        resources.extend(common_resources.iter().cloned());
+        (language_name, (language_identifier, resources))
+      },
+    )
+    .into_iter()
+    .collect();
+
+    for overlay_files in overlay_files {
+      let overlay_common_files = namespace_files(None, overlay_files.common, &options);
+      let overlay_common_resources = locate_files(
+        parse_common_files(overlay_common_files, true),
+        &overlay_files.directory,
+        None,
+      );
+
+      for (_, resources) in language_resources.values_mut() {
+        resources.extend(overlay_common_resources.iter().cloned());
+      }
+
+      for (language_name, (language_identifier, files)) in overlay_files.languages {
+        let files = namespace_files(Some(&language_name), files, &options);
+        let overlay_resources = locate_files(
+          parse_files(&language_name, files, true),
+          &overlay_files.directory,
+          Some(&language_name),
+        );
 
-      let Ok(language_identifier) = directory_name.parse::<LanguageIdentifier>() else {
+        language_resources
+          .entry(language_name)
+          .or_insert_with(|| (language_identifier, Vec::new()))
+          .1
+          .extend(overlay_resources);
+      }
+    }
+
+    Self::from_language_resources(
+      language_resources,
+      Some(directory_path.to_string()),
+      default_language,
+      options,
+    )
+  }
+
+  /// ### Description
+  /// Creates a translator from in-memory `(language, ftl_content)` pairs instead of a translations directory,
+  /// e.g. translations embedded in the binary with `include_str!`. A language may appear in several pairs,
+  /// its sources are added in the given order.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let sources = [
+  ///   ("en-US", include_str!("../translations/en-US/main.ftl")),
+  ///   ("es-ES", include_str!("../translations/es-ES/main.ftl")),
+  /// ];
+  ///
+  /// let translator = Translator::<Languages, TranslationKeys>::from_sources(sources, &Languages::English)?;
+  /// ```
+  pub fn from_sources<Sources, Name, Content>(
+    sources: Sources,
+    default_language: &LanguageGeneric,
+  ) -> Result<Translator<LanguageGeneric, TranslationKeyGeneric>, TranslatorError>
+  where
+    Sources: IntoIterator<Item = (Name, Content)>,
+    Name: AsRef<str>,
+    Content: Into<String>,
+  {
+    info!("Loading languages from sources...");
+
+    let options = TranslatorOptions::default();
+    let mut language_files: LanguageFiles = HashMap::new();
+
+    for (index, (language_name, content)) in sources.into_iter().enumerate() {
+      let language_name = language_name.as_ref();
+
+      let Some(language_identifier) = options.language_parser.parse(language_name) else {
         warn!(
-          "Ignoring {} as it is not a valid langugae identifier",
-          directory_name
+          "Ignoring source for {} as it is not a valid language identifier",
+          language_name
         );
         continue;
       };
 
-      debug!("Loading translations for {}", directory_name);
+      // Zero padded so sorting by name keeps the order the sources were given in.
+      let source_name = format!("source {index:06}");
+
+      language_files
+        .entry(language_name.to_string())
+        .or_insert_with(|| (language_identifier, Vec::new()))
+        .1
+        .push((content.into(), source_name));
+    }
+
+    let language_resources = language_files
+      .into_iter()
+      .map(|(language_name, (language_identifier, files))| {
+        let resources = parse_files(&language_name, files, false);
+        (language_name, (language_identifier, resources))
+      })
+      .collect();
 
-      let languages = vec![language_identifier];
-      let mut bundle = Bundle::new_concurrent(languages);
+    Self::from_language_resources(language_resources, None, default_language.as_str(), options)
+  }
 
-      let language_directory =
-        fs::read_dir(directory.path()).map_err(|error| TranslatorError::ReadDirError {
-          directory_path: directory.path().to_string_lossy().to_string(),
-          detail: error.to_string(),
-        })?;
+  pub(crate) fn from_language_resources(
+    language_resources: HashMap<String, (LanguageIdentifier, Vec<LoadedResource>)>,
+    directory_path: Option<String>,
+    default_language: &'static str,
+    options: TranslatorOptions,
+  ) -> Result<Translator<LanguageGeneric, TranslationKeyGeneric>, TranslatorError> {
+    let mut language_slots = LanguageSlots::default();
+    let mut load_errors = Vec::new();
 
-      for file_entry_result in language_directory {
-        let file_data = get_file_data(file_entry_result);
+    let mut language_resources: Vec<_> = language_resources.into_iter().collect();
+    language_resources.sort_by(|(first_name, _), (second_name, _)| first_name.cmp(second_name));
 
-        let Some(file_data) = file_data else {
-          continue;
-        };
+    let built_languages = map_languages(
+      language_resources,
+      options.parallel_loading,
+      |(language_name, (language_identifier, loaded_resources))| {
+        let (bundle, conflicts) = build_bundle(
+          language_identifier,
+          &loaded_resources,
+          &language_name,
+          &options,
+        );
 
-        let (content, file_name) = file_data;
+        (language_name, loaded_resources, bundle, conflicts)
+      },
+    );
 
-        let resource = FluentResource::try_new(content);
+    for (language_name, loaded_resources, bundle, conflicts) in built_languages {
+      load_errors.extend(language_load_errors(
+        &language_name,
+        &loaded_resources,
+        conflicts,
+        &options,
+      ));
 
-        match resource {
-          Ok(resource) => {
-            let bundle_result = bundle.add_resource(resource);
-            if bundle_result.is_err() {
-              warn!("Could not add resource from file {file_name} from language {directory_name}");
-            }
-          }
-          Err(_) => {
-            warn!("Corrupt entry found in file {file_name} from langauge {directory_name}")
-          }
-        };
-      }
+      language_slots.insert(language_name, bundle, loaded_resources);
+    }
 
-      translations.insert(directory_name, bundle);
+    if !load_errors.is_empty() {
+      return Err(TranslatorError::LoadErrors(load_errors));
     }
 
-    info!("Successfully loaded {} languages", translations.len());
+    info!("Successfully loaded {} languages", language_slots.len());
 
-    if !translations.contains_key(default_language.as_str()) {
-      return Err(TranslatorError::NoDefaultLanuage);
-    }
+    let translations = language_slots
+      .into_languages(default_language, None)
+      .ok_or(TranslatorError::NoDefaultLanuage)?;
+
+    Self::from_loaded_languages(translations, directory_path, default_language, options)
+  }
+
+  /// Creates the translator once its languages are loaded or indexed, then generates its pseudo-languages.
+  fn from_loaded_languages(
+    translations: LoadedLanguages,
+    directory_path: Option<String>,
+    default_language: &'static str,
+    options: TranslatorOptions,
+  ) -> Result<Translator<LanguageGeneric, TranslationKeyGeneric>, TranslatorError> {
+    let pseudo_languages = options.pseudo_languages.clone();
 
-    Ok(Translator {
+    let mut translator = Translator {
       translations,
-      default_language: default_language.as_str(),
+      overrides: HashMap::new(),
+      directory_path,
+      default_language,
+      identity: false,
+      format_cache: options.format_cache_capacity.map(FormatCache::new),
+      options,
       phantom: PhantomData,
-    })
+    };
+
+    for pseudo_language in &pseudo_languages {
+      translator.add_pseudo_language(pseudo_language)?;
+    }
+
+    Ok(translator)
   }
 
-  pub fn get_message<'lifetime>(
-    &'lifetime self,
-    language: &LanguageGeneric,
-    key: &TranslationKeyGeneric,
-  ) -> (Option<FluentMessage>, &'lifetime Bundle) {
-    let translation_key = key.as_str();
-    let default_language = self.default_language;
+  /// ### Description
+  /// Creates a translator that reads nothing from disk and echoes translation keys back, meant for testing
+  /// code that calls the translator without needing translation files.
+  /// `translate_without_arguments` returns the key and `build` returns the key followed by its arguments.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translator = Translator::<Languages, TranslationKeys>::identity(&Languages::English);
+  ///
+  /// let message = translator.translate(&Languages::Spanish, TranslationKeys::HelloWithArguments);
+  ///
+  /// assert_eq!(message.add_argument("name", "Alex").build(), "hello_with_arguments(name: Alex)");
+  /// ```
+  pub fn identity(
+    default_language: &LanguageGeneric,
+  ) -> Translator<LanguageGeneric, TranslationKeyGeneric> {
+    Self::identity_for(default_language.as_str())
+  }
 
-    let translation_info_optional = self.translations.get(language.as_str());
+  pub(crate) fn identity_for(
+    default_language: &'static str,
+  ) -> Translator<LanguageGeneric, TranslationKeyGeneric> {
+    let language_identifier = default_language
+      .parse::<LanguageIdentifier>()
+      .unwrap_or_default();
 
-    let translation_info = if let Some(translation_info) = translation_info_optional {
-      (translation_info, language.as_str())
-    } else {
-      warn!(
-        "Tried to translate to an unknown language {}, falling back to {default_language}",
-        language.as_str()
-      );
-      (
-        self.translations.get(default_language).unwrap(),
-        default_language,
-      )
-    };
+    let options = TranslatorOptions::default();
 
-    let (translations, language) = translation_info;
-    let mut message = translations.get_message(translation_key);
+    let translations = LoadedLanguages::with_default(
+      default_language.to_string(),
+      new_bundle(language_identifier, &options),
+      Vec::new(),
+    );
 
-    if message.is_none() && language != default_language {
-      message = self
-        .translations
-        .get(default_language)
-        .unwrap()
-        .get_message(translation_key);
+    Translator {
+      translations,
+      overrides: HashMap::new(),
+      directory_path: None,
+      default_language,
+      identity: true,
+      options,
+      format_cache: None,
+      phantom: PhantomData,
     }
+  }
 
-    (message, translations)
+  /// ### Description
+  /// Sets whether placeables are wrapped in Unicode isolation marks (U+2068 and U+2069) for every loaded
+  /// language, including translators which were not created through the builder, such as `from_sources`.
+  /// The setting is kept when bundles are rebuilt or reloaded.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let mut translator = Translator::<Languages, TranslationKeys>::from_sources(sources, &Languages::English)?;
+  /// translator.set_use_isolating(false);
+  ///
+  /// assert_eq!(translator.translate(&Languages::English, key).add_argument("name", "Alex").build(), "Hello Alex!");
+  /// ```
+  pub fn set_use_isolating(&mut self, use_isolating: bool) {
+    self.set_bidi_isolation(if use_isolating {
+      BidiIsolation::Always
+    } else {
+      BidiIsolation::Never
+    });
   }
 
   /// ### Description
-  /// Translate text that takes no arguments
+  /// Sets which languages wrap placeables in Unicode isolation marks for every loaded language, like
+  /// `set_use_isolating` does. The setting is kept when bundles are rebuilt or reloaded.
   /// ### Usage
+  /// ```ignore
+  /// ...
+  /// translator.set_bidi_isolation(BidiIsolation::RightToLeft);
   /// ```
+  pub fn set_bidi_isolation(&mut self, bidi_isolation: BidiIsolation) {
+    self.options.bidi_isolation = bidi_isolation;
+    self.translations.set_options(&self.options);
+
+    for bundle in self.translations.loaded_bundles_mut() {
+      let use_isolating = bundle
+        .locales
+        .first()
+        .is_some_and(|language_identifier| bidi_isolation.isolates(language_identifier));
+
+      bundle.set_use_isolating(use_isolating);
+    }
+
+    self.clear_format_cache();
+  }
+
+  /// ### Description
+  /// Sets the function every text fragment of the patterns is passed through when formatting, for every
+  /// loaded language, or removes it with `None`. The setting is kept when bundles are rebuilt or reloaded.
+  /// ### Usage
+  /// ```ignore
   /// ...
-  /// let language = Languages::Espanish;
-  /// let key = TranslationKeys::Hello;
-  ///
-  /// let message = translator.translate_without_arguments(language, key);
-  ///
-  /// println!("{message}");
+  /// translator.set_transform(Some(|text| Cow::Owned(text.to_uppercase())));
   /// ```
-  pub fn translate_without_arguments(
-    &self,
-    language: &LanguageGeneric,
-    key: TranslationKeyGeneric,
-  ) -> Cow<str> {
-    let (message, bundle) = self.get_message(language, &key);
+  pub fn set_transform(&mut self, transform: Option<TextTransform>) {
+    self.options.transform = transform;
+    self.translations.set_options(&self.options);
 
-    let Some(message) = message else {
-      warn!(
-        "Tried to translate to a non existing language key: {}",
-        key.as_str()
-      );
-      return Cow::Borrowed(TRANSLATION_FAILED);
-    };
+    for bundle in self.translations.loaded_bundles_mut() {
+      bundle.set_transform(transform);
+    }
 
-    let mut errors = Vec::new();
-    let Some(message_value) = message.value() else {
-      warn!("An error has ocurred while tring to get meesage value");
-      return Cow::Borrowed(TRANSLATION_FAILED);
-    };
+    self.clear_format_cache();
+  }
+
+  /// ### Description
+  /// Adds a custom function to every loaded language, including translators which were not created through
+  /// the builder, such as `from_sources`. The function is kept when bundles are rebuilt or reloaded.
+  /// Returns `false` when a function with that name already exists.
+  /// ### Usage
+  /// ```ignore
+  /// // price = { CURRENCY($amount) }
+  /// ...
+  /// translator.add_function("CURRENCY", |positional, _named| match positional.first() {
+  ///   Some(FluentValue::Number(amount)) => FluentValue::from(format!("${:.2}", amount.value)),
+  ///   _ => FluentValue::Error,
+  /// });
+  /// ```
+  pub fn add_function<Function>(&mut self, name: &str, function: Function) -> bool
+  where
+    Function: for<'value> Fn(&[FluentValue<'value>], &FluentArgs) -> FluentValue<'value>
+      + Send
+      + Sync
+      + 'static,
+  {
+    if self
+      .options
+      .functions
+      .0
+      .iter()
+      .any(|(function_name, _)| function_name == name)
+    {
+      warn!("Function {name} has already been added");
+      return false;
+    }
 
-    let translated = bundle.format_pattern(message_value, None, &mut errors);
+    let function: Arc<FluentFunctionFn> = Arc::new(function);
 
-    if errors.is_empty() {
-      translated
-    } else {
-      warn!(
-        "Translation failure(s) when translating {}: {:?}",
-        key.as_str(),
-        errors
-      );
-      Cow::Borrowed(TRANSLATION_FAILED)
+    for bundle in self.translations.loaded_bundles_mut() {
+      let function = Arc::clone(&function);
+
+      if let Err(error) =
+        bundle.add_function(name, move |positional, named| function(positional, named))
+      {
+        warn!("Could not add function {name}: {error}");
+      }
     }
+
+    self.options.functions.0.push((name.to_string(), function));
+    self.translations.set_options(&self.options);
+    self.clear_format_cache();
+    true
   }
 
   /// ### Description
-  /// Translates text that takes arguments
+  /// Returns the underlying Fluent bundle for a language, as an escape hatch for Fluent features this crate
+  /// does not wrap. The reference borrows the translator and reflects the translations currently loaded.
   /// ### Usage
+  /// ```ignore
+  /// ...
+  /// if let Some(bundle) = translator.bundle(&Languages::Spanish) {
+  ///   let message = bundle.get_message("hello").unwrap();
+  ///   let attributes = message.attributes().count();
+  /// }
   /// ```
+  pub fn bundle(&self, language: &LanguageGeneric) -> Option<&Bundle> {
+    self.translations.bundle(language.as_str())
+  }
+
+  /// ### Description
+  /// Returns the underlying Fluent bundle for the default language.
+  /// The reference borrows the translator and reflects the translations currently loaded.
+  pub fn default_bundle(&self) -> &Bundle {
+    self.translations.default_bundle().1
+  }
+
+  /// ### Description
+  /// Returns the underlying Fluent bundles of every loaded language with its code, in alphabetical order,
+  /// including pseudo-languages. The references borrow the translator like `bundle` does.
+  /// ### Usage
+  /// ```ignore
   /// ...
-  /// let language = Languages::Espanish;
-  /// let key = TranslationKeys::HelloWithArguments;
-  ///
-  /// let message = translator.translate(arguments, key).add_argument("name", "Alex");
-  /// let built_message = message.buld();
-  ///
-  /// println!("{bult_message}");
+  /// for (language, bundle) in translator.bundles() {
+  ///   println!("{language}: {}", bundle.has_message("hello"));
+  /// }
   /// ```
-  pub fn translate(
-    &self,
+  pub fn bundles(&self) -> impl Iterator<Item = (&str, &Bundle)> {
+    let mut bundles: Vec<(&str, &Bundle)> = self
+      .translations
+      .iter()
+      .map(|(language, loaded)| (language, &loaded.bundle))
+      .collect();
+    bundles.sort_unstable_by_key(|(language, _)| *language);
+    bundles.into_iter()
+  }
+
+  pub fn get_message<'lifetime>(
+    &'lifetime self,
     language: &LanguageGeneric,
-    key: TranslationKeyGeneric,
-  ) -> MessageTranslator<TranslationKeyGeneric> {
-    let (message, bundle) = self.get_message(language, &key);
+    key: &TranslationKeyGeneric,
+  ) -> (Option<FluentMessage<'lifetime>>, &'lifetime Bundle) {
+    let (message, bundle, _) = self.resolve_message(language.as_str(), key.as_str());
+    (message, bundle)
+  }
 
-    MessageTranslator {
-      key,
-      bundle,
-      message,
-      args: Default::default(),
+  /// ### Description
+  /// Returns the loaded language used for a requested one: the language itself when it is loaded, otherwise the
+  /// closest loaded regional variant or base language (`es-MX` falls back to `es`, then `es-ES`), and the default
+  /// language when nothing matches.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// // Loaded languages: en-US, es-ES
+  /// assert_eq!(translator.resolve_language("es-MX"), "es-ES");
+  /// assert_eq!(translator.resolve_language("ja"), "en-US");
+  /// ```
+  pub fn resolve_language(&self, language: &str) -> &str {
+    if let Some(language) = self.translations.key(language) {
+      return language;
     }
+
+    self
+      .fallback_languages(language)
+      .first()
+      .copied()
+      .unwrap_or(self.default_language)
   }
-}
 
-fn get_directory_data(directory_entry_result: Result<DirEntry, IoError>) -> Option<DirectoryData> {
-  let Ok(directory) = directory_entry_result else {
-    warn!("One directory could not be read");
-    return None;
-  };
+  /// ### Description
+  /// Picks the loaded language which best matches an ordered list of requested languages, such as the ones of
+  /// an `Accept-Language` header, using BCP-47 matching. Returns the default language when nothing matches.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// // Accept-Language: fr-CH, es-MX;q=0.8, en;q=0.5
+  /// // Loaded languages: en-US, es-ES
+  /// let language = translator.negotiate_language(&["fr-CH", "es-MX", "en"]);
+  ///
+  /// assert_eq!(language, "es-ES");
+  /// ```
+  pub fn negotiate_language(&self, requested: &[&str]) -> &str {
+    let requested: Vec<LanguageIdentifier> = requested
+      .iter()
+      .filter_map(|language| self.options.language_parser.parse(language))
+      .collect();
+
+    let mut available: Vec<(&str, &LanguageIdentifier)> = self.translations.identifiers().collect();
+    available.sort();
 
-  let directory_name = directory.file_name().to_string_lossy().to_string();
+    let identifiers: Vec<&LanguageIdentifier> = available
+      .iter()
+      .map(|(_, identifier)| *identifier)
+      .collect();
 
-  let Ok(is_dir) = is_directory(&directory) else {
-    warn!("Could not check if {directory_name} is a directory");
-    return None;
-  };
+    let negotiated =
+      negotiate_languages(&requested, &identifiers, None, NegotiationStrategy::Lookup);
 
-  if is_dir {
-    return Some((directory, directory_name));
+    negotiated
+      .first()
+      .and_then(|matched| {
+        available
+          .iter()
+          .find(|(_, identifier)| identifier == *matched)
+          .map(|(name, _)| *name)
+      })
+      .unwrap_or(self.default_language)
   }
 
-  None
-}
+  /// Looks a message up in the requested language, then in the loaded languages closest to it and finally in the
+  /// default language. Returns the bundle the message belongs to and the language it was found in, the default
+  /// bundle when the message is nowhere.
+  pub(crate) fn resolve_message<'lifetime>(
+    &'lifetime self,
+    language: &str,
+    translation_key: &str,
+  ) -> (
+    Option<FluentMessage<'lifetime>>,
+    &'lifetime Bundle,
+    &'lifetime str,
+  ) {
+    let default_language = self.default_language;
+    let requested = self
+      .translations
+      .get_key_value(language)
+      .map(|(language, loaded)| (language, &loaded.bundle));
 
-fn is_directory(directory: &DirEntry) -> Result<bool, TranslatorError> {
-  let file_type = directory
-    .file_type()
-    .map_err(|error| TranslatorError::DirEntryError {
-      detail: error.to_string(),
-    })?;
+    match requested {
+      Some((language, bundle)) => {
+        if let Some(message) = bundle.get_message(translation_key) {
+          return (Some(message), bundle, language);
+        }
 
-  Ok(file_type.is_dir())
-}
+        self.report_missing(language, translation_key);
+      }
+      None => log_at!(
+        self.options.miss_level,
+        "Tried to translate to an unknown language {language}, falling back to closer languages or {default_language}"
+      ),
+    }
 
-fn get_file_data(file_entry_result: Result<DirEntry, IoError>) -> Option<(String, String)> {
-  let Ok(file) = file_entry_result else {
-    warn!("One file could not be read");
-    return None;
-  };
+    // Unknown languages report the closest loaded language, which is the first one looked at.
+    let mut reported = requested.is_some();
 
-  let file_name = file.file_name().to_string_lossy().to_string();
+    for fallback_language in self.fallback_languages(language) {
+      let Some(bundle) = self.translations.bundle(fallback_language) else {
+        continue;
+      };
+
+      if let Some(message) = bundle.get_message(translation_key) {
+        debug!("Found {translation_key} for {language} in {fallback_language}");
+        return (Some(message), bundle, fallback_language);
+      }
+
+      if !reported {
+        self.report_missing(fallback_language, translation_key);
+        reported = true;
+      }
+    }
+
+    let (default_language, default_bundle) = self.translations.default_bundle();
+    let message = default_bundle.get_message(translation_key);
 
-  trace!("Loading file {file_name}");
+    if message.is_none() && !reported {
+      self.report_missing(default_language, translation_key);
+    }
 
-  let file_data_result = fs::read_to_string(file.path());
+    (message, default_bundle, default_language)
+  }
 
-  match file_data_result {
-    Ok(file_content) => Some((file_content, file_name)),
-    Err(error) => {
-      warn!("Could not read file {file_name} because of the following error: {error}");
-      None
+  /// Whether a language is loaded, or a loaded language shares its base language, such as `es-ES` for `es-MX`.
+  pub(crate) fn matches_loaded_language(&self, language: &str) -> bool {
+    if self.translations.contains_key(language) {
+      return true;
     }
+
+    let Some(requested) = self.options.language_parser.parse(language) else {
+      return false;
+    };
+
+    let identifiers: Vec<&LanguageIdentifier> = self
+      .translations
+      .identifiers()
+      .map(|(_, identifier)| identifier)
+      .collect();
+
+    !negotiate_languages(
+      &[requested],
+      &identifiers,
+      None,
+      NegotiationStrategy::Filtering,
+    )
+    .is_empty()
   }
+
+  /// Loaded languages, other than the requested and the default one, which match the requested language,
+  /// from closest to farthest.
+  fn fallback_languages(&self, language: &str) -> Vec<&str> {
+    let Some(requested) = self.options.language_parser.parse(language) else {
+      return Vec::new();
+    };
+
+    let mut candidates: Vec<(&str, &LanguageIdentifier)> = self
+      .translations
+      .identifiers()
+      .filter(|(name, _)| *name != language && *name != self.default_language)
+      .collect();
+    candidates.sort();
+
+    let identifiers: Vec<&LanguageIdentifier> = candidates
+      .iter()
+      .map(|(_, identifier)| *identifier)
+      .collect();
+
+    negotiate_languages(
+      &[requested],
+      &identifiers,
+      None,
+      NegotiationStrategy::Filtering,
+    )
+    .into_iter()
+    .filter_map(|matched| {
+      candidates
+        .iter()
+        .find(|(_, identifier)| identifier == matched)
+        .map(|(name, _)| *name)
+    })
+    .collect()
+  }
+
+  /// ### Description
+  /// Translate text that takes no arguments
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let language = Languages::Espanish;
+  /// let key = TranslationKeys::Hello;
+  ///
+  /// let message = translator.translate_without_arguments(language, key);
+  ///
+  /// println!("{message}");
+  /// ```
+  pub fn translate_without_arguments(
+    &self,
+    language: &LanguageGeneric,
+    key: TranslationKeyGeneric,
+  ) -> Cow<'_, str> {
+    self.translate_without_arguments_str(language.as_str(), key)
+  }
+
+  pub(crate) fn translate_without_arguments_str(
+    &self,
+    language: &'static str,
+    key: TranslationKeyGeneric,
+  ) -> Cow<'_, str> {
+    let key_name = key.as_str();
+
+    self
+      .try_translate_str(language, key)
+      .unwrap_or_else(|_| self.missing_text(language).text(key_name, None, None))
+  }
+
+  /// ### Description
+  /// Translates text that takes no arguments, returning `None` instead of the fallback text when the key
+  /// does not exist, the message has no value or formatting fails.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let language = Languages::Espanish;
+  /// let key = TranslationKeys::Hello;
+  ///
+  /// match translator.translate_opt(language, key) {
+  ///   Some(message) => println!("{message}"),
+  ///   None => render_banner_image(),
+  /// }
+  /// ```
+  pub fn translate_opt(
+    &self,
+    language: &LanguageGeneric,
+    key: TranslationKeyGeneric,
+  ) -> Option<Cow<'_, str>> {
+    self.try_translate(language, key).ok()
+  }
+
+  /// ### Description
+  /// Translates text that takes no arguments, returning why translating failed instead of the fallback text,
+  /// so applications can decide what to show.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// match translator.try_translate(&Languages::Spanish, TranslationKeys::Hello) {
+  ///   Ok(message) => println!("{message}"),
+  ///   Err(TranslationError::MessageNotFound { key }) => report_missing_key(&key),
+  ///   Err(error) => eprintln!("{error}"),
+  /// }
+  /// ```
+  pub fn try_translate(
+    &self,
+    language: &LanguageGeneric,
+    key: TranslationKeyGeneric,
+  ) -> Result<Cow<'_, str>, TranslationError> {
+    self.try_translate_str(language.as_str(), key)
+  }
+
+  pub(crate) fn try_translate_str(
+    &self,
+    language: &'static str,
+    key: TranslationKeyGeneric,
+  ) -> Result<Cow<'_, str>, TranslationError> {
+    if self.identity {
+      return Ok(Cow::Borrowed(key.as_str()));
+    }
+
+    if let Some(format_cache) = &self.format_cache {
+      if let Some(text) = format_cache.get(language, key.as_str()) {
+        return Ok(Cow::Owned(text));
+      }
+    }
+
+    let (message, bundle, effective_language) = self.resolve_message(language, key.as_str());
+    let translated = self.format_key_value(message, bundle, effective_language, &key, None)?;
+
+    if let Some(format_cache) = &self.format_cache {
+      format_cache.insert(language, key.as_str(), translated.to_string());
+    }
+
+    Ok(translated)
+  }
+
+  /// ### Description
+  /// Translates text that takes no arguments from a key only known at runtime, such as keys coming from a CMS,
+  /// with the same fallbacks as `translate_opt`. Returns `None` when the key does not exist in any fallback.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let label = translator
+  ///   .translate_dynamic(&Languages::Spanish, &menu_item.label_key)
+  ///   .unwrap_or_else(|| Cow::Owned(menu_item.label_key.clone()));
+  /// ```
+  pub fn translate_dynamic(&self, language: &LanguageGeneric, key: &str) -> Option<Cow<'_, str>> {
+    if self.identity {
+      return Some(Cow::Owned(key.to_string()));
+    }
+
+    let (message, bundle, effective_language) = self.resolve_message(language.as_str(), key);
+
+    self
+      .format_message_value(message, bundle, effective_language, key, None)
+      .ok()
+  }
+
+  /// ### Description
+  /// Translates text that takes no arguments, also reporting whether a fallback was needed and which
+  /// language produced the text. Useful to measure translation coverage of real requests.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let translation = translator.translate_detailed(&Languages::Spanish, TranslationKeys::Hello);
+  ///
+  /// if translation.used_fallback {
+  ///   metrics.record_fallback(&translation.effective_language);
+  /// }
+  ///
+  /// println!("{}", translation.text);
+  /// ```
+  pub fn translate_detailed(
+    &self,
+    language: &LanguageGeneric,
+    key: TranslationKeyGeneric,
+  ) -> Translation<'_> {
+    self.translate_detailed_str(language.as_str(), key)
+  }
+
+  pub(crate) fn translate_detailed_str(
+    &self,
+    language: &str,
+    key: TranslationKeyGeneric,
+  ) -> Translation<'_> {
+    if self.identity {
+      return Translation {
+        text: Cow::Borrowed(key.as_str()),
+        used_fallback: false,
+        effective_language: language.to_string(),
+      };
+    }
+
+    let (message, bundle, effective_language) = self.resolve_message(language, key.as_str());
+
+    let text = self
+      .format_key_value(message, bundle, effective_language, &key, None)
+      .ok();
+
+    Translation {
+      used_fallback: text.is_none() || effective_language != language,
+      text: text.unwrap_or_else(|| self.missing_text(language).text(key.as_str(), None, None)),
+      effective_language: effective_language.to_string(),
+    }
+  }
+
+  /// ### Description
+  /// Appends the translation of a text that takes no arguments to `out` instead of allocating a new string,
+  /// so the same buffer can be reused across many translations. Returns `false` and leaves `out` untouched
+  /// when the key does not exist, the message has no value or formatting fails.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let mut html = String::with_capacity(4096);
+  ///
+  /// for key in menu_keys {
+  ///   html.push_str("<li>");
+  ///   translator.translate_into(&language, key, &mut html);
+  ///   html.push_str("</li>");
+  /// }
+  /// ```
+  pub fn translate_into(
+    &self,
+    language: &LanguageGeneric,
+    key: TranslationKeyGeneric,
+    out: &mut String,
+  ) -> bool {
+    self.translate_into_str(language.as_str(), key, out)
+  }
+
+  pub(crate) fn translate_into_str(
+    &self,
+    language: &str,
+    key: TranslationKeyGeneric,
+    out: &mut String,
+  ) -> bool {
+    if self.identity {
+      out.push_str(key.as_str());
+      return true;
+    }
+
+    let (message, bundle, effective_language) = self.resolve_message(language, key.as_str());
+
+    let Some(message) = message else {
+      log_at!(
+        self.options.miss_level,
+        "Tried to translate to a non existing language key: {}",
+        key.as_str()
+      );
+      return false;
+    };
+
+    let Some(message_value) = message.value() else {
+      warn!("An error has ocurred while tring to get meesage value");
+      return false;
+    };
+
+    let default_args = merge_default_args(key.default_args(), None);
+
+    if check_unknown_arguments(
+      self.options.unknown_argument_policy,
+      bundle,
+      message_value,
+      key.as_str(),
+      default_args.as_ref(),
+    )
+    .is_err()
+    {
+      return false;
+    }
+    let global_args = self.options.global_args.merge(default_args.as_ref());
+
+    match write_pattern(
+      bundle,
+      message_value,
+      global_args.as_ref().or(default_args.as_ref()),
+      out,
+    ) {
+      Ok(()) => true,
+      Err(errors) => {
+        warn!(
+          "Translation failure(s) when translating {}: {:?}",
+          key.as_str(),
+          errors
+        );
+        self.report_format_error(effective_language, key.as_str(), &errors);
+        false
+      }
+    }
+  }
+
+  /// ### Description
+  /// Formats a message with optional arguments straight into any `fmt::Write` target, such as a `String`
+  /// or a `fmt::Formatter`, without allocating the translation. When formatting fails, the text written so
+  /// far, with the broken placeables replaced by their Fluent fallback, is kept and `FormattingErrors` is
+  /// returned.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let mut email = String::with_capacity(16 * 1024);
+  ///
+  /// translator.format_into(&mut email, &language, TranslationKeys::EmailGreeting, Some(&arguments))?;
+  /// email.push_str("\n\n");
+  /// translator.format_into(&mut email, &language, TranslationKeys::EmailBody, None)?;
+  /// ```
+  pub fn format_into<Writer>(
+    &self,
+    out: &mut Writer,
+    language: &LanguageGeneric,
+    key: TranslationKeyGeneric,
+    args: Option<&FluentArgs>,
+  ) -> Result<(), TranslationError>
+  where
+    Writer: fmt::Write,
+  {
+    let merged_args = merge_default_args(key.default_args(), args);
+
+    self.format_into_str(
+      out,
+      language.as_str(),
+      key.as_str(),
+      merged_args.as_ref().or(args),
+    )
+  }
+
+  pub(crate) fn format_into_str<Writer>(
+    &self,
+    out: &mut Writer,
+    language: &str,
+    key: &str,
+    args: Option<&FluentArgs>,
+  ) -> Result<(), TranslationError>
+  where
+    Writer: fmt::Write,
+  {
+    let write_error = || TranslationError::WriteError {
+      key: key.to_string(),
+      source: None,
+    };
+
+    if self.identity {
+      return out.write_str(key).map_err(|_| write_error());
+    }
+
+    let (message, bundle, effective_language) = self.resolve_message(language, key);
+
+    let Some(message) = message else {
+      log_at!(
+        self.options.miss_level,
+        "Tried to translate to a non existing language key: {}",
+        key
+      );
+      return Err(TranslationError::MessageNotFound {
+        key: key.to_string(),
+      });
+    };
+
+    let Some(message_value) = message.value() else {
+      warn!("An error has ocurred while tring to get meesage value");
+      return Err(TranslationError::NoValue {
+        key: key.to_string(),
+      });
+    };
+
+    check_unknown_arguments(
+      self.options.unknown_argument_policy,
+      bundle,
+      message_value,
+      key,
+      args,
+    )?;
+
+    let merged_args = self.options.global_args.merge(args);
+    let mut errors = Vec::new();
+
+    bundle
+      .write_pattern(
+        out,
+        message_value,
+        merged_args.as_ref().or(args),
+        &mut errors,
+      )
+      .map_err(|_| write_error())?;
+
+    if errors.is_empty() {
+      return Ok(());
+    }
+
+    warn!("Translation failure(s) when translating {key}: {errors:?}");
+    self.report_format_error(effective_language, key, &errors);
+
+    Err(TranslationError::FormattingErrors {
+      key: key.to_string(),
+      errors,
+    })
+  }
+
+  /// ### Description
+  /// Formats a message with optional arguments straight into any `io::Write` target, such as a file or a
+  /// socket, the same way `format_into` does. Errors of the target are returned as the source of
+  /// `WriteError`. Wrap unbuffered targets in a `BufWriter`, as the message is written in fragments.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let mut report = BufWriter::new(File::create("report.txt")?);
+  ///
+  /// for key in report_keys {
+  ///   translator.format_into_io(&mut report, &language, key, None)?;
+  ///   report.write_all(b"\n")?;
+  /// }
+  /// ```
+  pub fn format_into_io<Writer>(
+    &self,
+    out: &mut Writer,
+    language: &LanguageGeneric,
+    key: TranslationKeyGeneric,
+    args: Option<&FluentArgs>,
+  ) -> Result<(), TranslationError>
+  where
+    Writer: io::Write,
+  {
+    let mut adapter = IoWriteAdapter { out, error: None };
+
+    match self.format_into(&mut adapter, language, key, args) {
+      Err(TranslationError::WriteError { key, .. }) => Err(TranslationError::WriteError {
+        key,
+        source: adapter.error,
+      }),
+      result => result,
+    }
+  }
+
+  /// What to return for messages requested in `language` which can not be translated.
+  pub(crate) fn missing_text(&self, language: &str) -> MissingText<'_> {
+    MissingText {
+      policy: self.options.missing_message_policy,
+      handler: self.options.missing_message_handler.as_ref(),
+      default_bundle: self.translations.default_bundle().1,
+      language: self.resolve_language(language),
+      global_args: &self.options.global_args,
+    }
+  }
+
+  /// Formats the value of the message of a key like `format_message_value`, with the default arguments of the
+  /// key merged under the arguments of the call.
+  pub(crate) fn format_key_value<'lifetime>(
+    &self,
+    message: Option<FluentMessage<'lifetime>>,
+    bundle: &'lifetime Bundle,
+    language: &str,
+    key: &TranslationKeyGeneric,
+    args: Option<&'lifetime FluentArgs>,
+  ) -> Result<Cow<'lifetime, str>, TranslationError> {
+    match merge_default_args(key.default_args(), args) {
+      Some(merged_args) => self
+        .format_message_value(message, bundle, language, key.as_str(), Some(&merged_args))
+        .map(|translated| Cow::Owned(translated.into_owned())),
+      None => self.format_message_value(message, bundle, language, key.as_str(), args),
+    }
+  }
+
+  /// Formats the value of a message found in `language`, reporting formatting errors to the observer.
+  pub(crate) fn format_message_value<'lifetime>(
+    &self,
+    message: Option<FluentMessage<'lifetime>>,
+    bundle: &'lifetime Bundle,
+    language: &str,
+    key: &str,
+    args: Option<&'lifetime FluentArgs>,
+  ) -> Result<Cow<'lifetime, str>, TranslationError> {
+    let Some(message) = message else {
+      log_at!(
+        self.options.miss_level,
+        "Tried to translate to a non existing language key: {}",
+        key
+      );
+      return Err(TranslationError::MessageNotFound {
+        key: key.to_string(),
+      });
+    };
+
+    let mut errors = Vec::new();
+    let Some(message_value) = message.value() else {
+      warn!("An error has ocurred while tring to get meesage value");
+      return Err(TranslationError::NoValue {
+        key: key.to_string(),
+      });
+    };
+
+    check_unknown_arguments(
+      self.options.unknown_argument_policy,
+      bundle,
+      message_value,
+      key,
+      args,
+    )?;
+
+    let translated =
+      self
+        .options
+        .global_args
+        .format_pattern(bundle, message_value, args, &mut errors);
+
+    if errors.is_empty() {
+      Ok(translated)
+    } else {
+      warn!(
+        "Translation failure(s) when translating {}: {:?}",
+        key, errors
+      );
+      self.report_format_error(language, key, &errors);
+      Err(TranslationError::FormattingErrors {
+        key: key.to_string(),
+        errors,
+      })
+    }
+  }
+
+  /// ### Description
+  /// Translates an attribute of a message that takes no arguments, such as a tooltip or an aria-label defined
+  /// next to the message value. Returns the fallback text when the message or the attribute does not exist.
+  /// ### Usage
+  /// ```ignore
+  /// // login-input = Email
+  /// //     .placeholder = email@example.com
+  /// ...
+  /// let placeholder = translator.translate_attribute(&Languages::English, TranslationKeys::LoginInput, "placeholder");
+  ///
+  /// assert_eq!(placeholder, "email@example.com");
+  /// ```
+  pub fn translate_attribute(
+    &self,
+    language: &LanguageGeneric,
+    key: TranslationKeyGeneric,
+    attribute: &str,
+  ) -> Cow<'_, str> {
+    self.translate_attribute_str(language.as_str(), key, attribute)
+  }
+
+  pub(crate) fn translate_attribute_str(
+    &self,
+    language: &str,
+    key: TranslationKeyGeneric,
+    attribute: &str,
+  ) -> Cow<'_, str> {
+    if self.identity {
+      return Cow::Owned(format!("{}.{attribute}", key.as_str()));
+    }
+
+    let (message, bundle, effective_language) = self.resolve_message(language, key.as_str());
+
+    let Some(message) = message else {
+      log_at!(
+        self.options.miss_level,
+        "Tried to translate to a non existing language key: {}",
+        key.as_str()
+      );
+      return self
+        .missing_text(language)
+        .text(key.as_str(), Some(attribute), None);
+    };
+
+    let Some(attribute_value) = message.get_attribute(attribute) else {
+      log_at!(
+        self.options.miss_level,
+        "Tried to translate a non existing attribute {} of {}",
+        attribute,
+        key.as_str()
+      );
+      return self
+        .missing_text(language)
+        .text(key.as_str(), Some(attribute), None);
+    };
+
+    let mut errors = Vec::new();
+    let translated =
+      self
+        .options
+        .global_args
+        .format_pattern(bundle, attribute_value.value(), None, &mut errors);
+
+    if errors.is_empty() {
+      translated
+    } else {
+      warn!(
+        "Translation failure(s) when translating {}.{}: {:?}",
+        key.as_str(),
+        attribute,
+        errors
+      );
+      self.report_format_error(effective_language, key.as_str(), &errors);
+      self
+        .missing_text(language)
+        .text(key.as_str(), Some(attribute), None)
+    }
+  }
+
+  /// ### Description
+  /// Translates text that takes arguments
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let language = Languages::Espanish;
+  /// let key = TranslationKeys::HelloWithArguments;
+  ///
+  /// let message = translator.translate(arguments, key).add_argument("name", "Alex");
+  /// let built_message = message.buld();
+  ///
+  /// println!("{bult_message}");
+  /// ```
+  pub fn translate<'args>(
+    &self,
+    language: &LanguageGeneric,
+    key: TranslationKeyGeneric,
+  ) -> MessageTranslator<'_, 'args, TranslationKeyGeneric> {
+    self.translate_str(language.as_str(), key)
+  }
+
+  /// ### Description
+  /// Translates text with arguments built ahead of time, the same as `translate` followed by `with_args`.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let mut arguments = FluentArgs::new();
+  /// arguments.set("name", "Alex");
+  ///
+  /// let message = translator.translate_with_args(language, TranslationKeys::HelloWithArguments, arguments);
+  ///
+  /// println!("{}", message.build());
+  /// ```
+  pub fn translate_with_args<'args, Args, Name, Value>(
+    &self,
+    language: &LanguageGeneric,
+    key: TranslationKeyGeneric,
+    arguments: Args,
+  ) -> MessageTranslator<'_, 'args, TranslationKeyGeneric>
+  where
+    Args: IntoIterator<Item = (Name, Value)>,
+    Name: Into<Cow<'args, str>>,
+    Value: Into<FluentValue<'args>>,
+  {
+    self.translate(language, key).with_args(arguments)
+  }
+
+  /// ### Description
+  /// Translates text that takes no arguments into an owned `String`, which does not borrow the translator,
+  /// so it can be returned from functions holding a short-lived translator reference or sent to other threads.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// fn title(translator: &Translator<Languages, TranslationKeys>) -> String {
+  ///   translator.translate_owned(&Languages::English, TranslationKeys::Title)
+  /// }
+  /// ```
+  pub fn translate_owned(&self, language: &LanguageGeneric, key: TranslationKeyGeneric) -> String {
+    self.translate_without_arguments(language, key).into_owned()
+  }
+
+  /// ### Description
+  /// Translates text with arguments into an owned `String`, the same as `translate_with_args` followed by
+  /// `into_string`. Neither the text nor the arguments are borrowed once it returns.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let name = user.name().to_string();
+  ///
+  /// let message: String = translator.translate_with_args_owned(language, key, [("name", name)]);
+  /// ```
+  pub fn translate_with_args_owned<'args, Args, Name, Value>(
+    &self,
+    language: &LanguageGeneric,
+    key: TranslationKeyGeneric,
+    arguments: Args,
+  ) -> String
+  where
+    Args: IntoIterator<Item = (Name, Value)>,
+    Name: Into<Cow<'args, str>>,
+    Value: Into<FluentValue<'args>>,
+  {
+    self
+      .translate_with_args(language, key, arguments)
+      .into_string()
+  }
+
+  /// ### Description
+  /// Translates a key into every loaded language, such as to register localized command names or to send
+  /// an email in several languages. Languages without the message get the text they would fall back to,
+  /// and languages where formatting fails are left out.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let names = translator.translate_all(TranslationKeys::CommandName, None);
+  ///
+  /// for (language, name) in &names {
+  ///   println!("{language}: {name}");
+  /// }
+  /// ```
+  pub fn translate_all<'lifetime>(
+    &'lifetime self,
+    key: TranslationKeyGeneric,
+    args: Option<&'lifetime FluentArgs>,
+  ) -> HashMap<&'lifetime str, Cow<'lifetime, str>> {
+    self.translate_all_iter(key, args).collect()
+  }
+
+  /// ### Description
+  /// Iterator variant of `translate_all`, yielding every language and its text in alphabetical order
+  /// without collecting them.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// for (language, text) in translator.translate_all_iter(TranslationKeys::Hello, None) {
+  ///   println!("{language}: {text}");
+  /// }
+  /// ```
+  pub fn translate_all_iter<'lifetime>(
+    &'lifetime self,
+    key: TranslationKeyGeneric,
+    args: Option<&'lifetime FluentArgs>,
+  ) -> impl Iterator<Item = (&'lifetime str, Cow<'lifetime, str>)> {
+    self.languages().filter_map(move |language| {
+      if self.identity {
+        return Some((language, Cow::Borrowed(key.as_str())));
+      }
+
+      let (message, bundle, effective_language) = self.resolve_message(language, key.as_str());
+
+      self
+        .format_key_value(message, bundle, effective_language, &key, args)
+        .ok()
+        .map(|text| (language, text))
+    })
+  }
+
+  /// ### Description
+  /// Translates several keys to a language at once, sharing the arguments, such as the labels of a
+  /// settings screen. The language and its fallbacks are looked up once for the whole batch, and every key
+  /// falls back the same way `translate_without_arguments` does. Texts are returned in the order of the
+  /// keys.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let labels = translator.translate_many(
+  ///   &language,
+  ///   [TranslationKeys::Profile, TranslationKeys::Privacy, TranslationKeys::Notifications],
+  ///   None,
+  /// );
+  /// ```
+  pub fn translate_many<'lifetime, Keys>(
+    &'lifetime self,
+    language: &LanguageGeneric,
+    keys: Keys,
+    args: Option<&'lifetime FluentArgs>,
+  ) -> Vec<Cow<'lifetime, str>>
+  where
+    Keys: IntoIterator<Item = TranslationKeyGeneric>,
+  {
+    let language = language.as_str();
+
+    if self.identity {
+      return keys
+        .into_iter()
+        .map(|key| Cow::Borrowed(key.as_str()))
+        .collect();
+    }
+
+    let candidates = self.candidate_bundles(language);
+
+    keys
+      .into_iter()
+      .map(|typed_key| {
+        let key = typed_key.as_str();
+        let found = candidates.iter().find_map(|(candidate, bundle)| {
+          bundle
+            .get_message(key)
+            .map(|message| (message, *bundle, *candidate))
+        });
+
+        let (message, bundle, effective_language) = match found {
+          Some((message, bundle, candidate)) => (Some(message), bundle, candidate),
+          None => {
+            let (closest, bundle) = candidates
+              .first()
+              .copied()
+              .unwrap_or_else(|| self.translations.default_bundle());
+            self.report_missing(closest, key);
+            (None, bundle, closest)
+          }
+        };
+
+        self
+          .format_key_value(message, bundle, effective_language, &typed_key, args)
+          .unwrap_or_else(|_| self.missing_text(language).text(key, None, args))
+      })
+      .collect()
+  }
+
+  /// Loaded bundles a message requested in `language` is looked up in, in the order `resolve_message`
+  /// tries them: the language itself, its fallbacks and the default language.
+  pub(crate) fn candidate_bundles(&self, language: &str) -> Vec<(&str, &Bundle)> {
+    let default_language = self.default_language;
+    let mut candidates: Vec<(&str, &Bundle)> = Vec::new();
+
+    match self.translations.get_key_value(language) {
+      Some((language, loaded)) => candidates.push((language, &loaded.bundle)),
+      None => log_at!(
+        self.options.miss_level,
+        "Tried to translate to an unknown language {language}, falling back to closer languages or {default_language}"
+      ),
+    }
+
+    let fallbacks = self
+      .fallback_languages(language)
+      .into_iter()
+      .chain([default_language]);
+
+    for fallback_language in fallbacks {
+      if candidates
+        .iter()
+        .any(|(candidate, _)| *candidate == fallback_language)
+      {
+        continue;
+      }
+
+      if let Some((fallback_language, loaded)) = self.translations.get_key_value(fallback_language)
+      {
+        candidates.push((fallback_language, &loaded.bundle));
+      }
+    }
+
+    candidates
+  }
+
+  /// ### Description
+  /// Translates text to a language given as a string, such as a code stored in a database row, without
+  /// needing a `Language` value for it. Unknown languages fall back the same way `translate` does.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let language_code = user.language_code();
+  ///
+  /// let message = translator.translate_str(&language_code, TranslationKeys::HelloWithArguments);
+  /// let built_message = message.add_argument("name", "Alex").build();
+  ///
+  /// println!("{built_message}");
+  /// ```
+  pub fn translate_str<'args>(
+    &self,
+    language: &str,
+    key: TranslationKeyGeneric,
+  ) -> MessageTranslator<'_, 'args, TranslationKeyGeneric> {
+    let (message, bundle, effective_language) = self.resolve_message(language, key.as_str());
+    let default_args = merge_default_args(key.default_args(), None);
+
+    MessageTranslator {
+      key,
+      bundle,
+      message,
+      args: default_args,
+      identity: self.identity,
+      miss_level: self.options.miss_level,
+      variant: None,
+      attribute: None,
+      fallback_attribute: None,
+      missing_text: self.missing_text(language),
+      language: effective_language,
+      observer: self.options.observer.as_ref(),
+      global_args: &self.options.global_args,
+      unknown_argument_policy: self.options.unknown_argument_policy,
+    }
+  }
+
+  /// ### Description
+  /// Translates text from a language and a key given as strings, such as query parameters or configuration,
+  /// resolving them through `Language::from_str` and `TranslationKey::from_str`.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let message = translator.translate_by_str("es-ES", "hello_with_arguments")?;
+  /// let built_message = message.add_argument("name", "Alex").build();
+  ///
+  /// println!("{built_message}");
+  /// ```
+  pub fn translate_by_str<'args>(
+    &self,
+    language: &str,
+    key: &str,
+  ) -> Result<MessageTranslator<'_, 'args, TranslationKeyGeneric>, TranslatorError> {
+    let Some(typed_language) = LanguageGeneric::from_str(language) else {
+      return Err(TranslatorError::UnknownLanguage {
+        language: language.to_string(),
+      });
+    };
+
+    let Some(typed_key) = TranslationKeyGeneric::from_str(key) else {
+      return Err(TranslatorError::UnknownKey {
+        key: key.to_string(),
+      });
+    };
+
+    Ok(self.translate(&typed_language, typed_key))
+  }
+}
+
+/// Writes formatted text into an `io::Write` target, keeping the error `fmt::Write` can not carry.
+struct IoWriteAdapter<'out, Writer> {
+  out: &'out mut Writer,
+  error: Option<io::Error>,
+}
+
+impl<Writer: io::Write> fmt::Write for IoWriteAdapter<'_, Writer> {
+  fn write_str(&mut self, text: &str) -> fmt::Result {
+    self.out.write_all(text.as_bytes()).map_err(|error| {
+      self.error = Some(error);
+      fmt::Error
+    })
+  }
+}
+
+/// Arguments of a call merged over the default arguments of a key, or `None` when the key has no default
+/// arguments so the call arguments can be used as they are.
+pub(crate) fn merge_default_args<'args>(
+  default_args: FluentArgs<'static>,
+  args: Option<&'args FluentArgs>,
+) -> Option<FluentArgs<'args>> {
+  default_args.iter().next()?;
+
+  let mut merged: FluentArgs = args
+    .into_iter()
+    .flat_map(FluentArgs::iter)
+    .map(|(name, value)| (name, value.clone()))
+    .collect();
+
+  // `FluentArgs::set` keeps duplicated names, so only the default arguments the call does not pass are added.
+  for (name, value) in default_args {
+    if merged.get(name.as_ref()).is_none() {
+      merged.set(name, value);
+    }
+  }
+
+  Some(merged)
+}
+
+/// Appends a formatted pattern to `out`, restoring the buffer to its previous length when formatting fails.
+pub(crate) fn write_pattern(
+  bundle: &Bundle,
+  pattern: &Pattern<&str>,
+  args: Option<&FluentArgs>,
+  out: &mut String,
+) -> Result<(), Vec<FluentError>> {
+  let previous_length = out.len();
+  let mut errors = Vec::new();
+
+  let write_result = bundle.write_pattern(out, pattern, args, &mut errors);
+
+  if write_result.is_ok() && errors.is_empty() {
+    return Ok(());
+  }
+
+  out.truncate(previous_length);
+  Err(errors)
 }
@@ -0,0 +1,86 @@
+use crate::{
+  ast::{walk_pattern, Node},
+  Bundle, TranslationError, UnknownArgumentPolicy,
+};
+
+use std::collections::BTreeSet;
+
+use fluent_bundle::FluentArgs;
+use fluent_syntax::ast::{InlineExpression, Pattern};
+
+/// How deep message references are followed, so reference cycles can not loop forever.
+const MAX_REFERENCE_DEPTH: usize = 16;
+
+/// Checks the arguments passed to a pattern against the variables it uses, including the ones of the
+/// messages it references, which are formatted with the same arguments. Terms only get the arguments they
+/// are explicitly given, so their variables are not counted.
+pub(crate) fn check_unknown_arguments(
+  policy: UnknownArgumentPolicy,
+  bundle: &Bundle,
+  pattern: &Pattern<&str>,
+  key: &str,
+  args: Option<&FluentArgs>,
+) -> Result<(), TranslationError> {
+  if policy == UnknownArgumentPolicy::Ignore {
+    return Ok(());
+  }
+
+  let Some(args) = args else {
+    return Ok(());
+  };
+
+  let mut variables = BTreeSet::new();
+  pattern_variables(bundle, pattern, &mut variables, 0);
+
+  let unknown: Vec<String> = args
+    .iter()
+    .map(|(name, _)| name)
+    .filter(|name| !variables.contains(name))
+    .map(ToString::to_string)
+    .collect();
+
+  if unknown.is_empty() {
+    return Ok(());
+  }
+
+  warn!("{key} does not use the arguments {unknown:?} it was given");
+
+  match policy {
+    UnknownArgumentPolicy::Error => Err(TranslationError::UnknownArguments {
+      key: key.to_string(),
+      arguments: unknown,
+    }),
+    _ => Ok(()),
+  }
+}
+fn pattern_variables<'bundle>(
+  bundle: &'bundle Bundle,
+  pattern: &Pattern<&'bundle str>,
+  variables: &mut BTreeSet<&'bundle str>,
+  depth: usize,
+) {
+  walk_pattern(pattern, &mut |node| match node {
+    Node::Inline(InlineExpression::VariableReference { id }) => {
+      variables.insert(id.name);
+    }
+    Node::Inline(InlineExpression::MessageReference { id, attribute })
+      if depth < MAX_REFERENCE_DEPTH =>
+    {
+      let Some(message) = bundle.get_message(id.name) else {
+        return;
+      };
+
+      let pattern = match attribute {
+        Some(attribute) => message
+          .get_attribute(attribute.name)
+          .map(|attribute| attribute.value()),
+        None => message.value(),
+      };
+
+      if let Some(pattern) = pattern {
+        pattern_variables(bundle, pattern, variables, depth + 1);
+      }
+    }
+    _ => {}
+  });
+}
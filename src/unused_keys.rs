@@ -0,0 +1,83 @@
+use crate::{
+  ast::{message_patterns, walk_pattern, Node},
+  Language, TranslationKey, Translator,
+};
+
+use std::collections::{BTreeSet, HashMap};
+
+use fluent_syntax::ast::{Entry, InlineExpression};
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Returns the messages of the default language, in alphabetical order, which the application does not
+  /// use: neither listed in `used_keys` nor referenced by a used message, directly or through other messages.
+  /// Meant to prune dead entries from translation files.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let used_keys = TranslationKeys::iter().map(|key| key.as_str());
+  ///
+  /// for key in translator.unused_keys(used_keys) {
+  ///   println!("{key} is not used anymore");
+  /// }
+  /// ```
+  pub fn unused_keys<'key>(&self, used_keys: impl IntoIterator<Item = &'key str>) -> Vec<&str> {
+    let references = self.message_references(self.default_language);
+
+    let mut used: BTreeSet<&str> = BTreeSet::new();
+    let mut pending: Vec<&str> = used_keys
+      .into_iter()
+      .filter_map(|key| references.get_key_value(key).map(|(key, _)| *key))
+      .collect();
+
+    while let Some(key) = pending.pop() {
+      if !used.insert(key) {
+        continue;
+      }
+
+      pending.extend(references.get(key).into_iter().flatten().copied());
+    }
+
+    let mut unused: Vec<&str> = references
+      .keys()
+      .copied()
+      .filter(|key| !used.contains(key))
+      .collect();
+    unused.sort_unstable();
+    unused
+  }
+
+  /// Messages a language defines along with the messages each of them references.
+  fn message_references(&self, language: &str) -> HashMap<&str, Vec<&str>> {
+    let mut references = HashMap::new();
+
+    let messages = self
+      .translations
+      .resources(language)
+      .into_iter()
+      .flatten()
+      .flat_map(|loaded_resource| loaded_resource.resource.entries())
+      .filter_map(|entry| match entry {
+        Entry::Message(message) => Some(message),
+        _ => None,
+      });
+
+    for message in messages {
+      let message_references = references.entry(message.id.name).or_insert_with(Vec::new);
+
+      for pattern in message_patterns(message) {
+        walk_pattern(pattern, &mut |node| {
+          if let Node::Inline(InlineExpression::MessageReference { id, .. }) = node {
+            message_references.push(id.name);
+          }
+        });
+      }
+    }
+
+    references
+  }
+}
@@ -0,0 +1,144 @@
+use crate::{loader::top_level_language_name, Language, TranslationKey, Translator};
+
+use std::collections::BTreeSet;
+
+/// Result of [`Translator::validate`], listing what each language lacks compared to the expected keys
+/// and to the default language.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+  pub missing_messages: Vec<MissingMessage>,
+  pub missing_attributes: Vec<MissingAttribute>,
+  pub missing_files: Vec<MissingFile>,
+}
+
+impl ValidationReport {
+  /// Whether every language defines every key, attribute and file.
+  pub fn is_valid(&self) -> bool {
+    self.missing_messages.is_empty()
+      && self.missing_attributes.is_empty()
+      && self.missing_files.is_empty()
+  }
+}
+
+/// A key which a language does not define, so it falls back to another language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingMessage {
+  pub language: String,
+  pub key: String,
+}
+
+/// An attribute the default language defines for a message which a language does not.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingAttribute {
+  pub language: String,
+  pub key: String,
+  pub attribute: String,
+}
+
+/// A file of the default language directory which a language directory does not have. Languages only
+/// translated in a top level `<language>.ftl` file are not checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingFile {
+  pub language: String,
+  pub file_name: String,
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Checks every key against every loaded language, without falling back, and reports missing messages,
+  /// attributes the default language defines but a language does not, and files of the default language
+  /// directory a language lacks. Meant to fail CI when a key is not translated everywhere.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let report = translator.validate(&[TranslationKeys::Hello, TranslationKeys::HelloWithArguments]);
+  ///
+  /// assert!(report.is_valid(), "{report:#?}");
+  /// ```
+  pub fn validate(&self, keys: &[TranslationKeyGeneric]) -> ValidationReport {
+    let mut report = ValidationReport::default();
+
+    if self.identity {
+      return report;
+    }
+
+    let mut languages: Vec<&str> = self.translations.keys().collect();
+    languages.sort_unstable();
+
+    let default_bundle = self.default_bundle();
+    let default_files = self.file_names(self.default_language);
+
+    for language in languages {
+      let Some(bundle) = self.translations.bundle(language) else {
+        continue;
+      };
+
+      for key in keys {
+        let Some(message) = bundle.get_message(key.as_str()) else {
+          report.missing_messages.push(MissingMessage {
+            language: language.to_string(),
+            key: key.as_str().to_string(),
+          });
+          continue;
+        };
+
+        let Some(default_message) = default_bundle.get_message(key.as_str()) else {
+          continue;
+        };
+
+        for default_attribute in default_message.attributes() {
+          if message.get_attribute(default_attribute.id()).is_none() {
+            report.missing_attributes.push(MissingAttribute {
+              language: language.to_string(),
+              key: key.as_str().to_string(),
+              attribute: default_attribute.id().to_string(),
+            });
+          }
+        }
+      }
+
+      if self.directory_path.is_none() || language == self.default_language {
+        continue;
+      }
+
+      let files = self.file_names(language);
+
+      // Languages translated in a single top level file are not split the same way.
+      if files.is_empty() {
+        continue;
+      }
+
+      for file_name in default_files.difference(&files) {
+        report.missing_files.push(MissingFile {
+          language: language.to_string(),
+          file_name: file_name.to_string(),
+        });
+      }
+    }
+
+    report
+  }
+
+  /// Names of the files loaded from a language directory, leaving out the top level `<language>.ftl` file,
+  /// common files and development overlays.
+  fn file_names(&self, language: &str) -> BTreeSet<&str> {
+    let is_top_level_file = |file_name: &str| top_level_language_name(file_name) == Some(language);
+
+    self
+      .translations
+      .resources(language)
+      .into_iter()
+      .flatten()
+      .filter(|loaded_resource| {
+        !loaded_resource.overriding
+          && !loaded_resource.common
+          && !is_top_level_file(&loaded_resource.file_name)
+      })
+      .map(|loaded_resource| loaded_resource.file_name.as_str())
+      .collect()
+  }
+}
@@ -0,0 +1,170 @@
+use crate::{
+  loader::overlay_directories, FileFilter, Language, SharedTranslator, TranslationKey, Translator,
+  TranslatorError,
+};
+
+use std::{
+  path::{Path, PathBuf},
+  sync::mpsc::{self, Receiver, RecvTimeoutError},
+  thread,
+  time::Duration,
+};
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+/// Time without further changes to wait for before reloading, so a single save triggers a single reload.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Keeps the translations directory watched. Dropping it stops watching.
+pub struct WatchGuard {
+  _watcher: RecommendedWatcher,
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language + Send + Sync + 'static,
+  TranslationKeyGeneric: TranslationKey + Send + Sync + 'static,
+{
+  /// ### Description
+  /// Shares the translator and reloads it whenever a translation file of its translations directory changes,
+  /// so edited translations show up on the next request without restarting. Translation files are the ones
+  /// the file filter of the translator accepts, `.ftl` files by default.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let (translator, _watch_guard) = translator.watch()?;
+  ///
+  /// let message = translator.read().translate_without_arguments(&language, key).into_owned();
+  /// ```
+  pub fn watch(
+    self,
+  ) -> Result<
+    (
+      SharedTranslator<LanguageGeneric, TranslationKeyGeneric>,
+      WatchGuard,
+    ),
+    TranslatorError,
+  > {
+    let mut watched_paths: Vec<PathBuf> = self.directory_path.iter().map(PathBuf::from).collect();
+    watched_paths.extend(overlay_directories(&self.options));
+
+    let (sender, receiver) = mpsc::channel();
+
+    let mut watcher =
+      notify::recommended_watcher(sender).map_err(|error| watch_error(Path::new(""), error))?;
+
+    for path in &watched_paths {
+      watcher
+        .watch(path, RecursiveMode::Recursive)
+        .map_err(|error| watch_error(path, error))?;
+
+      info!("Watching {} for translation changes", path.display());
+    }
+
+    let changes = TranslationChanges {
+      watched_paths,
+      file_filter: self.options.file_filter.clone(),
+    };
+    let translator = SharedTranslator::new(self);
+    let reloaded_translator = translator.clone();
+
+    thread::spawn(move || reload_on_changes(&reloaded_translator, &receiver, &changes));
+
+    Ok((translator, WatchGuard { _watcher: watcher }))
+  }
+}
+
+/// Tells the events which change translations apart from the ones about other files of the watched
+/// directories.
+struct TranslationChanges {
+  watched_paths: Vec<PathBuf>,
+  file_filter: FileFilter,
+}
+
+fn reload_on_changes<LanguageGeneric, TranslationKeyGeneric>(
+  translator: &SharedTranslator<LanguageGeneric, TranslationKeyGeneric>,
+  receiver: &Receiver<notify::Result<Event>>,
+  changes: &TranslationChanges,
+) where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  while let Ok(event) = receiver.recv() {
+    if !changes.is_translation_change(&event) {
+      continue;
+    }
+
+    loop {
+      match receiver.recv_timeout(DEBOUNCE) {
+        Ok(_) => continue,
+        Err(RecvTimeoutError::Timeout) => break,
+        Err(RecvTimeoutError::Disconnected) => return,
+      }
+    }
+
+    match translator.reload() {
+      Ok(()) => info!("Reloaded translations after a change"),
+      Err(error) => error!("Could not reload translations, keeping the previous ones: {error}"),
+    }
+  }
+
+  debug!("Stopped watching translations");
+}
+
+impl TranslationChanges {
+  fn is_translation_change(&self, event: &notify::Result<Event>) -> bool {
+    let Ok(event) = event else {
+      return false;
+    };
+
+    let is_change = matches!(
+      event.kind,
+      EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    );
+
+    // Files written right after creating a language directory may be missed while the directory starts
+    // being watched, so creating a directory reloads as well.
+    let is_created_directory =
+      |path: &PathBuf| matches!(event.kind, EventKind::Create(_)) && path.is_dir();
+
+    is_change
+      && event
+        .paths
+        .iter()
+        .any(|path| is_created_directory(path) || self.file_filter.accepts(&self.file_name(path)))
+  }
+
+  /// Name of a changed file the way the file filter gets it when loading: relative to its language or
+  /// common directory, or the bare name of a top level file.
+  fn file_name(&self, path: &Path) -> String {
+    let relative_path = self
+      .watched_paths
+      .iter()
+      .find_map(|watched_path| path.strip_prefix(watched_path).ok());
+
+    let Some(relative_path) = relative_path else {
+      return path
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    };
+
+    let components: Vec<_> = relative_path
+      .components()
+      .map(|component| component.as_os_str().to_string_lossy())
+      .collect();
+
+    match components.as_slice() {
+      [file_name] => file_name.to_string(),
+      [_, nested @ ..] => nested.join("/"),
+      [] => String::new(),
+    }
+  }
+}
+
+fn watch_error(path: &Path, error: notify::Error) -> TranslatorError {
+  TranslatorError::WatchError {
+    path: path.to_string_lossy().to_string(),
+    source: error,
+  }
+}
@@ -0,0 +1,533 @@
+use crate::{Language, TranslationKey, Translator, TranslatorError};
+
+use fluent_syntax::{
+  ast::{Entry, Identifier, Message, Pattern, Resource},
+  parser, serializer,
+};
+
+/// Versions of the XLIFF format exchanged with translation vendors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XliffVersion {
+  V1_2,
+  V2_0,
+}
+
+/// Translations read from an XLIFF file, converted into Fluent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XliffImport {
+  pub source_language: Option<String>,
+  pub target_language: String,
+  pub ftl: String,
+}
+
+/// A translation unit: a message value, or one of its attributes when the id is `message.attribute`.
+struct XliffUnit {
+  id: String,
+  source: String,
+  target: Option<String>,
+  note: Option<String>,
+}
+
+/// Translated units of the same message, grouped to be written back as a single Fluent message.
+struct TranslatedMessage<'unit> {
+  id: &'unit str,
+  value: Option<&'unit str>,
+  attributes: Vec<(&'unit str, &'unit str)>,
+}
+
+impl<LanguageGeneric, TranslationKeyGeneric> Translator<LanguageGeneric, TranslationKeyGeneric>
+where
+  LanguageGeneric: Language,
+  TranslationKeyGeneric: TranslationKey,
+{
+  /// ### Description
+  /// Exports the messages of the default language as an XLIFF document to send to translation vendors, one
+  /// unit per message value and per attribute, named `message.attribute`. Texts are kept as Fluent source,
+  /// so placeables such as `{ $name }` reach translators untouched. Messages the target language already
+  /// translates are exported with their translation, and comments written above messages become notes.
+  /// ### Usage
+  /// ```ignore
+  /// ...
+  /// let xliff = translator.export_xliff("es-ES", XliffVersion::V2_0);
+  ///
+  /// fs::write("es-ES.xlf", xliff)?;
+  /// ```
+  pub fn export_xliff(&self, target_language: &str, version: XliffVersion) -> String {
+    let source_language = self.default_language;
+    let target_bundle = self.translations.bundle(target_language);
+    let mut units = Vec::new();
+
+    let Some(source_bundle) = self.translations.bundle(source_language) else {
+      return write_document(version, source_language, target_language, &units);
+    };
+
+    for key in self.message_keys(source_language) {
+      let Some(message) = source_bundle.get_message(key) else {
+        continue;
+      };
+      let target_message = target_bundle.and_then(|bundle| bundle.get_message(key));
+      let mut note = self.find_message_comment(source_language, key);
+
+      if let Some(value) = message.value() {
+        units.push(XliffUnit {
+          id: key.to_string(),
+          source: pattern_source(value),
+          target: target_message
+            .as_ref()
+            .and_then(|target| target.value())
+            .map(pattern_source),
+          note: note.take(),
+        });
+      }
+
+      for attribute in message.attributes() {
+        units.push(XliffUnit {
+          id: format!("{key}.{}", attribute.id()),
+          source: pattern_source(attribute.value()),
+          target: target_message
+            .as_ref()
+            .and_then(|target| target.get_attribute(attribute.id()))
+            .map(|target| pattern_source(target.value())),
+          note: note.take(),
+        });
+      }
+    }
+
+    write_document(version, source_language, target_language, &units)
+  }
+}
+
+/// ### Description
+/// Converts a translated XLIFF 1.2 or 2.0 document back into a Fluent file for its target language, such
+/// as one exported with `export_xliff` and returned by a translation vendor. Units without a translation
+/// are left out. Fails when the document has no target language or a translation is not valid Fluent.
+/// ### Usage
+/// ```ignore
+/// ...
+/// let import = xliff_to_ftl(&fs::read_to_string("es-ES.xlf")?)?;
+///
+/// fs::write(format!("translations/{}/vendor.ftl", import.target_language), import.ftl)?;
+/// ```
+pub fn xliff_to_ftl(content: &str) -> Result<XliffImport, TranslatorError> {
+  let invalid_file = |detail: String| TranslatorError::InvalidXliffFile { detail };
+
+  let tokens = tokenize(content).map_err(invalid_file)?;
+
+  let mut source_language = None;
+  let mut target_language = None;
+  let mut unit_id = None;
+  let mut target: Option<String> = None;
+  let mut target_depth = 0;
+  let mut translations: Vec<(String, String)> = Vec::new();
+
+  for token in tokens {
+    match token {
+      XmlToken::Start {
+        name,
+        attributes,
+        self_closing,
+      } => {
+        let attribute = |wanted: &str| {
+          attributes
+            .iter()
+            .find(|(name, _)| *name == wanted)
+            .map(|(_, value)| value.clone())
+        };
+
+        match name {
+          "xliff" | "file" => {
+            source_language = attribute("srcLang")
+              .or_else(|| attribute("source-language"))
+              .or(source_language);
+            target_language = attribute("trgLang")
+              .or_else(|| attribute("target-language"))
+              .or(target_language);
+          }
+          "unit" | "trans-unit" => unit_id = attribute("id"),
+          "target" if target_depth == 0 && !self_closing => {
+            target = Some(String::new());
+            target_depth = 1;
+          }
+          _ if target_depth > 0 && !self_closing => target_depth += 1,
+          _ => {}
+        }
+      }
+      XmlToken::End { name } => {
+        if target_depth > 0 {
+          target_depth -= 1;
+          continue;
+        }
+
+        if name == "unit" || name == "trans-unit" {
+          if let (Some(id), Some(text)) = (unit_id.take(), target.take()) {
+            if !text.is_empty() {
+              translations.push((id, text));
+            }
+          }
+        }
+      }
+      XmlToken::Text(text) => {
+        if let Some(target) = target.as_mut().filter(|_| target_depth > 0) {
+          target.push_str(&text);
+        }
+      }
+    }
+  }
+
+  let target_language = target_language
+    .ok_or_else(|| invalid_file("the document does not declare a target language".to_string()))?;
+
+  Ok(XliffImport {
+    source_language,
+    target_language,
+    ftl: units_to_ftl(&translations).map_err(invalid_file)?,
+  })
+}
+
+/// Writes the Fluent source of a pattern as translators see it, without the indentation of its lines.
+fn pattern_source(pattern: &Pattern<&str>) -> String {
+  let resource = Resource {
+    body: vec![Entry::Message(Message {
+      id: Identifier { name: "unit" },
+      value: Some(pattern.clone()),
+      attributes: Vec::new(),
+      comment: None,
+    })],
+  };
+
+  let serialized = serializer::serialize(&resource);
+  let value = serialized
+    .strip_prefix("unit =")
+    .unwrap_or(&serialized)
+    .trim_end_matches('\n');
+
+  match value.strip_prefix('\n') {
+    Some(block) => block
+      .lines()
+      .map(|line| line.strip_prefix("    ").unwrap_or(line))
+      .collect::<Vec<&str>>()
+      .join("\n"),
+    None => value.strip_prefix(' ').unwrap_or(value).to_string(),
+  }
+}
+
+/// Groups translated units into messages, checking every message is valid Fluent.
+fn units_to_ftl(translations: &[(String, String)]) -> Result<String, String> {
+  let mut messages: Vec<TranslatedMessage> = Vec::new();
+
+  for (id, text) in translations {
+    let (message_id, attribute) = match id.split_once('.') {
+      Some((message_id, attribute)) => (message_id, Some(attribute)),
+      None => (id.as_str(), None),
+    };
+
+    let index = match messages.iter().position(|message| message.id == message_id) {
+      Some(index) => index,
+      None => {
+        messages.push(TranslatedMessage {
+          id: message_id,
+          value: None,
+          attributes: Vec::new(),
+        });
+        messages.len() - 1
+      }
+    };
+
+    match attribute {
+      Some(attribute) => messages[index].attributes.push((attribute, text)),
+      None => messages[index].value = Some(text),
+    }
+  }
+
+  let mut ftl = String::new();
+
+  for TranslatedMessage {
+    id,
+    value,
+    attributes,
+  } in messages
+  {
+    let mut message = id.to_string();
+
+    match value {
+      Some(value) => write_pattern(&mut message, " =", value, 4),
+      None => message.push_str(" ="),
+    }
+
+    for (attribute, text) in attributes {
+      write_pattern(&mut message, &format!("\n    .{attribute} ="), text, 8);
+    }
+
+    message.push('\n');
+
+    if let Err((_, errors)) = parser::parse(message.as_str()) {
+      return Err(format!(
+        "the translation of {id} is not valid Fluent: {errors:?}"
+      ));
+    }
+
+    ftl.push_str(&message);
+  }
+
+  Ok(ftl)
+}
+
+fn write_pattern(ftl: &mut String, prefix: &str, text: &str, indent: usize) {
+  ftl.push_str(prefix);
+
+  if !text.contains('\n') {
+    ftl.push(' ');
+    ftl.push_str(text);
+    return;
+  }
+
+  for line in text.lines() {
+    ftl.push('\n');
+
+    if !line.is_empty() {
+      ftl.push_str(&" ".repeat(indent));
+      ftl.push_str(line);
+    }
+  }
+}
+
+fn write_document(
+  version: XliffVersion,
+  source_language: &str,
+  target_language: &str,
+  units: &[XliffUnit],
+) -> String {
+  let source_language = escape(source_language);
+  let target_language = escape(target_language);
+
+  let mut xliff = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+
+  match version {
+    XliffVersion::V1_2 => {
+      xliff.push_str("<xliff version=\"1.2\" xmlns=\"urn:oasis:names:tc:xliff:document:1.2\">\n");
+      xliff.push_str(&format!(
+        "  <file original=\"translations\" datatype=\"plaintext\" source-language=\"{source_language}\" target-language=\"{target_language}\">\n    <body>\n"
+      ));
+
+      for unit in units {
+        xliff.push_str(&format!(
+          "      <trans-unit id=\"{}\">\n        <source>{}</source>\n",
+          escape(&unit.id),
+          escape(&unit.source)
+        ));
+
+        if let Some(target) = &unit.target {
+          xliff.push_str(&format!(
+            "        <target state=\"translated\">{}</target>\n",
+            escape(target)
+          ));
+        }
+
+        if let Some(note) = &unit.note {
+          xliff.push_str(&format!("        <note>{}</note>\n", escape(note)));
+        }
+
+        xliff.push_str("      </trans-unit>\n");
+      }
+
+      xliff.push_str("    </body>\n  </file>\n</xliff>\n");
+    }
+    XliffVersion::V2_0 => {
+      xliff.push_str(&format!(
+        "<xliff version=\"2.0\" xmlns=\"urn:oasis:names:tc:xliff:document:2.0\" srcLang=\"{source_language}\" trgLang=\"{target_language}\">\n  <file id=\"translations\">\n"
+      ));
+
+      for unit in units {
+        xliff.push_str(&format!("    <unit id=\"{}\">\n", escape(&unit.id)));
+
+        if let Some(note) = &unit.note {
+          xliff.push_str(&format!(
+            "      <notes>\n        <note>{}</note>\n      </notes>\n",
+            escape(note)
+          ));
+        }
+
+        let state = if unit.target.is_some() {
+          "translated"
+        } else {
+          "initial"
+        };
+
+        xliff.push_str(&format!(
+          "      <segment state=\"{state}\">\n        <source>{}</source>\n",
+          escape(&unit.source)
+        ));
+
+        if let Some(target) = &unit.target {
+          xliff.push_str(&format!("        <target>{}</target>\n", escape(target)));
+        }
+
+        xliff.push_str("      </segment>\n    </unit>\n");
+      }
+
+      xliff.push_str("  </file>\n</xliff>\n");
+    }
+  }
+
+  xliff
+}
+
+fn escape(text: &str) -> String {
+  let mut escaped = String::with_capacity(text.len());
+
+  for character in text.chars() {
+    match character {
+      '&' => escaped.push_str("&amp;"),
+      '<' => escaped.push_str("&lt;"),
+      '>' => escaped.push_str("&gt;"),
+      '"' => escaped.push_str("&quot;"),
+      '\'' => escaped.push_str("&apos;"),
+      character => escaped.push(character),
+    }
+  }
+
+  escaped
+}
+
+fn unescape(text: &str) -> Result<String, String> {
+  let mut unescaped = String::with_capacity(text.len());
+  let mut rest = text;
+
+  while let Some(start) = rest.find('&') {
+    unescaped.push_str(&rest[..start]);
+
+    let end = rest[start..]
+      .find(';')
+      .ok_or_else(|| format!("unterminated entity in {text}"))?;
+    let entity = &rest[start + 1..start + end];
+
+    let character = match entity {
+      "amp" => Some('&'),
+      "lt" => Some('<'),
+      "gt" => Some('>'),
+      "quot" => Some('"'),
+      "apos" => Some('\''),
+      _ => entity
+        .strip_prefix("#x")
+        .and_then(|code| u32::from_str_radix(code, 16).ok())
+        .or_else(|| entity.strip_prefix('#').and_then(|code| code.parse().ok()))
+        .and_then(char::from_u32),
+    };
+
+    unescaped.push(character.ok_or_else(|| format!("unknown entity &{entity};"))?);
+    rest = &rest[start + end + 1..];
+  }
+
+  unescaped.push_str(rest);
+  Ok(unescaped)
+}
+
+/// The parts of an XML document XLIFF files are read from. Names are kept without their namespace prefix.
+enum XmlToken<'source> {
+  Start {
+    name: &'source str,
+    attributes: Vec<(&'source str, String)>,
+    self_closing: bool,
+  },
+  End {
+    name: &'source str,
+  },
+  Text(String),
+}
+
+fn tokenize(content: &str) -> Result<Vec<XmlToken<'_>>, String> {
+  let mut tokens = Vec::new();
+  let mut rest = content;
+
+  while !rest.is_empty() {
+    let Some(start) = rest.find('<') else {
+      tokens.push(XmlToken::Text(unescape(rest)?));
+      break;
+    };
+
+    if start > 0 {
+      tokens.push(XmlToken::Text(unescape(&rest[..start])?));
+    }
+
+    rest = &rest[start..];
+
+    let skipped = [
+      ("<?", "?>"),
+      ("<!--", "-->"),
+      ("<![CDATA[", "]]>"),
+      ("<!", ">"),
+    ]
+    .into_iter()
+    .find(|(opening, _)| rest.starts_with(opening));
+
+    if let Some((opening, closing)) = skipped {
+      let end = rest
+        .find(closing)
+        .ok_or_else(|| format!("unterminated {opening}"))?;
+
+      if opening == "<![CDATA[" {
+        tokens.push(XmlToken::Text(rest[opening.len()..end].to_string()));
+      }
+
+      rest = &rest[end + closing.len()..];
+      continue;
+    }
+
+    let end = rest
+      .find('>')
+      .ok_or_else(|| "unterminated tag".to_string())?;
+    let tag = &rest[1..end];
+    rest = &rest[end + 1..];
+
+    if let Some(name) = tag.strip_prefix('/') {
+      tokens.push(XmlToken::End {
+        name: local_name(name.trim()),
+      });
+      continue;
+    }
+
+    let (tag, self_closing) = match tag.strip_suffix('/') {
+      Some(tag) => (tag, true),
+      None => (tag, false),
+    };
+
+    let (name, attributes) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+
+    tokens.push(XmlToken::Start {
+      name: local_name(name),
+      attributes: parse_attributes(attributes)?,
+      self_closing,
+    });
+  }
+
+  Ok(tokens)
+}
+
+fn parse_attributes(mut source: &str) -> Result<Vec<(&str, String)>, String> {
+  let mut attributes = Vec::new();
+
+  loop {
+    source = source.trim_start();
+
+    let Some((name, rest)) = source.split_once('=') else {
+      return Ok(attributes);
+    };
+
+    let rest = rest.trim_start();
+    let quote = rest
+      .chars()
+      .next()
+      .filter(|quote| *quote == '"' || *quote == '\'')
+      .ok_or_else(|| format!("attribute {} is not quoted", name.trim()))?;
+    let end = rest[1..]
+      .find(quote)
+      .ok_or_else(|| format!("attribute {} is not terminated", name.trim()))?;
+
+    attributes.push((local_name(name.trim()), unescape(&rest[1..end + 1])?));
+    source = &rest[end + 2..];
+  }
+}
+
+fn local_name(name: &str) -> &str {
+  name.rsplit_once(':').map_or(name, |(_, local)| local)
+}
@@ -0,0 +1,116 @@
+#![cfg(feature = "tokio")]
+
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use std::future::Future;
+
+use translate::{Translator, TranslatorError};
+
+fn block_on<Output>(future: impl Future<Output = Output>) -> Output {
+  tokio::runtime::Builder::new_current_thread()
+    .build()
+    .unwrap()
+    .block_on(future)
+}
+
+#[test]
+fn translations_load_asynchronously_like_new() {
+  let english = TestLanguage("en-US");
+  let spanish = TestLanguage("es-ES");
+
+  let translator = block_on(Translator::<TestLanguage, TestKey>::new_async(
+    &fixture("languages"),
+    &english,
+  ))
+  .unwrap();
+
+  assert_eq!(
+    translator.translate_without_arguments(&english, TestKey("hello")),
+    "Hello"
+  );
+  assert_eq!(
+    translator.translate_without_arguments(&spanish, TestKey("hello")),
+    "Hola"
+  );
+}
+
+#[test]
+fn the_builder_loads_asynchronously_with_its_options() {
+  let english = TestLanguage("en-US");
+
+  let translator = block_on(
+    Translator::<TestLanguage, TestKey>::builder()
+      .directory(&fixture("languages"))
+      .default_language(&english)
+      .skip_languages(&["es-ES"])
+      .build_async(),
+  )
+  .unwrap();
+
+  assert_eq!(translator.languages().collect::<Vec<_>>(), ["en-US"]);
+}
+
+#[test]
+fn asynchronous_loading_fails_like_new() {
+  let english = TestLanguage("en-US");
+
+  let missing = block_on(Translator::<TestLanguage, TestKey>::new_async(
+    &fixture("missing"),
+    &english,
+  ));
+  let strict = block_on(
+    Translator::<TestLanguage, TestKey>::builder()
+      .directory(&fixture("duplicates"))
+      .default_language(&english)
+      .strict(true)
+      .build_async(),
+  );
+  let without_directory = block_on(
+    Translator::<TestLanguage, TestKey>::builder()
+      .default_language(&english)
+      .build_async(),
+  );
+
+  assert!(matches!(
+    missing,
+    Err(TranslatorError::DirectoryNotFound { .. })
+  ));
+  assert!(matches!(strict, Err(TranslatorError::LoadErrors(_))));
+  assert!(matches!(
+    without_directory,
+    Err(TranslatorError::NoDirectory)
+  ));
+}
+
+#[test]
+fn lazy_loading_leaves_other_languages_unloaded_until_used() {
+  let english = TestLanguage("en-US");
+  let spanish = TestLanguage("es-ES");
+
+  let translator = block_on(
+    Translator::<TestLanguage, TestKey>::builder()
+      .directory(&fixture("languages"))
+      .default_language(&english)
+      .lazy_loading(true)
+      .build_async(),
+  )
+  .unwrap();
+
+  let is_loaded = |language: &str| {
+    translator
+      .stats()
+      .languages
+      .into_iter()
+      .find(|stats| stats.language == language)
+      .map(|stats| stats.loaded)
+  };
+
+  assert_eq!(is_loaded("en-US"), Some(true));
+  assert_eq!(is_loaded("es-ES"), Some(false));
+  assert_eq!(
+    translator.translate_without_arguments(&spanish, TestKey("hello")),
+    "Hola"
+  );
+  assert_eq!(is_loaded("es-ES"), Some(true));
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use common::{TestKey, TestLanguage};
+use translate::{TranslationError, Translator};
+
+fn translator() -> Translator<TestLanguage, TestKey> {
+  let mut translator = Translator::<TestLanguage, TestKey>::from_sources(
+    [
+      (
+        "en-US",
+        "login-input = Email\n    .placeholder = email@example.com\n    .aria-label = Email of { $name }\n",
+      ),
+      (
+        "es-ES",
+        "login-input = Correo\n    .placeholder = correo@ejemplo.com\n",
+      ),
+    ],
+    &TestLanguage("en-US"),
+  )
+  .unwrap();
+  translator.set_use_isolating(false);
+  translator
+}
+
+#[test]
+fn attributes_are_translated_next_to_the_value() {
+  let translator = translator();
+  let spanish = TestLanguage("es-ES");
+
+  assert_eq!(
+    translator.translate_without_arguments(&spanish, TestKey("login-input")),
+    "Correo"
+  );
+  assert_eq!(
+    translator.translate_attribute(&spanish, TestKey("login-input"), "placeholder"),
+    "correo@ejemplo.com"
+  );
+}
+
+#[test]
+fn attributes_take_arguments() {
+  let translator = translator();
+
+  let message = translator
+    .translate(&TestLanguage("en-US"), TestKey("login-input"))
+    .attribute("aria-label")
+    .add_argument("name", "Alex");
+
+  assert_eq!(message.try_build().unwrap(), "Email of Alex");
+}
+
+#[test]
+fn missing_attributes_fail_without_using_the_value() {
+  let translator = translator();
+  let spanish = TestLanguage("es-ES");
+
+  let message = translator
+    .translate(&spanish, TestKey("login-input"))
+    .attribute("title");
+
+  assert!(matches!(
+    message.try_build(),
+    Err(TranslationError::AttributeNotFound { key, attribute })
+      if key == "login-input" && attribute == "title"
+  ));
+
+  let fallback = translator.translate_attribute(&spanish, TestKey("login-input"), "title");
+  assert!(!fallback.is_empty());
+  assert_ne!(fallback, "Correo");
+}
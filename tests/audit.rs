@@ -0,0 +1,100 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use translate::{AuditIssue, AuditIssueKind, Translator};
+
+fn audit(fixture_name: &str) -> Vec<AuditIssue> {
+  Translator::<TestLanguage, TestKey>::new(&fixture(fixture_name), &TestLanguage("en-US"))
+    .unwrap()
+    .audit()
+}
+
+fn issue(language: &str, key: &str, kind: AuditIssueKind) -> AuditIssue {
+  AuditIssue {
+    language: language.to_string(),
+    key: key.to_string(),
+    kind,
+  }
+}
+
+#[test]
+fn valid_translations_have_no_issues() {
+  assert_eq!(audit("languages"), []);
+}
+
+#[test]
+fn messages_with_only_attributes_are_not_reported() {
+  assert_eq!(audit("attributes-only"), []);
+}
+
+#[test]
+fn undefined_references_are_reported() {
+  assert_eq!(
+    audit("undefined-references"),
+    [
+      issue(
+        "en-US",
+        "about",
+        AuditIssueKind::UndefinedReference {
+          reference: "-brand".to_string()
+        }
+      ),
+      issue(
+        "en-US",
+        "about",
+        AuditIssueKind::UndefinedReference {
+          reference: "company".to_string()
+        }
+      ),
+      issue(
+        "en-US",
+        "title",
+        AuditIssueKind::UndefinedReference {
+          reference: "OTHER()".to_string()
+        }
+      ),
+    ]
+  );
+}
+
+#[test]
+fn duplicate_definitions_are_reported_with_the_later_file() {
+  assert_eq!(
+    audit("duplicates"),
+    [issue(
+      "en-US",
+      "hello",
+      AuditIssueKind::DuplicateDefinition {
+        file_name: "b.ftl".to_string()
+      }
+    )]
+  );
+}
+
+#[test]
+fn unparsed_entries_are_reported_with_their_line() {
+  let issues = audit("syntax-error");
+
+  assert_eq!(issues.len(), 1, "{issues:#?}");
+  assert_eq!(issues[0].language, "en-US");
+  assert_eq!(issues[0].key, "broken");
+  assert!(matches!(
+    &issues[0].kind,
+    AuditIssueKind::ParseRecovered { file_name, line: 2, .. } if file_name == "main.ftl"
+  ));
+}
+
+#[test]
+fn arguments_differing_from_the_default_language_are_reported() {
+  assert_eq!(
+    audit("arguments-differ"),
+    [issue(
+      "es-ES",
+      "hello",
+      AuditIssueKind::ArgumentsDiffer {
+        missing: vec!["name".to_string()],
+        extra: vec!["nombre".to_string()],
+      }
+    )]
+  );
+}
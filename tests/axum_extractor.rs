@@ -0,0 +1,96 @@
+#![cfg(feature = "axum")]
+
+mod common;
+
+use common::{TestKey, TestLanguage};
+use std::{
+  future::Future,
+  pin::pin,
+  task::{Context, Poll, Waker},
+};
+
+use axum::{
+  extract::FromRequestParts,
+  http::{header::ACCEPT_LANGUAGE, Request},
+};
+use translate::{SharedTranslator, Translated, Translator};
+
+type State = SharedTranslator<TestLanguage, TestKey>;
+
+fn state() -> State {
+  let translator = Translator::<TestLanguage, TestKey>::from_sources(
+    [
+      ("en-US", "hello = Hello\n"),
+      ("es-ES", "hello = Hola\n"),
+      ("fr-FR", "hello = Bonjour\n"),
+    ],
+    &TestLanguage("en-US"),
+  )
+  .unwrap();
+
+  SharedTranslator::new(translator)
+}
+
+/// The extractor never waits on anything, so it is ready the first time it is polled.
+fn ready<Output>(future: impl Future<Output = Output>) -> Output {
+  match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+    Poll::Ready(output) => output,
+    Poll::Pending => panic!("the extractor is not ready"),
+  }
+}
+
+fn extract(state: &State, accept_language: Option<&str>) -> Translated<TestLanguage, TestKey> {
+  let mut request = Request::builder();
+
+  if let Some(accept_language) = accept_language {
+    request = request.header(ACCEPT_LANGUAGE, accept_language);
+  }
+
+  let (mut parts, ()) = request.body(()).unwrap().into_parts();
+
+  match ready(Translated::from_request_parts(&mut parts, state)) {
+    Ok(translated) => translated,
+    Err(infallible) => match infallible {},
+  }
+}
+
+#[test]
+fn languages_are_picked_by_quality() {
+  let state = state();
+  let language = |header| extract(&state, Some(header)).language().to_string();
+
+  assert_eq!(language("es-ES,fr-FR;q=0.9"), "es-ES");
+  assert_eq!(language("es-ES;q=0.5,fr-FR;q=0.9"), "fr-FR");
+  assert_eq!(language("de-DE, es-MX;q=0.8, en;q=0.5"), "es-ES");
+  assert_eq!(language("es-ES;q=0.8,fr-FR;q=0.8"), "es-ES");
+}
+
+#[test]
+fn wildcards_and_rejected_languages_are_ignored() {
+  let state = state();
+  let language = |header| extract(&state, Some(header)).language().to_string();
+
+  assert_eq!(language("*,fr-FR;q=0.1"), "fr-FR");
+  assert_eq!(language("es-ES;q=0,fr-FR;q=0.1"), "fr-FR");
+  assert_eq!(language("es-ES;q=abc,fr-FR;q=0.1"), "fr-FR");
+}
+
+#[test]
+fn requests_without_a_known_language_use_the_default_one() {
+  let state = state();
+
+  assert_eq!(extract(&state, None).language(), "en-US");
+  assert_eq!(extract(&state, Some("ja-JP, de")).language(), "en-US");
+  assert_eq!(extract(&state, Some("")).language(), "en-US");
+}
+
+#[test]
+fn translations_use_the_language_of_the_request() {
+  let state = state();
+
+  assert_eq!(
+    extract(&state, Some("fr-FR")).t(TestKey("hello")),
+    "Bonjour"
+  );
+  assert_eq!(extract(&state, None).t(TestKey("hello")), "Hello");
+}
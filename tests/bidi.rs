@@ -0,0 +1,124 @@
+mod common;
+
+use common::{fixture, temporary_fixture, TestKey, TestLanguage};
+use translate::{isolate, text_direction, BidiIsolation, TextDirection, Translator};
+
+fn translator(bidi_isolation: BidiIsolation) -> Translator<TestLanguage, TestKey> {
+  Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("bidi"))
+    .default_language(&TestLanguage("en-US"))
+    .with_bidi_isolation(bidi_isolation)
+    .build()
+    .unwrap()
+}
+
+#[test]
+fn text_direction_follows_first_strong_character() {
+  assert_eq!(
+    text_direction("مرحبا Alex"),
+    Some(TextDirection::RightToLeft)
+  );
+  assert_eq!(text_direction("42 Alex"), Some(TextDirection::LeftToRight));
+  assert_eq!(text_direction("42"), None);
+}
+
+#[test]
+fn right_to_left_isolation_only_isolates_right_to_left_languages() {
+  let translator = translator(BidiIsolation::RightToLeft);
+
+  let arabic = translator
+    .translate(&TestLanguage("ar"), TestKey("welcome"))
+    .add_argument("name", "Alex");
+  let english = translator
+    .translate(&TestLanguage("en-US"), TestKey("welcome"))
+    .add_argument("name", "Alex");
+
+  assert_eq!(arabic.build(), format!("مرحبا {}", isolate("Alex")));
+  assert_eq!(english.build(), "Welcome Alex");
+}
+
+#[test]
+fn isolate_argument_only_isolates_opposite_direction_without_placeable_isolation() {
+  let arabic = TestLanguage("ar");
+  let english = TestLanguage("en-US");
+  let never = translator(BidiIsolation::Never);
+  let always = translator(BidiIsolation::Always);
+
+  assert_eq!(never.isolate_argument(&arabic, "Alex"), isolate("Alex"));
+  assert_eq!(never.isolate_argument(&arabic, "محمد"), "محمد");
+  assert_eq!(never.isolate_argument(&english, "محمد"), isolate("محمد"));
+  assert_eq!(never.isolate_argument(&english, "42"), "42");
+  assert_eq!(always.isolate_argument(&arabic, "Alex"), "Alex");
+}
+
+fn welcome(translator: &Translator<TestLanguage, TestKey>, language: &'static str) -> String {
+  translator
+    .translate(&TestLanguage(language), TestKey("welcome"))
+    .add_argument("name", "Alex")
+    .into_string()
+}
+
+#[test]
+fn placeables_are_isolated_by_default() {
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("bidi"), &TestLanguage("en-US")).unwrap();
+
+  assert_eq!(
+    welcome(&translator, "en-US"),
+    format!("Welcome {}", isolate("Alex"))
+  );
+  assert_eq!(
+    welcome(&translator, "ar"),
+    format!("مرحبا {}", isolate("Alex"))
+  );
+}
+
+#[test]
+fn isolation_can_be_toggled_for_every_loaded_language() {
+  let mut translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("bidi"), &TestLanguage("en-US")).unwrap();
+
+  translator.set_use_isolating(false);
+
+  assert_eq!(welcome(&translator, "en-US"), "Welcome Alex");
+  assert_eq!(welcome(&translator, "ar"), "مرحبا Alex");
+
+  translator.set_use_isolating(true);
+
+  assert_eq!(
+    welcome(&translator, "en-US"),
+    format!("Welcome {}", isolate("Alex"))
+  );
+}
+
+#[test]
+fn isolation_can_be_disabled_on_the_builder() {
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("bidi"))
+    .default_language(&TestLanguage("en-US"))
+    .use_isolating(false)
+    .build()
+    .unwrap();
+
+  assert_eq!(welcome(&translator, "ar"), "مرحبا Alex");
+}
+
+#[test]
+fn disabled_isolation_is_kept_when_bundles_are_rebuilt() {
+  let directory = temporary_fixture("bidi");
+  let mut translator =
+    Translator::<TestLanguage, TestKey>::new(directory.to_str().unwrap(), &TestLanguage("en-US"))
+      .unwrap();
+  translator.set_use_isolating(false);
+
+  translator
+    .set_override(&TestLanguage("en-US"), "welcome", "Hi { $name }")
+    .unwrap();
+  assert_eq!(welcome(&translator, "en-US"), "Hi Alex");
+
+  translator.reload().unwrap();
+  assert_eq!(welcome(&translator, "en-US"), "Hi Alex");
+  assert_eq!(welcome(&translator, "ar"), "مرحبا Alex");
+
+  std::fs::remove_dir_all(directory).unwrap();
+}
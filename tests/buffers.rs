@@ -0,0 +1,78 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use translate::Translator;
+
+fn translator() -> Translator<TestLanguage, TestKey> {
+  Translator::<TestLanguage, TestKey>::new(&fixture("buffers"), &TestLanguage("en-US")).unwrap()
+}
+
+#[test]
+fn translations_are_appended_to_the_buffer() {
+  let translator = translator();
+  let english = TestLanguage("en-US");
+  let mut html = String::from("<ul>");
+
+  for key in ["home", "settings"] {
+    html.push_str("<li>");
+    assert!(translator.translate_into(&english, TestKey(key), &mut html));
+    html.push_str("</li>");
+  }
+
+  html.push_str("</ul>");
+
+  assert_eq!(html, "<ul><li>Home</li><li>Settings</li></ul>");
+}
+
+#[test]
+fn translations_into_buffers_fall_back_like_translate() {
+  let translator = translator();
+  let spanish = TestLanguage("es-ES");
+  let mut out = String::new();
+
+  assert!(translator.translate_into(&spanish, TestKey("home"), &mut out));
+  out.push(' ');
+  assert!(translator.translate_into(&spanish, TestKey("settings"), &mut out));
+
+  assert_eq!(out, "Inicio Settings");
+}
+
+#[test]
+fn buffers_are_untouched_when_there_is_nothing_to_write() {
+  let translator = translator();
+  let english = TestLanguage("en-US");
+  let mut out = String::from("kept");
+
+  assert!(!translator.translate_into(&english, TestKey("missing"), &mut out));
+  assert!(!translator.translate_into(&english, TestKey("login"), &mut out));
+
+  assert_eq!(out, "kept");
+}
+
+#[test]
+fn built_messages_are_appended_to_the_buffer() {
+  let translator = translator();
+  let english = TestLanguage("en-US");
+  let mut out = String::new();
+
+  for name in ["Alex", "Sam"] {
+    let message = translator
+      .translate(&english, TestKey("greeting"))
+      .add_argument("name", name);
+
+    assert!(message.build_into(&mut out));
+    out.push('\n');
+  }
+
+  assert_eq!(
+    out,
+    "Hello \u{2068}Alex\u{2069}\nHello \u{2068}Sam\u{2069}\n"
+  );
+  assert!(!translator
+    .translate(&english, TestKey("missing"))
+    .build_into(&mut out));
+  assert_eq!(
+    out,
+    "Hello \u{2068}Alex\u{2069}\nHello \u{2068}Sam\u{2069}\n"
+  );
+}
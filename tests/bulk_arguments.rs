@@ -0,0 +1,105 @@
+mod common;
+
+use common::{TestKey, TestLanguage};
+use std::collections::HashMap;
+
+use translate::{FluentArgs, IntoFluentArgs, Translator};
+
+struct Inbox<'a> {
+  name: &'a str,
+  count: u32,
+}
+
+impl<'a> IntoFluentArgs<'a> for Inbox<'a> {
+  fn into_fluent_args(self) -> FluentArgs<'a> {
+    let mut args = FluentArgs::new();
+    args.set("name", self.name);
+    args.set("count", self.count);
+    args
+  }
+}
+
+fn translator() -> Translator<TestLanguage, TestKey> {
+  let mut translator = Translator::<TestLanguage, TestKey>::from_sources(
+    [(
+      "en-US",
+      "welcome = Welcome { $name } from { $city }\nunread = { $name } has { $count } messages\n",
+    )],
+    &TestLanguage("en-US"),
+  )
+  .unwrap();
+  translator.set_use_isolating(false);
+  translator
+}
+
+#[test]
+fn arguments_are_added_from_maps_lists_and_fluent_args() {
+  let translator = translator();
+  let english = TestLanguage("en-US");
+
+  let from_map = translator
+    .translate(&english, TestKey("welcome"))
+    .with_args(HashMap::from([("name", "Alex"), ("city", "Lima")]));
+  let from_list = translator.translate_with_args(
+    &english,
+    TestKey("welcome"),
+    [("name", "Alex"), ("city", "Lima")],
+  );
+
+  let mut fluent_args = FluentArgs::new();
+  fluent_args.set("name", "Alex");
+  fluent_args.set("count", 3);
+  let from_fluent_args = translator
+    .translate(&english, TestKey("unread"))
+    .with_args(fluent_args);
+
+  assert_eq!(from_map.into_string(), "Welcome Alex from Lima");
+  assert_eq!(from_list.into_string(), "Welcome Alex from Lima");
+  assert_eq!(from_fluent_args.into_string(), "Alex has 3 messages");
+}
+
+#[test]
+fn later_arguments_replace_earlier_ones() {
+  let translator = translator();
+
+  let message = translator
+    .translate(&TestLanguage("en-US"), TestKey("welcome"))
+    .add_argument("name", "Sam")
+    .with_args([("name", "Alex"), ("city", "Quito"), ("city", "Lima")]);
+
+  assert_eq!(message.into_string(), "Welcome Alex from Lima");
+}
+
+#[test]
+fn owned_arguments_are_not_borrowed_by_the_result() {
+  let translator = translator();
+
+  let message = {
+    let name = String::from("Alex");
+    let city = String::from("Lima");
+
+    translator.translate_with_args_owned(
+      &TestLanguage("en-US"),
+      TestKey("welcome"),
+      [("name", name), ("city", city)],
+    )
+  };
+
+  assert_eq!(message, "Welcome Alex from Lima");
+}
+
+#[test]
+fn typed_arguments_are_converted_into_fluent_args() {
+  let translator = translator();
+  let name = String::from("Alex");
+
+  let message = translator
+    .translate(&TestLanguage("en-US"), TestKey("unread"))
+    .add_argument("count", 1)
+    .with(Inbox {
+      name: &name,
+      count: 3,
+    });
+
+  assert_eq!(message.into_string(), "Alex has 3 messages");
+}
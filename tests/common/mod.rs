@@ -0,0 +1,60 @@
+#![allow(dead_code)]
+
+use std::{
+  env, fs,
+  path::{Path, PathBuf},
+  process,
+  sync::atomic::{AtomicUsize, Ordering},
+};
+
+use translate::{Language, TranslationKey};
+
+pub struct TestLanguage(pub &'static str);
+
+impl Language for TestLanguage {
+  fn as_str(&self) -> &'static str {
+    self.0
+  }
+}
+
+pub struct TestKey(pub &'static str);
+
+impl TranslationKey for TestKey {
+  fn as_str(&self) -> &'static str {
+    self.0
+  }
+}
+
+pub fn fixture(name: &str) -> String {
+  format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Copies a fixture to a new temporary directory, for tests which modify the translation files.
+pub fn temporary_fixture(name: &str) -> PathBuf {
+  static COPIES: AtomicUsize = AtomicUsize::new(0);
+
+  let copy = COPIES.fetch_add(1, Ordering::Relaxed);
+  let directory = env::temp_dir().join(format!("translate-{name}-{}-{copy}", process::id()));
+
+  if directory.exists() {
+    fs::remove_dir_all(&directory).unwrap();
+  }
+
+  copy_directory(Path::new(&fixture(name)), &directory);
+  directory
+}
+
+fn copy_directory(source: &Path, destination: &Path) {
+  fs::create_dir_all(destination).unwrap();
+
+  for entry in fs::read_dir(source).unwrap() {
+    let entry = entry.unwrap();
+    let destination = destination.join(entry.file_name());
+
+    if entry.file_type().unwrap().is_dir() {
+      copy_directory(&entry.path(), &destination);
+    } else {
+      fs::copy(entry.path(), destination).unwrap();
+    }
+  }
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use common::{TestKey, TestLanguage};
+use translate::Translator;
+
+fn translator() -> Translator<TestLanguage, TestKey> {
+  Translator::<TestLanguage, TestKey>::from_sources(
+    [
+      (
+        "en-US",
+        "hello = Hello\ngoodbye = Goodbye\nthanks = Thanks\nwelcome = Welcome\n",
+      ),
+      ("es-ES", "hello = Hola\ngoodbye = Adiós\nextra = Extra\n"),
+      ("fr-FR", ""),
+    ],
+    &TestLanguage("en-US"),
+  )
+  .unwrap()
+}
+
+#[test]
+fn coverage_compares_every_language_with_the_default_one() {
+  let coverage = translator().coverage();
+
+  let languages: Vec<&str> = coverage
+    .iter()
+    .map(|coverage| coverage.language.as_str())
+    .collect();
+  assert_eq!(languages, ["en-US", "es-ES", "fr-FR"]);
+
+  assert_eq!(coverage[0].translated, 4);
+  assert_eq!(coverage[0].percentage(), 100.0);
+
+  let spanish = &coverage[1];
+  assert_eq!(spanish.translated, 2);
+  assert_eq!(spanish.total, 4);
+  assert_eq!(spanish.percentage(), 50.0);
+  assert_eq!(spanish.missing_keys, ["thanks", "welcome"]);
+  assert_eq!(spanish.extra_keys, ["extra"]);
+
+  assert_eq!(coverage[2].translated, 0);
+  assert_eq!(coverage[2].percentage(), 0.0);
+}
+
+#[test]
+fn overrides_count_as_translated() {
+  let mut translator = translator();
+
+  translator
+    .set_override(&TestLanguage("es-ES"), "thanks", "Gracias")
+    .unwrap();
+
+  let coverage = translator.coverage();
+
+  assert_eq!(coverage[1].translated, 3);
+  assert_eq!(coverage[1].missing_keys, ["welcome"]);
+}
+
+#[test]
+fn default_language_without_messages_is_fully_covered() {
+  let translator = Translator::<TestLanguage, TestKey>::from_sources(
+    [("en-US", ""), ("es-ES", "hello = Hola\n")],
+    &TestLanguage("en-US"),
+  )
+  .unwrap();
+
+  let coverage = translator.coverage();
+
+  assert_eq!(coverage[1].total, 0);
+  assert_eq!(coverage[1].percentage(), 100.0);
+  assert_eq!(coverage[1].extra_keys, ["hello"]);
+}
@@ -0,0 +1,153 @@
+#![cfg(feature = "derive")]
+
+mod common;
+
+use common::{fixture, TestLanguage};
+use translate::{
+  validate_keys, FluentValue, IntoFluentArgs, TranslationError, TranslationKey, Translator,
+};
+
+validate_keys! {
+  "tests/fixtures/derive", "en-US";
+
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, TranslationKey)]
+  enum Keys {
+    Hello,
+    #[translate(args("name", "unread-count"))]
+    HelloWithArguments,
+    #[translate(key = "menu-save")]
+    Save,
+    #[translate(defaults(count = 1))]
+    Unread,
+  }
+}
+
+#[derive(IntoFluentArgs)]
+struct Inbox<'a> {
+  name: &'a str,
+  #[translate(name = "unread-count")]
+  unread_count: u32,
+}
+
+fn translator() -> Translator<TestLanguage, Keys> {
+  Translator::<TestLanguage, Keys>::builder()
+    .directory(&fixture("derive"))
+    .default_language(&TestLanguage("en-US"))
+    .use_isolating(false)
+    .build()
+    .unwrap()
+}
+
+#[test]
+fn variants_map_to_snake_case_or_renamed_keys() {
+  assert_eq!(Keys::Hello.as_str(), "hello");
+  assert_eq!(Keys::HelloWithArguments.as_str(), "hello_with_arguments");
+  assert_eq!(Keys::Save.as_str(), "menu-save");
+
+  for key in [
+    Keys::Hello,
+    Keys::HelloWithArguments,
+    Keys::Save,
+    Keys::Unread,
+  ] {
+    assert_eq!(Keys::from_str(key.as_str()), Some(key));
+  }
+  assert_eq!(Keys::from_str("save"), None);
+}
+
+#[test]
+fn variants_list_their_required_and_default_arguments() {
+  assert_eq!(
+    Keys::HelloWithArguments.required_args(),
+    ["name", "unread-count"]
+  );
+  assert!(Keys::Hello.required_args().is_empty());
+
+  let defaults = Keys::Unread.default_args();
+
+  assert!(
+    matches!(defaults.get("count"), Some(FluentValue::Number(number)) if number.value == 1.0)
+  );
+  assert_eq!(Keys::Hello.default_args().iter().count(), 0);
+}
+
+#[test]
+fn derived_arguments_are_passed_by_field_name() {
+  let translator = translator();
+  let inbox = Inbox {
+    name: "Alex",
+    unread_count: 3,
+  };
+
+  assert_eq!(
+    translator
+      .translate(&TestLanguage("en-US"), Keys::HelloWithArguments)
+      .with(inbox)
+      .try_build()
+      .unwrap(),
+    "Hello Alex, you have 3 messages"
+  );
+}
+
+#[test]
+fn validated_keys_translate() {
+  let translator = translator();
+  let english = TestLanguage("en-US");
+
+  assert_eq!(
+    translator.translate_without_arguments(&english, Keys::Save),
+    "Save"
+  );
+  assert_eq!(
+    translator.translate_without_arguments(&english, Keys::Unread),
+    "One unread message"
+  );
+
+  let args = Inbox {
+    name: "Alex",
+    unread_count: 3,
+  }
+  .into_fluent_args();
+
+  assert_eq!(args.iter().count(), 2);
+}
+
+#[test]
+fn arguments_are_checked_against_the_declared_ones() {
+  let translator = translator();
+  let english = TestLanguage("en-US");
+
+  let missing = translator
+    .translate(&english, Keys::HelloWithArguments)
+    .add_argument("name", "Alex");
+  let extra = translator
+    .translate(&english, Keys::HelloWithArguments)
+    .with(Inbox {
+      name: "Alex",
+      unread_count: 3,
+    })
+    .add_argument("city", "Lima");
+
+  assert!(matches!(
+    missing.try_build(),
+    Err(TranslationError::ArgumentMismatch { key, missing, extra })
+      if key == "hello_with_arguments" && missing == ["unread-count"] && extra.is_empty()
+  ));
+  assert!(matches!(
+    extra.try_build(),
+    Err(TranslationError::ArgumentMismatch { missing, extra, .. })
+      if missing.is_empty() && extra == ["city"]
+  ));
+  assert_ne!(missing.build(), "Hello Alex, you have 3 messages");
+}
+
+#[test]
+fn keys_without_declared_arguments_are_not_checked() {
+  let translator = translator();
+
+  let message = translator
+    .translate(&TestLanguage("en-US"), Keys::Unread)
+    .add_argument("count", 2);
+
+  assert_eq!(message.try_build().unwrap(), "2 unread messages");
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "discord")]
+
+mod common;
+
+use common::{TestKey, TestLanguage};
+use translate::Translator;
+
+fn translator(languages: &[&'static str]) -> Translator<TestLanguage, TestKey> {
+  let sources = languages
+    .iter()
+    .map(|language| (*language, format!("ping = Ping {language}\n")));
+
+  Translator::<TestLanguage, TestKey>::from_sources(sources, &TestLanguage("en-US")).unwrap()
+}
+
+#[test]
+fn discord_locales_use_the_closest_loaded_language() {
+  let translator = translator(&["en-US", "en-GB", "es", "pt", "pt-PT", "fr-FR"]);
+
+  assert_eq!(translator.discord_language("en-GB"), Some("en-GB"));
+  assert_eq!(translator.discord_language("pt-BR"), Some("pt"));
+  assert_eq!(translator.discord_language("es-419"), Some("es"));
+  assert_eq!(translator.discord_language("es-ES"), Some("es"));
+  assert_eq!(translator.discord_language("fr"), Some("fr-FR"));
+  assert_eq!(translator.discord_language("ja"), None);
+}
+
+#[test]
+fn norwegian_matches_both_no_and_nb() {
+  assert_eq!(
+    translator(&["en-US", "nb"]).discord_language("no"),
+    Some("nb")
+  );
+  assert_eq!(
+    translator(&["en-US", "no"]).discord_language("no"),
+    Some("no")
+  );
+  assert_eq!(
+    translator(&["en-US", "nb-NO"]).discord_language("no"),
+    Some("nb-NO")
+  );
+}
+
+#[test]
+fn chinese_locales_only_use_languages_of_their_script() {
+  let both_scripts = translator(&["en-US", "zh-Hans", "zh-Hant"]);
+
+  assert_eq!(both_scripts.discord_language("zh-CN"), Some("zh-Hans"));
+  assert_eq!(both_scripts.discord_language("zh-TW"), Some("zh-Hant"));
+  assert_eq!(
+    translator(&["en-US", "zh-Hant"]).discord_language("zh-CN"),
+    None
+  );
+  assert_eq!(
+    translator(&["en-US", "zh-CN"]).discord_language("zh-CN"),
+    Some("zh-CN")
+  );
+}
+
+#[test]
+fn localizations_leave_out_locales_without_a_translation() {
+  let translator = Translator::<TestLanguage, TestKey>::from_sources(
+    [
+      ("en-US", "ping = Ping\nhelp = Help\n"),
+      ("es", "ping = Latencia\n"),
+      ("de", "ping = Pingen\n"),
+    ],
+    &TestLanguage("en-US"),
+  )
+  .unwrap();
+
+  let localizations = translator.discord_localizations(TestKey("ping"));
+
+  assert_eq!(localizations.len(), 5, "{localizations:?}");
+  assert_eq!(localizations["en-US"], "Ping");
+  assert_eq!(localizations["es-ES"], "Latencia");
+  assert_eq!(localizations["es-419"], "Latencia");
+  assert_eq!(localizations["de"], "Pingen");
+  assert_eq!(localizations["en-GB"], "Ping");
+
+  let help = translator.discord_localizations(TestKey("help"));
+
+  assert!(
+    help.keys().all(|locale| locale.starts_with("en")),
+    "{help:?}"
+  );
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use common::{TestKey, TestLanguage};
+use translate::{TranslationError, Translator};
+
+fn translator() -> Translator<TestLanguage, TestKey> {
+  let mut translator = Translator::<TestLanguage, TestKey>::from_sources(
+    [
+      (
+        "en-US",
+        "hello = Hello\nabout = About { -brand }\ngreeting = Hello { $name }\nlogin =\n    .title = Log in\n",
+      ),
+      ("es-ES", "hello = Hola\n"),
+    ],
+    &TestLanguage("en-US"),
+  )
+  .unwrap();
+  translator.set_use_isolating(false);
+  translator
+}
+
+#[test]
+fn successful_translations_are_ok_even_when_falling_back() {
+  let translator = translator();
+  let spanish = TestLanguage("es-ES");
+
+  assert_eq!(
+    translator
+      .try_translate(&spanish, TestKey("hello"))
+      .unwrap(),
+    "Hola"
+  );
+  assert_eq!(
+    translator
+      .translate(&spanish, TestKey("greeting"))
+      .add_argument("name", "Alex")
+      .try_build()
+      .unwrap(),
+    "Hello Alex"
+  );
+}
+
+#[test]
+fn messages_without_value_fail_with_no_value() {
+  let translator = translator();
+  let english = TestLanguage("en-US");
+
+  assert!(matches!(
+    translator.try_translate(&english, TestKey("login")),
+    Err(TranslationError::NoValue { key }) if key == "login"
+  ));
+  assert!(matches!(
+    translator.translate(&english, TestKey("login")).try_build(),
+    Err(TranslationError::NoValue { key }) if key == "login"
+  ));
+}
+
+#[test]
+fn broken_references_and_missing_arguments_fail_with_the_formatting_errors() {
+  let translator = translator();
+  let english = TestLanguage("en-US");
+
+  assert!(matches!(
+    translator.try_translate(&english, TestKey("about")),
+    Err(TranslationError::FormattingErrors { key, errors }) if key == "about" && errors.len() == 1
+  ));
+  assert!(matches!(
+    translator.translate(&english, TestKey("greeting")).try_build(),
+    Err(TranslationError::FormattingErrors { key, errors }) if key == "greeting" && errors.len() == 1
+  ));
+}
+
+#[test]
+fn failed_translations_still_have_a_fallback_text() {
+  let translator = translator();
+  let english = TestLanguage("en-US");
+
+  for key in ["missing", "login", "about"] {
+    assert!(translator.try_translate(&english, TestKey(key)).is_err());
+    assert!(
+      !translator
+        .translate_without_arguments(&english, TestKey(key))
+        .is_empty(),
+      "{key} has no fallback text"
+    );
+  }
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use translate::{FluentArgs, Translator};
+
+#[test]
+fn export_formats_values_and_attributes_in_key_order() {
+  let english = TestLanguage("en-US");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("variants"), &english).unwrap();
+
+  let exported: Vec<(String, String)> = translator.export(&english, |_| None).into_iter().collect();
+
+  assert_eq!(
+    exported,
+    [
+      ("greeting".to_string(), "Hello".to_string()),
+      ("greeting.formal".to_string(), "Good day".to_string()),
+      ("login-button.label".to_string(), "Log in".to_string()),
+    ]
+  );
+}
+
+#[test]
+fn export_uses_provided_arguments() {
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("arguments"))
+    .default_language(&english)
+    .use_isolating(false)
+    .build()
+    .unwrap();
+
+  let with_arguments = translator.export(&english, |key| {
+    (key == "hello_with_arguments").then(|| FluentArgs::from_iter([("name", "Alex")]))
+  });
+  let without_arguments = translator.export(&english, |_| None);
+
+  assert_eq!(with_arguments["hello_with_arguments"], "Hello Alex!");
+  assert_eq!(without_arguments["hello_with_arguments"], "Hello {$name}!");
+  assert!(translator
+    .export(&TestLanguage("fr-FR"), |_| None)
+    .is_empty());
+}
@@ -0,0 +1,314 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use translate::{FluentArgs, TranslationError, Translator};
+
+#[test]
+fn unknown_language_and_missing_key_fail_without_panicking() {
+  let english = TestLanguage("en-US");
+  let japanese = TestLanguage("ja-JP");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("languages"), &english).unwrap();
+
+  let result = translator.try_translate(&japanese, TestKey("missing"));
+
+  assert!(matches!(
+    result,
+    Err(TranslationError::MessageNotFound { key }) if key == "missing"
+  ));
+  assert!(!translator
+    .translate_without_arguments(&japanese, TestKey("missing"))
+    .is_empty());
+  assert!(!translator
+    .translate_attribute(&japanese, TestKey("missing"), "title")
+    .is_empty());
+}
+
+#[test]
+fn unknown_language_falls_back_to_default_language() {
+  let english = TestLanguage("en-US");
+  let japanese = TestLanguage("ja-JP");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("languages"), &english).unwrap();
+
+  assert_eq!(
+    translator.translate_without_arguments(&japanese, TestKey("hello")),
+    "Hello"
+  );
+}
+
+#[test]
+fn has_message_does_not_fall_back() {
+  let english = TestLanguage("en-US");
+  let japanese = TestLanguage("ja-JP");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("languages"), &english).unwrap();
+
+  assert!(translator.has_message(&english, TestKey("hello")));
+  assert!(!translator.has_message(&japanese, TestKey("hello")));
+  assert!(!translator.has_message(&english, TestKey("missing")));
+  assert!(translator.has_message_in_any_language(TestKey("hello")));
+  assert!(!translator.has_message_in_any_language(TestKey("missing")));
+}
+
+#[test]
+fn translate_many_keeps_key_order_and_falls_back_per_key() {
+  let english = TestLanguage("en-US");
+  let mexican_spanish = TestLanguage("es-MX");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("languages"), &english).unwrap();
+
+  let texts = translator.translate_many(
+    &mexican_spanish,
+    [TestKey("hello"), TestKey("missing"), TestKey("hello")],
+    None,
+  );
+
+  assert_eq!(texts.len(), 3);
+  assert_eq!(texts[0], "Hola");
+  assert_eq!(
+    texts[1],
+    translator.translate_without_arguments(&mexican_spanish, TestKey("missing"))
+  );
+  assert_eq!(texts[2], "Hola");
+}
+
+#[test]
+fn optional_translations_are_none_instead_of_the_fallback_text() {
+  let english = TestLanguage("en-US");
+  let spanish = TestLanguage("es-ES");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("optional"), &english).unwrap();
+
+  assert_eq!(
+    translator
+      .translate_opt(&spanish, TestKey("hello"))
+      .as_deref(),
+    Some("Hello")
+  );
+  assert_eq!(translator.translate_opt(&english, TestKey("missing")), None);
+  assert_eq!(translator.translate_opt(&english, TestKey("login")), None);
+
+  let greeting = translator.translate(&english, TestKey("greeting"));
+  assert_eq!(greeting.build_opt(), None);
+  assert_eq!(
+    greeting.add_argument("name", "Alex").build_opt().as_deref(),
+    Some("Hello \u{2068}Alex\u{2069}")
+  );
+}
+
+#[test]
+fn empty_default_language_falls_back_without_panicking() {
+  let english = TestLanguage("en-US");
+  let spanish = TestLanguage("es-ES");
+  let japanese = TestLanguage("ja-JP");
+  let mut translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("empty-default"), &english).unwrap();
+
+  assert!(translator.remove_language(&english).is_err());
+
+  let message = translator.translate(&japanese, TestKey("hello"));
+  let fallback = translator.translate_without_arguments(&japanese, TestKey("hello"));
+
+  assert!(matches!(
+    message.try_build(),
+    Err(TranslationError::MessageNotFound { key }) if key == "hello"
+  ));
+  assert_eq!(message.build(), fallback);
+  assert!(!fallback.is_empty());
+  assert_eq!(
+    translator.translate_without_arguments(&spanish, TestKey("hello")),
+    "Hola"
+  );
+  assert!(!translator.default_bundle().has_message("hello"));
+}
+
+#[test]
+fn detailed_translations_report_the_language_that_produced_the_text() {
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("detailed"), &TestLanguage("en-US")).unwrap();
+  let spanish = TestLanguage("es-ES");
+
+  let translation = translator.translate_detailed(&spanish, TestKey("hello"));
+  assert_eq!(translation.text, "Hola");
+  assert!(!translation.used_fallback);
+  assert_eq!(translation.effective_language, "es-ES");
+
+  let translation = translator.translate_detailed(&spanish, TestKey("farewell"));
+  assert_eq!(translation.text, "Goodbye");
+  assert!(translation.used_fallback);
+  assert_eq!(translation.effective_language, "en-US");
+}
+
+#[test]
+fn detailed_translations_of_unknown_languages_and_keys_use_fallbacks() {
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("detailed"), &TestLanguage("en-US")).unwrap();
+
+  let translation = translator.translate_detailed(&TestLanguage("ja-JP"), TestKey("hello"));
+  assert_eq!(translation.text, "Hello");
+  assert!(translation.used_fallback);
+  assert_eq!(translation.effective_language, "en-US");
+
+  let translation = translator.translate_detailed(&TestLanguage("en-US"), TestKey("missing"));
+  assert_eq!(
+    translation.text,
+    translator.translate_without_arguments(&TestLanguage("en-US"), TestKey("missing"))
+  );
+  assert!(translation.used_fallback);
+}
+
+fn regional_translator() -> Translator<TestLanguage, TestKey> {
+  Translator::<TestLanguage, TestKey>::from_sources(
+    [
+      (
+        "en-US",
+        "hello = Hello\nfarewell = Goodbye\nthanks = Thanks\n",
+      ),
+      ("es", "hello = Hola\nfarewell = Adiós\n"),
+      ("es-ES", "hello = Hola, España\n"),
+      ("es-MX", "hello = Qué onda\n"),
+    ],
+    &TestLanguage("en-US"),
+  )
+  .unwrap()
+}
+
+#[test]
+fn regional_languages_fall_back_to_their_base_language_then_the_default() {
+  let translator = regional_translator();
+  let mexican_spanish = TestLanguage("es-MX");
+
+  assert_eq!(
+    translator.translate_without_arguments(&mexican_spanish, TestKey("hello")),
+    "Qué onda"
+  );
+  assert_eq!(
+    translator.translate_without_arguments(&mexican_spanish, TestKey("farewell")),
+    "Adiós"
+  );
+  assert_eq!(
+    translator.translate_without_arguments(&mexican_spanish, TestKey("thanks")),
+    "Thanks"
+  );
+}
+
+#[test]
+fn unknown_regional_languages_use_the_closest_loaded_language() {
+  let translator = regional_translator();
+
+  assert_eq!(
+    translator.translate_without_arguments(&TestLanguage("es-AR"), TestKey("hello")),
+    "Hola"
+  );
+  assert_eq!(
+    translator.translate_without_arguments(&TestLanguage("es-AR"), TestKey("thanks")),
+    "Thanks"
+  );
+}
+
+#[test]
+fn resolve_language_picks_the_closest_loaded_language() {
+  let translator = regional_translator();
+
+  assert_eq!(translator.resolve_language("es-MX"), "es-MX");
+  assert_eq!(translator.resolve_language("es-AR"), "es");
+  assert_eq!(translator.resolve_language("en-GB"), "en-US");
+  assert_eq!(translator.resolve_language("ja"), "en-US");
+  assert_eq!(translator.resolve_language("not a language"), "en-US");
+}
+
+#[test]
+fn negotiate_language_follows_the_requested_order() {
+  let translator = regional_translator();
+
+  assert_eq!(
+    translator.negotiate_language(&["fr-CH", "es-MX", "en"]),
+    "es-MX"
+  );
+  assert_eq!(translator.negotiate_language(&["fr-CH", "en-GB"]), "en-US");
+  assert_eq!(translator.negotiate_language(&["fr-CH"]), "en-US");
+}
+
+#[test]
+fn negotiate_language_matches_regions_of_loaded_languages() {
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("languages"), &TestLanguage("en-US"))
+      .unwrap();
+
+  assert_eq!(
+    translator.negotiate_language(&["fr-CH", "es-MX", "en"]),
+    "es-ES"
+  );
+  assert_eq!(
+    translator.negotiate_language(&["not a language", "es"]),
+    "es-ES"
+  );
+  assert_eq!(translator.negotiate_language(&[]), "en-US");
+}
+
+#[test]
+fn negotiate_language_returns_the_name_of_languages_loaded_through_a_parser() {
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("language-names"))
+    .default_language(&TestLanguage("english"))
+    .with_language_parser(|name| match name {
+      "english" => "en-US".parse().ok(),
+      "russian" => "ru".parse().ok(),
+      name => name.parse().ok(),
+    })
+    .build()
+    .unwrap();
+
+  assert_eq!(translator.negotiate_language(&["ru-RU", "en"]), "russian");
+  assert_eq!(translator.negotiate_language(&["en-GB"]), "english");
+}
+
+#[test]
+fn translate_all_covers_every_language_with_fallbacks() {
+  let translator = regional_translator();
+
+  let farewells = translator.translate_all(TestKey("farewell"), None);
+
+  assert_eq!(farewells.len(), 4);
+  assert_eq!(farewells["en-US"], "Goodbye");
+  assert_eq!(farewells["es"], "Adiós");
+  assert_eq!(farewells["es-ES"], "Adiós");
+  assert_eq!(farewells["es-MX"], "Adiós");
+  assert_eq!(
+    translator
+      .translate_all_iter(TestKey("hello"), None)
+      .collect::<Vec<_>>(),
+    [
+      ("en-US", "Hello".into()),
+      ("es", "Hola".into()),
+      ("es-ES", "Hola, España".into()),
+      ("es-MX", "Qué onda".into()),
+    ]
+  );
+}
+
+#[test]
+fn translate_all_leaves_out_languages_failing_to_format() {
+  let english = TestLanguage("en-US");
+  let mut translator = Translator::<TestLanguage, TestKey>::from_sources(
+    [
+      ("en-US", "greeting = Hello { $name }\n"),
+      ("es-ES", "greeting = Hola { $nombre }\n"),
+    ],
+    &english,
+  )
+  .unwrap();
+  translator.set_use_isolating(false);
+
+  let mut args = FluentArgs::new();
+  args.set("name", "Alex");
+
+  let greetings = translator.translate_all(TestKey("greeting"), Some(&args));
+
+  assert_eq!(greetings.len(), 1);
+  assert_eq!(greetings["en-US"], "Hello Alex");
+  assert!(translator
+    .translate_all(TestKey("missing"), None)
+    .is_empty());
+}
@@ -0,0 +1 @@
+hello_with_arguments = Hello { $name }!
@@ -0,0 +1,3 @@
+login =
+    .placeholder = Email
+    .aria-label = Login form
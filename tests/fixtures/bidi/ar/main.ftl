@@ -0,0 +1 @@
+welcome = مرحبا { $name }
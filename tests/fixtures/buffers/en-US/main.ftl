@@ -0,0 +1,5 @@
+home = Home
+settings = Settings
+greeting = Hello { $name }
+login =
+    .title = Log in
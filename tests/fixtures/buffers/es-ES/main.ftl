@@ -0,0 +1 @@
+home = Inicio
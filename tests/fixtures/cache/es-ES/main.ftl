@@ -0,0 +1 @@
+first = Primero
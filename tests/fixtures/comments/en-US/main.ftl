@@ -0,0 +1,8 @@
+## Home page
+
+# Greeting shown on the home page
+# Keep it short
+hello = Hello
+goodbye = Goodbye
+# Shown when leaving
+farewell = Farewell
@@ -0,0 +1,3 @@
+# Saludo
+hello = Hola
+farewell = Adiós
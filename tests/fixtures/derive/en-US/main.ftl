@@ -0,0 +1,7 @@
+hello = Hello
+hello_with_arguments = Hello { $name }, you have { $unread-count } messages
+menu-save = Save
+unread = { $count ->
+    [one] One unread message
+   *[other] { $count } unread messages
+}
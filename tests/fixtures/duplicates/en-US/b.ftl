@@ -0,0 +1,2 @@
+hello = Hello from b
+goodbye = Goodbye from b
@@ -0,0 +1 @@
+download = Download for { PLATFORM() }
@@ -0,0 +1 @@
+goodbye = Adiós
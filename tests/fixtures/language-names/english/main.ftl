@@ -0,0 +1,4 @@
+unread = { $count ->
+    [one] One message
+   *[other] { $count } messages
+}
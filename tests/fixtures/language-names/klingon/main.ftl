@@ -0,0 +1 @@
+unread = { $count } QIn
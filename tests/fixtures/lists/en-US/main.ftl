@@ -0,0 +1,3 @@
+choices = Pick { LIST($first, $second, $third, type: "disjunction") }
+numbers = { LIST(NUMBER($first), NUMBER($second)) }
+years = { LIST($first, $second) }
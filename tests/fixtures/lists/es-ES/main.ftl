@@ -0,0 +1,2 @@
+choices = Elige { LIST($first, $second, $third) }
+numbers = { LIST(NUMBER($first), NUMBER($second)) }
@@ -0,0 +1,3 @@
+about = About us
+login = Log in
+    .title = Log in to continue
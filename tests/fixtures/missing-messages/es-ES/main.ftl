@@ -0,0 +1,2 @@
+about = Sobre { -brand }
+login = Entrar
@@ -0,0 +1 @@
+about = { -brand-name } by Acme
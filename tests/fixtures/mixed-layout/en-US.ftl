@@ -0,0 +1 @@
+hello = Hello from the top level file
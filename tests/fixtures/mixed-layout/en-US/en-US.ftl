@@ -0,0 +1 @@
+goodbye = Goodbye from the directory
@@ -0,0 +1,9 @@
+## Pages shown when something goes wrong
+
+# Shown when title cannot be found
+not-found = Page of { -brand } not found
+    .title = { title } - { -brand }
+title = Error
+retry = { not-found.title } again
+
+-brand = Acme { title }
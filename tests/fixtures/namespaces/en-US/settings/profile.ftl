@@ -0,0 +1,4 @@
+# Profile settings, see title
+name = Name
+    .placeholder = Your { name }
+greeting = Hello { name }, see { errors-title }
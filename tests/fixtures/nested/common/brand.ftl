@@ -0,0 +1,2 @@
+app-name = Rocket
+footer = Made by Acme
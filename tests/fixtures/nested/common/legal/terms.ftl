@@ -0,0 +1 @@
+legal = All rights reserved
@@ -0,0 +1 @@
+security = Security
@@ -0,0 +1,2 @@
+hello = Hola
+footer = Hecho por Acme
@@ -0,0 +1,4 @@
+hello = Hello
+greeting = Hello { $name }
+login =
+    .title = Log in
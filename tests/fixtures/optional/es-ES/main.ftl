@@ -0,0 +1 @@
+hola = Hola
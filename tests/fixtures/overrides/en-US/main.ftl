@@ -0,0 +1,3 @@
+-brand = Acme
+welcome = Welcome
+goodbye = Goodbye
@@ -0,0 +1,11 @@
+unread = { $count ->
+    [one] One
+   *[other] Many
+}
+title =
+    .label = { $count ->
+        [one] One
+        [few] Few
+        [many] Many
+       *[other] Other
+    }
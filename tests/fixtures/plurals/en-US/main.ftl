@@ -0,0 +1,4 @@
+unread = { $count ->
+    [one] One unread message
+   *[other] { $count } unread messages
+}
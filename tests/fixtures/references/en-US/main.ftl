@@ -0,0 +1,6 @@
+app-name = Translate
+welcome = Welcome to { app-name }
+about = { about-title }
+about-title = About { app-name }
+old-banner = { old-banner-title }
+old-banner-title = Try the new version
@@ -0,0 +1,13 @@
+relative-time-now = just now
+relative-time-minute-past = { $count ->
+    [one] a minute ago
+   *[other] { $count } minutes ago
+}
+relative-time-day-future = { $count ->
+    [one] tomorrow
+   *[other] in { $count } days
+}
+duration-hour = { $count ->
+    [one] an hour
+   *[other] { $count } hours
+}
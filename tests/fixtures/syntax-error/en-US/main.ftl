@@ -0,0 +1,3 @@
+hello = Hello
+broken = { $name ]
+goodbye = Goodbye
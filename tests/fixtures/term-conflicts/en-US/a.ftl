@@ -0,0 +1,2 @@
+-brand = Acme
+hello = Hello from a
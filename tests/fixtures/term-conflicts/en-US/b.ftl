@@ -0,0 +1,4 @@
+-brand = Other
+brand = Brand
+hello = Hello from b
+goodbye = Goodbye
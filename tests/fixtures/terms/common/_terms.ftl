@@ -0,0 +1 @@
+-company = Acme
@@ -0,0 +1 @@
+-brand-name = Rocket
@@ -0,0 +1,2 @@
+-brand-name = Overridden
+about = { -brand-name } by { -company }
@@ -0,0 +1,3 @@
+about = { -brand } by { company }
+title =
+    .label = { OTHER() }
@@ -0,0 +1,4 @@
+hello = Hello
+login = Log in
+    .placeholder = Email
+    .title = Login
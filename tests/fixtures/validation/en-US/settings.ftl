@@ -0,0 +1 @@
+theme = Theme
@@ -0,0 +1,3 @@
+hello = Hola
+login = Entrar
+    .placeholder = Correo
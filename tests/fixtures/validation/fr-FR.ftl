@@ -0,0 +1,5 @@
+hello = Bonjour
+login = Connexion
+    .placeholder = E-mail
+    .title = Connexion
+theme = Thème
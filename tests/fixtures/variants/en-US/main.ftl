@@ -0,0 +1,4 @@
+greeting = Hello
+    .formal = Good day
+login-button =
+    .label = Log in
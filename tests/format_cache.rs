@@ -0,0 +1,114 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use translate::{FormatCacheStats, Translator};
+
+fn translator(capacity: usize) -> Translator<TestLanguage, TestKey> {
+  Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("cache"))
+    .default_language(&TestLanguage("en-US"))
+    .with_format_cache(capacity)
+    .build()
+    .unwrap()
+}
+
+fn translate(translator: &Translator<TestLanguage, TestKey>, key: &'static str) -> String {
+  translator
+    .try_translate(&TestLanguage("en-US"), TestKey(key))
+    .unwrap()
+    .into_owned()
+}
+
+#[test]
+fn format_cache_is_disabled_by_default() {
+  assert_eq!(translator(0).format_cache_stats(), None);
+}
+
+#[test]
+fn format_cache_counts_hits_and_misses() {
+  let translator = translator(8);
+
+  assert_eq!(translate(&translator, "first"), "First");
+  assert_eq!(translate(&translator, "first"), "First");
+  assert_eq!(translate(&translator, "second"), "Second");
+
+  assert_eq!(
+    translator.format_cache_stats(),
+    Some(FormatCacheStats {
+      hits: 1,
+      misses: 2,
+      entries: 2,
+    })
+  );
+}
+
+#[test]
+fn least_recently_used_messages_are_evicted() {
+  let translator = translator(2);
+
+  translate(&translator, "first");
+  translate(&translator, "second");
+  // Uses first again, so second is the least recently used when third is cached.
+  translate(&translator, "first");
+  translate(&translator, "third");
+  translate(&translator, "first");
+  translate(&translator, "second");
+
+  assert_eq!(
+    translator.format_cache_stats(),
+    Some(FormatCacheStats {
+      hits: 2,
+      misses: 4,
+      entries: 2,
+    })
+  );
+}
+
+#[test]
+fn languages_are_cached_separately() {
+  let translator = translator(1);
+
+  translate(&translator, "first");
+  assert_eq!(
+    translator
+      .try_translate(&TestLanguage("es-ES"), TestKey("first"))
+      .unwrap(),
+    "Primero"
+  );
+  translate(&translator, "first");
+
+  let stats = translator.format_cache_stats().unwrap();
+  assert_eq!((stats.hits, stats.entries), (1, 2));
+}
+
+#[test]
+fn overriding_a_message_clears_the_cache() {
+  let mut translator = translator(8);
+
+  assert_eq!(translate(&translator, "first"), "First");
+
+  translator
+    .set_override(&TestLanguage("en-US"), "first", "Updated")
+    .unwrap();
+
+  assert_eq!(translator.format_cache_stats().unwrap().entries, 0);
+  assert_eq!(translate(&translator, "first"), "Updated");
+}
+
+#[test]
+fn clearing_the_cache_keeps_its_statistics() {
+  let translator = translator(8);
+
+  translate(&translator, "first");
+  translate(&translator, "first");
+  translator.clear_format_cache();
+
+  assert_eq!(
+    translator.format_cache_stats(),
+    Some(FormatCacheStats {
+      hits: 1,
+      misses: 1,
+      entries: 0,
+    })
+  );
+}
@@ -0,0 +1,163 @@
+mod common;
+
+use common::{TestKey, TestLanguage};
+use translate::Translator;
+
+const LANGUAGES: [&str; 4] = ["en-US", "de-DE", "fr-FR", "es-ES"];
+
+fn translator(source: &str) -> Translator<TestLanguage, TestKey> {
+  let sources = LANGUAGES.map(|language| (language, source));
+  let mut translator =
+    Translator::<TestLanguage, TestKey>::from_sources(sources, &TestLanguage("en-US")).unwrap();
+  translator.set_use_isolating(false);
+  translator
+}
+
+fn format(
+  translator: &Translator<TestLanguage, TestKey>,
+  language: &'static str,
+  key: &'static str,
+  value: impl Into<f64>,
+) -> String {
+  translator
+    .translate(&TestLanguage(language), TestKey(key))
+    .add_argument("value", value.into())
+    .build()
+    .into_owned()
+}
+
+#[test]
+fn plain_number_arguments_are_not_grouped() {
+  let translator = translator("year = { $value }\n");
+
+  for language in LANGUAGES {
+    assert_eq!(format(&translator, language, "year", 2024), "2024");
+  }
+}
+
+#[test]
+fn number_uses_grouping_and_decimal_separators_of_the_language() {
+  let translator = translator("number = { NUMBER($value) }\n");
+
+  assert_eq!(
+    format(&translator, "en-US", "number", 1234567.5),
+    "1,234,567.5"
+  );
+  assert_eq!(
+    format(&translator, "de-DE", "number", 1234567.5),
+    "1.234.567,5"
+  );
+  assert_eq!(
+    format(&translator, "fr-FR", "number", 1234567.5),
+    "1\u{202f}234\u{202f}567,5"
+  );
+  assert_eq!(
+    format(&translator, "es-ES", "number", 1234567.5),
+    "1.234.567,5"
+  );
+}
+
+#[test]
+fn spanish_does_not_group_four_digit_numbers() {
+  let translator = translator("number = { NUMBER($value) }\n");
+
+  assert_eq!(format(&translator, "es-ES", "number", 1234), "1234");
+  assert_eq!(format(&translator, "es-ES", "number", 12345), "12.345");
+  assert_eq!(format(&translator, "en-US", "number", 1234), "1,234");
+}
+
+#[test]
+fn grouping_can_be_disabled() {
+  let translator = translator("number = { NUMBER($value, useGrouping: \"false\") }\n");
+
+  assert_eq!(format(&translator, "en-US", "number", 1234567), "1234567");
+}
+
+#[test]
+fn percent_multiplies_by_one_hundred() {
+  let translator = translator("percent = { NUMBER($value, style: \"percent\") }\n");
+
+  assert_eq!(format(&translator, "en-US", "percent", 0.25), "25%");
+  assert_eq!(format(&translator, "de-DE", "percent", 12.5), "1.250%");
+}
+
+#[test]
+fn currency_is_placed_by_language() {
+  let translator =
+    translator("price = { NUMBER($value, style: \"currency\", currency: \"EUR\") }\n");
+
+  assert_eq!(
+    format(&translator, "en-US", "price", 1234.5),
+    "EUR\u{a0}1,234.50"
+  );
+  assert_eq!(
+    format(&translator, "de-DE", "price", 1234.5),
+    "1.234,50\u{a0}EUR"
+  );
+  assert_eq!(
+    format(&translator, "fr-FR", "price", 1234.5),
+    "1\u{202f}234,50\u{a0}EUR"
+  );
+}
+
+#[test]
+fn fraction_digits_are_padded_and_rounded() {
+  let translator = translator(
+    "minimum = { NUMBER($value, minimumFractionDigits: 2) }\n\
+     maximum = { NUMBER($value, maximumFractionDigits: 1) }\n",
+  );
+
+  assert_eq!(format(&translator, "en-US", "minimum", 3), "3.00");
+  assert_eq!(format(&translator, "de-DE", "minimum", 3.5), "3,50");
+  assert_eq!(format(&translator, "en-US", "maximum", 1.26), "1.3");
+  assert_eq!(format(&translator, "en-US", "maximum", 2.96), "3");
+}
+
+#[test]
+fn dates_are_formatted_with_the_pattern_of_the_language() {
+  let translator = translator("date = { DATETIME(\"2024-01-05T09:30\", timeStyle: \"short\") }\n");
+  let date = |language: &'static str| {
+    translator
+      .translate(&TestLanguage(language), TestKey("date"))
+      .build()
+      .into_owned()
+  };
+
+  assert_eq!(date("en-US"), "1/5/2024, 9:30\u{202f}AM");
+  assert_eq!(date("de-DE"), "05.01.2024, 09:30");
+  assert_eq!(date("fr-FR"), "05/01/2024, 09:30");
+  assert_eq!(date("es-ES"), "5/1/2024, 09:30");
+}
+
+#[test]
+fn leap_days_are_accepted() {
+  let translator = translator("date = { DATETIME(\"2024-02-29\") }\n");
+
+  assert_eq!(
+    translator
+      .translate(&TestLanguage("en-US"), TestKey("date"))
+      .build(),
+    "2/29/2024"
+  );
+}
+
+#[test]
+fn out_of_range_dates_are_not_formatted() {
+  let invalid = [
+    "2024-13-01",
+    "2024-00-10",
+    "2024-04-31",
+    "2023-02-29",
+    "2024-01-01T24:00",
+    "2024-01-01T12:60",
+    "2024-01-01Tnoon",
+  ];
+
+  for date in invalid {
+    let translator = translator(&format!("date = {{ DATETIME(\"{date}\") }}\n"));
+
+    let message = translator.translate(&TestLanguage("en-US"), TestKey("date"));
+
+    assert_eq!(message.build(), "DATETIME()", "{date} was formatted");
+  }
+}
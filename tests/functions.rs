@@ -0,0 +1,97 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use translate::{FluentArgs, FluentValue, Translator};
+
+fn currency<'value>(positional: &[FluentValue<'value>], named: &FluentArgs) -> FluentValue<'value> {
+  let symbol = match named.get("symbol") {
+    Some(FluentValue::String(symbol)) => symbol.to_string(),
+    _ => "$".to_string(),
+  };
+
+  match positional.first() {
+    Some(FluentValue::Number(amount)) => FluentValue::from(format!("{symbol}{:.2}", amount.value)),
+    _ => FluentValue::Error,
+  }
+}
+
+fn translator() -> Translator<TestLanguage, TestKey> {
+  let mut translator = Translator::<TestLanguage, TestKey>::from_sources(
+    [
+      (
+        "en-US",
+        "price = Price: { CURRENCY($amount) }\neuros = { CURRENCY($amount, symbol: \"€\") }\n",
+      ),
+      ("es-ES", "price = Precio: { CURRENCY($amount) }\n"),
+    ],
+    &TestLanguage("en-US"),
+  )
+  .unwrap();
+  translator.set_use_isolating(false);
+  translator
+}
+
+fn price(
+  translator: &Translator<TestLanguage, TestKey>,
+  language: &'static str,
+  key: &'static str,
+) -> String {
+  translator
+    .translate(&TestLanguage(language), TestKey(key))
+    .add_argument("amount", 4.5)
+    .build()
+    .into_owned()
+}
+
+#[test]
+fn functions_receive_positional_and_named_arguments_in_every_language() {
+  let mut translator = translator();
+
+  assert!(translator.add_function("CURRENCY", currency));
+
+  assert_eq!(price(&translator, "en-US", "price"), "Price: $4.50");
+  assert_eq!(price(&translator, "en-US", "euros"), "€4.50");
+  assert_eq!(price(&translator, "es-ES", "price"), "Precio: $4.50");
+}
+
+#[test]
+fn functions_cannot_be_added_twice() {
+  let mut translator = translator();
+
+  assert!(translator.add_function("CURRENCY", currency));
+  assert!(!translator.add_function("CURRENCY", |_, _| FluentValue::from("free")));
+
+  assert_eq!(price(&translator, "en-US", "price"), "Price: $4.50");
+}
+
+#[test]
+fn functions_are_kept_when_bundles_are_rebuilt() {
+  let mut translator = translator();
+  translator.add_function("CURRENCY", currency);
+
+  translator
+    .set_override(
+      &TestLanguage("es-ES"),
+      "price",
+      "Total: { CURRENCY($amount) }",
+    )
+    .unwrap();
+
+  assert_eq!(price(&translator, "es-ES", "price"), "Total: $4.50");
+}
+
+#[test]
+fn functions_can_be_added_on_the_builder() {
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("functions"))
+    .default_language(&TestLanguage("en-US"))
+    .use_isolating(false)
+    .add_function("PLATFORM", |_, _| FluentValue::from("Linux"))
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    translator.translate_without_arguments(&TestLanguage("en-US"), TestKey("download")),
+    "Download for Linux"
+  );
+}
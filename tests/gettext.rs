@@ -0,0 +1,69 @@
+#![cfg(feature = "gettext")]
+
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use translate::{gettext_message_id, po_to_ftl, Translator, TranslatorError};
+
+#[test]
+fn gettext_message_ids_are_identifiers() {
+  assert_eq!(gettext_message_id(None, "Save changes?"), "save-changes");
+  assert_eq!(gettext_message_id(Some("menu"), "Open"), "menu-open");
+  assert_eq!(gettext_message_id(None, "%d file"), "d-file");
+  assert_eq!(gettext_message_id(None, "404"), "msg-404");
+}
+
+#[test]
+fn po_files_are_converted_into_fluent() {
+  let ftl = po_to_ftl(
+    "msgid \"Saved %s of %s {user}\"\nmsgstr \"Guardado %2$s de %1$s {user} al 100%%\"\n",
+  )
+  .unwrap();
+
+  assert_eq!(
+    ftl,
+    "saved-s-of-s-user = Guardado { $arg2 } de { $arg1 } { $user } al 100%\n"
+  );
+  assert!(matches!(
+    po_to_ftl("msgid \"Hello\"\nmsgstr \"Hola\n"),
+    Err(TranslatorError::InvalidPoFile { .. })
+  ));
+}
+
+#[test]
+fn po_files_are_loaded_with_plural_forms() {
+  let russian = TestLanguage("ru");
+  let spanish = TestLanguage("es-ES");
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("gettext"))
+    .default_language(&russian)
+    .use_isolating(false)
+    .build()
+    .unwrap();
+
+  let files = |count: i64| {
+    translator
+      .translate(&russian, TestKey("d-file"))
+      .add_argument("count", count)
+      .add_argument("arg1", count)
+      .build()
+      .into_owned()
+  };
+
+  assert_eq!(
+    translator
+      .translate(&spanish, TestKey("hello-name-s"))
+      .add_argument("name", "Alex")
+      .build(),
+    "¡Hola, Alex!"
+  );
+  assert_eq!(
+    translator.translate_without_arguments(&russian, TestKey("menu-open")),
+    "Открыть"
+  );
+  assert_eq!(files(1), "1 файл");
+  assert_eq!(files(3), "3 файла");
+  assert_eq!(files(5), "5 файлов");
+  assert!(!translator.has_message(&russian, TestKey("save")));
+  assert!(!translator.has_message(&russian, TestKey("untranslated")));
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use common::{TestKey, TestLanguage};
+use translate::Translator;
+
+fn translator() -> Translator<TestLanguage, TestKey> {
+  Translator::<TestLanguage, TestKey>::identity(&TestLanguage("en-US"))
+}
+
+#[test]
+fn keys_are_echoed_in_every_language() {
+  let translator = translator();
+
+  for language in ["en-US", "es-ES", "ja-JP"] {
+    assert_eq!(
+      translator.translate_without_arguments(&TestLanguage(language), TestKey("hello")),
+      "hello"
+    );
+  }
+  assert_eq!(
+    translator
+      .try_translate(&TestLanguage("es-ES"), TestKey("missing"))
+      .unwrap(),
+    "missing"
+  );
+}
+
+#[test]
+fn arguments_are_listed_after_the_key() {
+  let translator = translator();
+
+  let message = translator
+    .translate(&TestLanguage("es-ES"), TestKey("hello_with_arguments"))
+    .add_argument("name", "Alex")
+    .add_argument("count", 3);
+
+  assert_eq!(
+    message.build(),
+    "hello_with_arguments(count: 3, name: Alex)"
+  );
+  assert_eq!(
+    message.try_build().unwrap(),
+    "hello_with_arguments(count: 3, name: Alex)"
+  );
+}
+
+#[test]
+fn attributes_are_named_after_their_message() {
+  let translator = translator();
+
+  assert_eq!(
+    translator
+      .translate(&TestLanguage("en-US"), TestKey("login"))
+      .attribute("placeholder")
+      .build(),
+    "login.placeholder"
+  );
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use translate::Translator;
+
+#[test]
+fn message_origin_points_at_kept_definition() {
+  let english = TestLanguage("en-US");
+  let mexican_spanish = TestLanguage("es-MX");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("duplicates"), &english).unwrap();
+
+  let (hello_path, hello_line) = translator
+    .message_origin(&english, &TestKey("hello"))
+    .unwrap();
+  let (goodbye_path, goodbye_line) = translator
+    .message_origin(&mexican_spanish, &TestKey("goodbye"))
+    .unwrap();
+
+  assert!(
+    hello_path.ends_with("duplicates/en-US/a.ftl"),
+    "{hello_path:?}"
+  );
+  assert_eq!(hello_line, 1);
+  assert!(
+    goodbye_path.ends_with("duplicates/en-US/b.ftl"),
+    "{goodbye_path:?}"
+  );
+  assert_eq!(goodbye_line, 2);
+  assert!(translator
+    .message_origin(&english, &TestKey("missing"))
+    .is_none());
+}
+
+#[test]
+fn bundles_give_access_to_fluent() {
+  let english = TestLanguage("en-US");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("languages"), &english).unwrap();
+
+  let spanish_bundle = translator.bundle(&TestLanguage("es-ES")).unwrap();
+  let message = spanish_bundle.get_message("hello").unwrap();
+  let mut errors = Vec::new();
+
+  assert_eq!(
+    spanish_bundle.format_pattern(message.value().unwrap(), None, &mut errors),
+    "Hola"
+  );
+  assert!(errors.is_empty());
+  assert!(translator.bundle(&TestLanguage("ja-JP")).is_none());
+  assert!(translator.default_bundle().has_message("hello"));
+  assert_eq!(
+    translator
+      .bundles()
+      .map(|(language, _)| language)
+      .collect::<Vec<_>>(),
+    ["en-US", "es-ES"]
+  );
+}
+
+#[test]
+fn message_comments_are_read_from_the_source() {
+  let english = TestLanguage("en-US");
+  let spanish = TestLanguage("es-ES");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("comments"), &english).unwrap();
+
+  assert_eq!(
+    translator
+      .message_comment(&english, &TestKey("hello"))
+      .as_deref(),
+    Some("Greeting shown on the home page\nKeep it short")
+  );
+  assert_eq!(
+    translator
+      .message_comment(&spanish, &TestKey("hello"))
+      .as_deref(),
+    Some("Saludo")
+  );
+  // Messages without a comment use the one of the default language.
+  assert_eq!(
+    translator
+      .message_comment(&spanish, &TestKey("farewell"))
+      .as_deref(),
+    Some("Shown when leaving")
+  );
+  assert_eq!(
+    translator
+      .message_comment(&TestLanguage("ja-JP"), &TestKey("farewell"))
+      .as_deref(),
+    Some("Shown when leaving")
+  );
+  assert_eq!(
+    translator.message_comment(&english, &TestKey("goodbye")),
+    None
+  );
+}
+
+#[test]
+fn languages_and_message_keys_are_listed_in_alphabetical_order() {
+  let mut translator = Translator::<TestLanguage, TestKey>::from_sources(
+    [
+      ("fr-FR", "hello = Bonjour\n"),
+      ("en-US", "-brand = Acme\nwelcome = Welcome\nhello = Hello\n"),
+      ("es-ES", "hello = Hola\n"),
+    ],
+    &TestLanguage("en-US"),
+  )
+  .unwrap();
+
+  translator
+    .set_override(&TestLanguage("es-ES"), "goodbye", "Adiós")
+    .unwrap();
+
+  assert_eq!(
+    translator.languages().collect::<Vec<_>>(),
+    ["en-US", "es-ES", "fr-FR"]
+  );
+  assert!(translator.has_language("es-ES"));
+  assert!(!translator.has_language("es-MX"));
+  assert_eq!(
+    translator.message_keys("en-US").collect::<Vec<_>>(),
+    ["hello", "welcome"]
+  );
+  assert_eq!(
+    translator.message_keys("es-ES").collect::<Vec<_>>(),
+    ["goodbye", "hello"]
+  );
+  assert_eq!(translator.message_keys("ja-JP").count(), 0);
+}
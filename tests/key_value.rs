@@ -0,0 +1,106 @@
+#![cfg(feature = "serde")]
+
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use translate::{key_value_to_ftl, KeyValueFormat, Translator, TranslatorError};
+
+#[test]
+fn formats_are_detected_from_the_extension() {
+  assert_eq!(
+    KeyValueFormat::from_file_name("en-US.json"),
+    Some(KeyValueFormat::Json)
+  );
+  assert_eq!(
+    KeyValueFormat::from_file_name("main.yml"),
+    Some(KeyValueFormat::Yaml)
+  );
+  assert_eq!(
+    KeyValueFormat::from_file_name("main.toml"),
+    Some(KeyValueFormat::Toml)
+  );
+  assert_eq!(KeyValueFormat::from_file_name("main.ftl"), None);
+}
+
+#[test]
+fn nested_and_dotted_keys_are_joined_and_placeholders_converted() {
+  let ftl = key_value_to_ftl(
+    r#"{ "menu": { "save": "Save {name}", "file.open": "Open {{ file }}" } }"#,
+    KeyValueFormat::Json,
+  )
+  .unwrap();
+
+  assert_eq!(
+    ftl,
+    "menu-file-open = Open { $file }\nmenu-save = Save { $name }\n"
+  );
+}
+
+#[test]
+fn fluent_syntax_in_values_is_escaped() {
+  let ftl = key_value_to_ftl(
+    "[values]\nbraces = \"Sets look like {1, 2}\"\nlist = \"* first\"\nempty = \"\"\n",
+    KeyValueFormat::Toml,
+  )
+  .unwrap();
+
+  let translator =
+    Translator::<TestLanguage, TestKey>::from_sources([("en-US", ftl)], &TestLanguage("en-US"))
+      .unwrap();
+  let english = TestLanguage("en-US");
+
+  assert_eq!(
+    translator.translate_without_arguments(&english, TestKey("values-braces")),
+    "Sets look like {1, 2}"
+  );
+  assert_eq!(
+    translator.translate_without_arguments(&english, TestKey("values-list")),
+    "* first"
+  );
+  assert_eq!(
+    translator.translate_without_arguments(&english, TestKey("values-empty")),
+    ""
+  );
+}
+
+#[test]
+fn files_which_are_not_maps_are_rejected() {
+  for (content, format) in [
+    ("[\"hello\"]", KeyValueFormat::Json),
+    ("{ \"hello\": ", KeyValueFormat::Json),
+    ("- hello", KeyValueFormat::Yaml),
+  ] {
+    assert!(matches!(
+      key_value_to_ftl(content, format),
+      Err(TranslatorError::InvalidKeyValueFile { format: error_format, .. }) if error_format == format
+    ));
+  }
+}
+
+#[test]
+fn key_value_files_are_loaded_next_to_fluent_files() {
+  let mut translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("key-value"), &TestLanguage("en-US"))
+      .unwrap();
+  translator.set_use_isolating(false);
+
+  let hello = |language: &'static str| {
+    translator
+      .translate(&TestLanguage(language), TestKey("hello"))
+      .add_argument("name", "Alex")
+      .into_string()
+  };
+
+  assert_eq!(hello("en-US"), "Hello Alex");
+  assert_eq!(hello("es-ES"), "Hola Alex");
+  assert_eq!(hello("fr-FR"), "Bonjour Alex");
+
+  let text = |language: &'static str, key: &'static str| {
+    translator.translate_owned(&TestLanguage(language), TestKey(key))
+  };
+
+  assert_eq!(text("en-US", "menu-open-recent"), "Open recent");
+  assert_eq!(text("es-ES", "menu-save"), "Guardar");
+  assert_eq!(text("es-ES", "goodbye"), "Adiós");
+  assert_eq!(text("fr-FR", "menu-save"), "Enregistrer");
+}
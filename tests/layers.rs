@@ -0,0 +1,89 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+
+use translate::{Translator, TranslatorBuilder, TranslatorError};
+
+fn builder() -> TranslatorBuilder<TestLanguage, TestKey> {
+  Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("languages"))
+    .default_language(&TestLanguage("en-US"))
+}
+
+fn hello(translator: &Translator<TestLanguage, TestKey>, language: &'static str) -> String {
+  translator
+    .translate_without_arguments(&TestLanguage(language), TestKey("hello"))
+    .into_owned()
+}
+
+#[test]
+fn layer_overrides_messages_it_defines() {
+  let translator = builder()
+    .with_layer(&format!("{}/brand", fixture("layers")))
+    .build()
+    .unwrap();
+
+  assert_eq!(hello(&translator, "en-US"), "Howdy");
+  assert_eq!(hello(&translator, "es-ES"), "Hola");
+}
+
+#[test]
+fn later_layers_win() {
+  for lazy_loading in [false, true] {
+    let translator = builder()
+      .with_layer(&format!("{}/brand", fixture("layers")))
+      .with_layer(&format!("{}/dev", fixture("layers")))
+      .lazy_loading(lazy_loading)
+      .build()
+      .unwrap();
+
+    assert_eq!(hello(&translator, "en-US"), "Hey");
+    assert_eq!(hello(&translator, "es-ES"), "Buenas");
+  }
+}
+
+#[test]
+fn missing_layer_directory_fails() {
+  let result = builder()
+    .with_layer(&format!("{}/missing", fixture("layers")))
+    .build();
+
+  assert!(matches!(
+    result,
+    Err(TranslatorError::DirectoryNotFound { path }) if path.ends_with("missing")
+  ));
+}
+
+#[test]
+fn dev_overlay_is_loaded_from_environment_variable() {
+  std::env::set_var("TRANSLATE_TEST_DEV_OVERLAY", fixture("layers") + "/dev");
+
+  let translator = builder()
+    .with_layer(&format!("{}/brand", fixture("layers")))
+    .with_dev_overlay_from_env("TRANSLATE_TEST_DEV_OVERLAY")
+    .build()
+    .unwrap();
+
+  assert_eq!(hello(&translator, "en-US"), "Hey");
+}
+
+#[test]
+fn dev_overlay_is_ignored_when_unset_or_missing() {
+  std::env::remove_var("TRANSLATE_TEST_UNSET_OVERLAY");
+  std::env::set_var(
+    "TRANSLATE_TEST_MISSING_OVERLAY",
+    fixture("layers") + "/missing",
+  );
+
+  let unset = builder()
+    .with_dev_overlay_from_env("TRANSLATE_TEST_UNSET_OVERLAY")
+    .build()
+    .unwrap();
+  let missing = builder()
+    .with_dev_overlay_from_env("TRANSLATE_TEST_MISSING_OVERLAY")
+    .build()
+    .unwrap();
+
+  assert_eq!(hello(&unset, "en-US"), "Hello");
+  assert_eq!(hello(&missing, "en-US"), "Hello");
+}
@@ -0,0 +1,122 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use std::borrow::Cow;
+
+use translate::{ListStyle, Translator};
+
+#[test]
+fn lists_are_joined_by_style_and_language() {
+  let english = TestLanguage("en-US");
+  let spanish = TestLanguage("es-ES");
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("lists"))
+    .default_language(&english)
+    .use_isolating(false)
+    .build()
+    .unwrap();
+
+  let items = [Cow::from("red"), Cow::from("green"), Cow::from("blue")];
+  let choices = |language: &TestLanguage| {
+    translator
+      .translate_with_args(
+        language,
+        TestKey("choices"),
+        [("first", "red"), ("second", "green"), ("third", "blue")],
+      )
+      .into_string()
+  };
+
+  assert_eq!(
+    translator.format_list_with_style(&english, &items, ListStyle::Or),
+    "red, green, or blue"
+  );
+  assert_eq!(
+    translator.format_list(&spanish, &items),
+    "red, green y blue"
+  );
+  assert_eq!(choices(&english), "Pick red, green, or blue");
+  assert_eq!(choices(&spanish), "Elige red, green y blue");
+}
+
+#[test]
+fn lists_follow_the_patterns_of_each_language() {
+  let translator =
+    Translator::<TestLanguage, TestKey>::from_sources([("en-US", "")], &TestLanguage("en-US"))
+      .unwrap();
+  let pair = [Cow::from("A"), Cow::from("B")];
+  let items = [
+    Cow::from("A"),
+    Cow::from("B"),
+    Cow::from("C"),
+    Cow::from("D"),
+  ];
+
+  assert_eq!(translator.format_list(&TestLanguage("en-US"), &[]), "");
+  assert_eq!(
+    translator.format_list(&TestLanguage("en-US"), &items[..1]),
+    "A"
+  );
+  assert_eq!(
+    translator.format_list(&TestLanguage("en-US"), &pair),
+    "A and B"
+  );
+  assert_eq!(
+    translator.format_list(&TestLanguage("en-GB"), &items),
+    "A, B, C and D"
+  );
+  assert_eq!(
+    translator.format_list(&TestLanguage("zh"), &items),
+    "A、B、C和D"
+  );
+  assert_eq!(
+    translator.format_list_with_style(&TestLanguage("fr-FR"), &pair, ListStyle::Or),
+    "A ou B"
+  );
+  assert_eq!(
+    translator.format_list(&TestLanguage("xx"), &items),
+    "A, B, C, D"
+  );
+}
+
+#[test]
+fn lists_use_the_language_parser() {
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("lists"))
+    .default_language(&TestLanguage("en-US"))
+    .with_language_parser(|name| match name {
+      "spanish" => "es".parse().ok(),
+      name => name.parse().ok(),
+    })
+    .build()
+    .unwrap();
+  let items = [Cow::from("A"), Cow::from("B"), Cow::from("C")];
+
+  assert_eq!(
+    translator.format_list(&TestLanguage("spanish"), &items),
+    "A, B y C"
+  );
+}
+
+#[test]
+fn numbers_in_lists_are_formatted_like_outside_of_them() {
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("lists"))
+    .default_language(&TestLanguage("en-US"))
+    .use_isolating(false)
+    .build()
+    .unwrap();
+  let format = |language: &'static str, key: &'static str| {
+    translator
+      .translate_with_args(
+        &TestLanguage(language),
+        TestKey(key),
+        [("first", 1234.5), ("second", 20000.0)],
+      )
+      .into_string()
+  };
+
+  assert_eq!(format("en-US", "numbers"), "1,234.5 and 20,000");
+  assert_eq!(format("es-ES", "numbers"), "1234,5 y 20.000");
+  assert_eq!(format("en-US", "years"), "1234.5 and 20000");
+}
@@ -0,0 +1,443 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use std::{error::Error, path::Path};
+
+use translate::{ConflictPolicy, DefinitionConflict, LoadError, Translator, TranslatorError};
+
+#[test]
+fn duplicated_message_resolves_to_first_file_by_name() {
+  let english = TestLanguage("en-US");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("duplicates"), &english).unwrap();
+
+  let hello = translator.translate_without_arguments(&english, TestKey("hello"));
+  let goodbye = translator.translate_without_arguments(&english, TestKey("goodbye"));
+
+  assert_eq!(hello, "Hello from a");
+  assert_eq!(goodbye, "Goodbye from b");
+}
+
+#[test]
+fn conflicts_report_kept_and_ignored_files() {
+  let english = TestLanguage("en-US");
+  let keep_first =
+    Translator::<TestLanguage, TestKey>::new(&fixture("duplicates"), &english).unwrap();
+  let keep_last = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("duplicates"))
+    .default_language(&english)
+    .with_conflict_policy(ConflictPolicy::KeepLast)
+    .build()
+    .unwrap();
+
+  let conflict = |kept_file: &str, ignored_file: &str| DefinitionConflict {
+    language: "en-US".to_string(),
+    id: "hello".to_string(),
+    kept_file: kept_file.to_string(),
+    ignored_files: vec![ignored_file.to_string()],
+  };
+
+  assert_eq!(keep_first.conflicts(), [conflict("a.ftl", "b.ftl")]);
+  assert_eq!(keep_last.conflicts(), [conflict("b.ftl", "a.ftl")]);
+}
+
+#[test]
+fn stray_files_are_skipped_and_both_layouts_are_merged() {
+  let english = TestLanguage("en-US");
+  let spanish = TestLanguage("es-ES");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("mixed-layout"), &english).unwrap();
+
+  assert_eq!(
+    translator.translate_without_arguments(&english, TestKey("hello")),
+    "Hello from the top level file"
+  );
+  assert_eq!(
+    translator.translate_without_arguments(&english, TestKey("goodbye")),
+    "Goodbye from the directory"
+  );
+  assert_eq!(
+    translator.translate_without_arguments(&english, TestKey("extra")),
+    "Extra"
+  );
+  assert_eq!(
+    translator.translate_without_arguments(&spanish, TestKey("hello")),
+    "Hola"
+  );
+}
+
+#[test]
+fn language_files_named_after_their_language_are_located() {
+  for lazy_loading in [false, true] {
+    let english = TestLanguage("en-US");
+    let spanish = TestLanguage("es-ES");
+    let translator = Translator::<TestLanguage, TestKey>::builder()
+      .directory(&fixture("mixed-layout"))
+      .default_language(&english)
+      .lazy_loading(lazy_loading)
+      .build()
+      .unwrap();
+    let origin = |language: &TestLanguage, key: &'static str| {
+      let (path, _) = translator.message_origin(language, &TestKey(key)).unwrap();
+      path
+        .strip_prefix(fixture("mixed-layout"))
+        .unwrap()
+        .to_path_buf()
+    };
+
+    assert_eq!(origin(&english, "hello"), Path::new("en-US.ftl"));
+    assert_eq!(origin(&english, "goodbye"), Path::new("en-US/en-US.ftl"));
+    assert_eq!(origin(&english, "extra"), Path::new("en-US/extra.ftl"));
+    assert_eq!(origin(&spanish, "hello"), Path::new("es-ES/es-ES.ftl"));
+  }
+}
+
+#[test]
+fn missing_or_invalid_directories_fail_with_their_path() {
+  let english = TestLanguage("en-US");
+
+  let missing = Translator::<TestLanguage, TestKey>::new(&fixture("missing"), &english);
+  let file = Translator::<TestLanguage, TestKey>::new(&fixture("mixed-layout/en-US.ftl"), &english);
+
+  assert!(matches!(
+    missing,
+    Err(TranslatorError::DirectoryNotFound { path }) if path == fixture("missing")
+  ));
+  assert!(matches!(
+    file,
+    Err(TranslatorError::ReadDirError { directory_path, .. }) if directory_path == fixture("mixed-layout/en-US.ftl")
+  ));
+}
+
+fn named_languages_translator() -> Translator<TestLanguage, TestKey> {
+  Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("language-names"))
+    .default_language(&TestLanguage("english"))
+    .with_language_parser(|name| match name {
+      "english" => "en-US".parse().ok(),
+      "russian" => "ru".parse().ok(),
+      _ => None,
+    })
+    .build()
+    .unwrap()
+}
+
+#[test]
+fn languages_the_parser_rejects_are_skipped() {
+  let translator = named_languages_translator();
+
+  assert!(translator.bundle(&TestLanguage("english")).is_some());
+  assert!(translator.bundle(&TestLanguage("russian")).is_some());
+  assert!(translator.bundle(&TestLanguage("klingon")).is_none());
+}
+
+#[test]
+fn parsed_languages_are_looked_up_by_name_with_the_parsed_plural_rules() {
+  let translator = named_languages_translator();
+  let unread = |language: &'static str, count: u32| {
+    translator
+      .translate(&TestLanguage(language), TestKey("unread"))
+      .add_argument("count", count)
+      .build()
+      .replace(['\u{2068}', '\u{2069}'], "")
+  };
+
+  assert_eq!(unread("english", 1), "One message");
+  assert_eq!(unread("english", 3), "3 messages");
+  assert_eq!(unread("russian", 1), "1 сообщение");
+  assert_eq!(unread("russian", 3), "3 сообщения");
+  assert_eq!(unread("russian", 5), "5 сообщений");
+}
+
+#[test]
+fn keep_last_policy_lets_later_files_override_but_not_common_files() {
+  let english = TestLanguage("en-US");
+  let keep_last = |directory: &str| {
+    Translator::<TestLanguage, TestKey>::builder()
+      .directory(&fixture(directory))
+      .default_language(&english)
+      .with_conflict_policy(ConflictPolicy::KeepLast)
+      .build()
+      .unwrap()
+  };
+
+  let duplicates = keep_last("duplicates");
+  let nested = keep_last("nested");
+
+  assert_eq!(
+    duplicates.translate_without_arguments(&english, TestKey("hello")),
+    "Hello from b"
+  );
+  assert_eq!(
+    nested.translate_without_arguments(&TestLanguage("es-ES"), TestKey("footer")),
+    "Hecho por Acme"
+  );
+  assert_eq!(
+    nested.translate_without_arguments(&english, TestKey("footer")),
+    "Made by Acme"
+  );
+}
+
+#[test]
+fn conflict_policy_error_reports_ignored_messages_and_terms() {
+  let english = TestLanguage("en-US");
+  let result = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("term-conflicts"))
+    .default_language(&english)
+    .with_conflict_policy(ConflictPolicy::Error)
+    .build();
+
+  let Err(TranslatorError::LoadErrors(errors)) = result else {
+    panic!("conflicts were not reported");
+  };
+
+  let ids: Vec<&str> = errors
+    .iter()
+    .filter_map(|error| match error {
+      LoadError::Conflict { id, file_name, .. } if file_name == "b.ftl" => Some(id.as_str()),
+      _ => None,
+    })
+    .collect();
+
+  // Messages and terms share names in a bundle, so the `brand` message is ignored too.
+  assert_eq!(ids, ["-brand", "brand", "hello"]);
+}
+
+#[test]
+fn term_files_are_loaded_first_and_shared_terms_resolve() {
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("terms"))
+    .default_language(&english)
+    .use_isolating(false)
+    .build()
+    .unwrap();
+
+  let about = translator.translate_without_arguments(&english, TestKey("about"));
+
+  assert_eq!(about, "Rocket by Acme");
+}
+
+#[test]
+fn missing_term_fails_in_strict_mode() {
+  let english = TestLanguage("en-US");
+  let result = Translator::<TestLanguage, TestKey>::new_strict(&fixture("missing-term"), &english);
+
+  let Err(TranslatorError::LoadErrors(errors)) = result else {
+    panic!("expected load errors");
+  };
+
+  assert_eq!(
+    errors,
+    [LoadError::MissingTerm {
+      language: "en-US".to_string(),
+      file_name: "main.ftl".to_string(),
+      key: "about".to_string(),
+      term: "-brand-name".to_string(),
+    }]
+  );
+}
+
+#[test]
+fn lazy_loading_loads_languages_on_first_use() {
+  let english = TestLanguage("en-US");
+  let spanish = TestLanguage("es-ES");
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("languages"))
+    .default_language(&english)
+    .lazy_loading(true)
+    .build()
+    .unwrap();
+
+  assert!(translator.has_language("es-ES"));
+  assert_eq!(
+    translator.translate_without_arguments(&spanish, TestKey("hello")),
+    "Hola"
+  );
+}
+
+#[test]
+fn stats_report_loaded_messages_and_resources() {
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, TestKey>::new(&fixture("terms"), &english).unwrap();
+
+  let stats = translator.stats();
+  let english_stats = &stats.languages[0];
+
+  assert_eq!(english_stats.language, "en-US");
+  assert!(english_stats.loaded);
+  assert_eq!(english_stats.messages, 1);
+  assert_eq!(english_stats.resources, 3);
+  assert_eq!(stats.resources, 3);
+  assert_eq!(stats.source_bytes, english_stats.source_bytes);
+  assert!(stats.approximate_bytes > stats.source_bytes);
+}
+
+#[test]
+fn single_file_per_language_layout_is_detected() {
+  let english = TestLanguage("en-US");
+  let spanish = TestLanguage("es-ES");
+  let translator = Translator::<TestLanguage, TestKey>::new(&fixture("flat"), &english).unwrap();
+
+  assert_eq!(
+    translator.languages().collect::<Vec<_>>(),
+    ["en-US", "es-ES"]
+  );
+  assert_eq!(
+    translator.translate_without_arguments(&spanish, TestKey("hello")),
+    "Hola"
+  );
+}
+
+#[test]
+fn syntax_error_reports_position_and_parser_source() {
+  let english = TestLanguage("en-US");
+  let result = Translator::<TestLanguage, TestKey>::new_strict(&fixture("syntax-error"), &english);
+
+  let Err(TranslatorError::LoadErrors(errors)) = result else {
+    panic!("expected load errors");
+  };
+  let [LoadError::Parse {
+    file_name, line, ..
+  }] = errors.as_slice()
+  else {
+    panic!("expected a single syntax error, got {errors:?}");
+  };
+
+  assert_eq!(file_name, "main.ftl");
+  assert_eq!(*line, 2);
+  assert!(errors[0].source().is_some());
+}
+
+#[test]
+fn language_filter_skips_languages_but_keeps_default() {
+  let english = TestLanguage("en-US");
+  let only_spanish = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("languages"))
+    .default_language(&english)
+    .languages(&["es-ES"])
+    .build()
+    .unwrap();
+  let without_spanish = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("languages"))
+    .default_language(&english)
+    .skip_languages(&["es-ES"])
+    .lazy_loading(true)
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    only_spanish.languages().collect::<Vec<_>>(),
+    ["en-US", "es-ES"]
+  );
+  assert_eq!(without_spanish.languages().collect::<Vec<_>>(), ["en-US"]);
+}
+
+#[test]
+fn languages_are_added_and_removed_at_runtime() {
+  let english = TestLanguage("en-US");
+  let spanish = TestLanguage("es-ES");
+  let french = TestLanguage("fr-FR");
+  let mut translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("arguments"), &english).unwrap();
+
+  translator
+    .add_language(&french, ["hello_with_arguments = Bonjour!\n"])
+    .unwrap();
+  translator
+    .add_language_from_directory(&spanish, fixture("languages") + "/es-ES")
+    .unwrap();
+
+  let french_hello =
+    translator.translate_without_arguments(&french, TestKey("hello_with_arguments"));
+  let spanish_hello = translator.translate_without_arguments(&spanish, TestKey("hello"));
+
+  assert_eq!(french_hello, "Bonjour!");
+  assert_eq!(spanish_hello, "Hola");
+  assert!(translator.remove_language(&french).unwrap());
+  assert!(!translator.remove_language(&french).unwrap());
+  assert!(translator.bundle(&french).is_none());
+  assert!(matches!(
+    translator.remove_language(&english),
+    Err(TranslatorError::DefaultLanguage { .. })
+  ));
+}
+
+#[test]
+fn replacing_the_default_language_regenerates_pseudo_languages() {
+  let english = TestLanguage("en-US");
+  let pseudo = TestLanguage("en-XA");
+  let mut translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("languages"), &english).unwrap();
+
+  translator.enable_pseudo("en-XA").unwrap();
+  let before = translator
+    .translate_without_arguments(&pseudo, TestKey("hello"))
+    .into_owned();
+
+  translator
+    .add_language(&english, ["hello = Goodbye\n"])
+    .unwrap();
+  let after = translator.translate_without_arguments(&pseudo, TestKey("hello"));
+
+  assert_ne!(after, before);
+  assert!(after.starts_with('[') && after.contains('ḓ'));
+}
+
+#[test]
+fn strict_mode_reports_redefined_messages_which_new_skips() {
+  let english = TestLanguage("en-US");
+
+  assert!(Translator::<TestLanguage, TestKey>::new(&fixture("duplicates"), &english).is_ok());
+  assert!(Translator::<TestLanguage, TestKey>::new_strict(&fixture("languages"), &english).is_ok());
+
+  let result = Translator::<TestLanguage, TestKey>::new_strict(&fixture("duplicates"), &english);
+
+  let Err(TranslatorError::LoadErrors(errors)) = result else {
+    panic!("expected load errors");
+  };
+
+  assert_eq!(
+    errors,
+    [LoadError::Conflict {
+      language: "en-US".to_string(),
+      file_name: "b.ftl".to_string(),
+      id: "hello".to_string(),
+    }]
+  );
+}
+
+#[test]
+fn strict_builder_option_fails_like_new_strict() {
+  let english = TestLanguage("en-US");
+  let strict = |strict: bool| {
+    Translator::<TestLanguage, TestKey>::builder()
+      .directory(&fixture("syntax-error"))
+      .default_language(&english)
+      .strict(strict)
+      .build()
+  };
+
+  assert!(strict(false).is_ok());
+  assert!(matches!(
+    strict(true),
+    Err(TranslatorError::LoadErrors(errors)) if matches!(errors.as_slice(), [LoadError::Parse { .. }])
+  ));
+}
+
+#[test]
+fn file_filter_applies_to_top_level_files() {
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("mixed-layout"))
+    .default_language(&english)
+    .with_file_filter(|file_name| file_name == "extra.ftl")
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    translator.translate_without_arguments(&english, TestKey("extra")),
+    "Extra"
+  );
+  assert_eq!(translator.translate_opt(&english, TestKey("hello")), None);
+}
@@ -0,0 +1,117 @@
+#![cfg(feature = "tracing")]
+
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use std::{
+  fmt::Debug,
+  sync::{Arc, Mutex},
+};
+
+use tracing::{
+  field::{Field, Visit},
+  span::{Attributes, Id, Record},
+  Event, Level, Metadata, Subscriber,
+};
+use translate::{LogLevel, Translator};
+
+/// Remembers the level and message of every event emitted by the crate.
+#[derive(Clone, Default)]
+struct Recorder {
+  events: Arc<Mutex<Vec<(Level, String)>>>,
+}
+
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+  fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+    if field.name() == "message" {
+      self.0 = format!("{value:?}");
+    }
+  }
+}
+
+impl Subscriber for Recorder {
+  fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+    metadata.target().starts_with("translate")
+  }
+
+  fn new_span(&self, _span: &Attributes<'_>) -> Id {
+    Id::from_u64(1)
+  }
+
+  fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+  fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+  fn event(&self, event: &Event<'_>) {
+    let mut visitor = MessageVisitor(String::new());
+    event.record(&mut visitor);
+
+    self
+      .events
+      .lock()
+      .unwrap()
+      .push((*event.metadata().level(), visitor.0));
+  }
+
+  fn enter(&self, _span: &Id) {}
+
+  fn exit(&self, _span: &Id) {}
+}
+
+fn missing_key_events(miss_level: Option<Level>) -> Vec<(Level, String)> {
+  let recorder = Recorder::default();
+  let english = TestLanguage("en-US");
+
+  tracing::subscriber::with_default(recorder.clone(), || {
+    let mut builder = Translator::<TestLanguage, TestKey>::builder()
+      .directory(&fixture("languages"))
+      .default_language(&english);
+
+    if let Some(miss_level) = miss_level {
+      builder = builder.with_miss_level(miss_level);
+    }
+
+    let translator = builder.build().unwrap();
+    recorder.events.lock().unwrap().clear();
+
+    translator.translate_without_arguments(&english, TestKey("missing"));
+  });
+
+  let events = recorder.events.lock().unwrap().clone();
+
+  events
+    .into_iter()
+    .filter(|(_, message)| message.contains("missing"))
+    .collect()
+}
+
+#[test]
+fn missing_keys_are_logged_as_warnings_by_default() {
+  let events = missing_key_events(None);
+
+  assert!(!events.is_empty());
+  assert!(
+    events.iter().all(|(level, _)| *level == Level::WARN),
+    "{events:?}"
+  );
+}
+
+#[test]
+fn missing_keys_are_logged_at_the_configured_level() {
+  let events = missing_key_events(Some(Level::DEBUG));
+
+  assert!(!events.is_empty());
+  assert!(
+    events.iter().all(|(level, _)| *level == Level::DEBUG),
+    "{events:?}"
+  );
+}
+
+#[test]
+fn tracing_levels_convert_into_log_levels() {
+  assert_eq!(LogLevel::from(Level::ERROR), LogLevel::Error);
+  assert_eq!(LogLevel::from(Level::INFO), LogLevel::Info);
+  assert_eq!(LogLevel::from(Level::TRACE), LogLevel::Trace);
+}
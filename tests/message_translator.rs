@@ -0,0 +1,513 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use std::borrow::Cow;
+
+use translate::{
+  FluentArgs, MessageTranslator, NumberOptions, NumberStyle, TranslationError, TranslationKey,
+  Translator, UnknownArgumentPolicy,
+};
+
+#[test]
+fn variant_is_preferred_when_present() {
+  let english = TestLanguage("en-US");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("variants"), &english).unwrap();
+
+  let message = translator
+    .translate(&english, TestKey("greeting"))
+    .variant("formal");
+
+  assert_eq!(message.build(), "Good day");
+}
+
+#[test]
+fn variant_falls_back_to_base_message_when_absent() {
+  let english = TestLanguage("en-US");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("variants"), &english).unwrap();
+
+  let message = translator
+    .translate(&english, TestKey("greeting"))
+    .variant("casual");
+
+  assert_eq!(message.build(), "Hello");
+}
+
+#[test]
+fn fallback_attribute_is_used_when_message_has_no_value() {
+  let english = TestLanguage("en-US");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("variants"), &english).unwrap();
+
+  let login = translator
+    .translate(&english, TestKey("login-button"))
+    .or_attribute("label");
+  let greeting = translator
+    .translate(&english, TestKey("greeting"))
+    .or_attribute("formal");
+
+  assert_eq!(login.build(), "Log in");
+  assert_eq!(greeting.build(), "Hello");
+}
+
+fn greeting<'bundle, 'name>(
+  translator: &'bundle Translator<TestLanguage, TestKey>,
+  name: &'name str,
+) -> MessageTranslator<'bundle, 'name, TestKey> {
+  translator
+    .translate(&TestLanguage("en-US"), TestKey("hello_with_arguments"))
+    .add_argument("name", name)
+}
+
+#[test]
+fn arguments_can_borrow_from_a_shorter_scope_than_the_bundle() {
+  let english = TestLanguage("en-US");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("arguments"), &english).unwrap();
+
+  let message = translator.translate(&english, TestKey("hello_with_arguments"));
+
+  let name = String::from("Alex");
+  let message = message.add_argument("name", name.as_str());
+
+  assert_eq!(message.build(), "Hello \u{2068}Alex\u{2069}!");
+  assert_eq!(
+    greeting(&translator, &name).build(),
+    "Hello \u{2068}Alex\u{2069}!"
+  );
+}
+
+#[test]
+fn global_arguments_are_used_unless_passed() {
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("arguments"))
+    .default_language(&english)
+    .use_isolating(false)
+    .with_global_arg("name", "Acme")
+    .build()
+    .unwrap();
+
+  let global = translator.translate(&english, TestKey("hello_with_arguments"));
+  let passed = translator
+    .translate(&english, TestKey("hello_with_arguments"))
+    .add_argument("name", "Alex");
+
+  assert_eq!(global.build(), "Hello Acme!");
+  assert_eq!(passed.build(), "Hello Alex!");
+}
+
+#[test]
+fn format_into_appends_to_fmt_and_io_targets() {
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("arguments"))
+    .default_language(&english)
+    .use_isolating(false)
+    .build()
+    .unwrap();
+  let mut arguments = FluentArgs::new();
+  arguments.set("name", "Alex");
+
+  let mut text = String::from("> ");
+  let mut bytes = Vec::new();
+
+  translator
+    .format_into(
+      &mut text,
+      &english,
+      TestKey("hello_with_arguments"),
+      Some(&arguments),
+    )
+    .unwrap();
+  translator
+    .format_into_io(
+      &mut bytes,
+      &english,
+      TestKey("hello_with_arguments"),
+      Some(&arguments),
+    )
+    .unwrap();
+
+  assert_eq!(text, "> Hello Alex!");
+  assert_eq!(bytes, b"Hello Alex!");
+  assert!(translator
+    .format_into(&mut text, &english, TestKey("missing"), None)
+    .is_err());
+}
+
+#[test]
+fn transform_applies_to_text_but_not_arguments() {
+  let english = TestLanguage("en-US");
+  let mut translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("arguments"))
+    .default_language(&english)
+    .use_isolating(false)
+    .with_transform(|text| Cow::Owned(text.to_uppercase()))
+    .build()
+    .unwrap();
+
+  let transformed = translator
+    .translate(&english, TestKey("hello_with_arguments"))
+    .add_argument("name", "Alex")
+    .build()
+    .into_owned();
+  translator.set_transform(None);
+  let plain = translator
+    .translate(&english, TestKey("hello_with_arguments"))
+    .add_argument("name", "Alex")
+    .build()
+    .into_owned();
+
+  assert_eq!(transformed, "HELLO Alex!");
+  assert_eq!(plain, "Hello Alex!");
+}
+
+#[test]
+fn build_lossy_keeps_text_with_missing_argument() {
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("arguments"))
+    .default_language(&english)
+    .use_isolating(false)
+    .build()
+    .unwrap();
+
+  let message = translator.translate(&english, TestKey("hello_with_arguments"));
+  let (text, errors) = message.build_lossy();
+
+  assert_eq!(text, "Hello {$name}!");
+  assert!(matches!(
+    errors.as_slice(),
+    [TranslationError::FormattingErrors { .. }]
+  ));
+}
+
+#[test]
+fn unknown_arguments_fail_with_error_policy() {
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("arguments"))
+    .default_language(&english)
+    .use_isolating(false)
+    .with_unknown_argument_policy(UnknownArgumentPolicy::Error)
+    .build()
+    .unwrap();
+
+  let known = translator
+    .translate(&english, TestKey("hello_with_arguments"))
+    .add_argument("name", "Alex");
+  let misspelled = translator
+    .translate(&english, TestKey("hello_with_arguments"))
+    .add_argument("nmae", "Alex");
+
+  assert_eq!(known.try_build().unwrap(), "Hello Alex!");
+  assert!(matches!(
+    misspelled.try_build(),
+    Err(TranslationError::UnknownArguments { arguments, .. }) if arguments == ["nmae"]
+  ));
+}
+
+struct MisspelledDefaultKey;
+
+impl TranslationKey for MisspelledDefaultKey {
+  fn as_str(&self) -> &'static str {
+    "hello_with_arguments"
+  }
+
+  fn default_args(&self) -> FluentArgs<'static> {
+    FluentArgs::from_iter([("nmae", "friend")])
+  }
+}
+
+#[test]
+fn unknown_arguments_fail_writing_into_buffers_with_error_policy() {
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, MisspelledDefaultKey>::builder()
+    .directory(&fixture("arguments"))
+    .default_language(&english)
+    .use_isolating(false)
+    .with_unknown_argument_policy(UnknownArgumentPolicy::Error)
+    .build()
+    .unwrap();
+
+  let mut translated = String::new();
+  let mut built = String::new();
+
+  assert!(!translator.translate_into(&english, MisspelledDefaultKey, &mut translated));
+  assert!(!translator
+    .translate(&english, MisspelledDefaultKey)
+    .build_into(&mut built));
+  assert!(translated.is_empty());
+  assert!(built.is_empty());
+}
+
+#[test]
+fn html_arguments_are_escaped() {
+  let english = TestLanguage("en-US");
+  let builder = || {
+    Translator::<TestLanguage, TestKey>::builder()
+      .directory(&fixture("arguments"))
+      .default_language(&english)
+      .use_isolating(false)
+  };
+  let translator = builder().build().unwrap();
+  let escaping_translator = builder().escape_html_arguments(true).build().unwrap();
+
+  let escaped = translator
+    .translate(&english, TestKey("hello_with_arguments"))
+    .add_argument_escaped("name", "<b>Tom & Jerry</b>");
+  let raw = translator
+    .translate(&english, TestKey("hello_with_arguments"))
+    .add_argument("name", "<b>Tom</b>");
+  let escaped_by_default = escaping_translator
+    .translate(&english, TestKey("hello_with_arguments"))
+    .add_argument("name", "\"Tom's\"");
+
+  assert_eq!(escaped.build(), "Hello &lt;b&gt;Tom &amp; Jerry&lt;/b&gt;!");
+  assert_eq!(raw.build(), "Hello <b>Tom</b>!");
+  assert_eq!(escaped_by_default.build(), "Hello &quot;Tom&#x27;s&quot;!");
+}
+
+struct GreetingKey;
+
+impl TranslationKey for GreetingKey {
+  fn as_str(&self) -> &'static str {
+    "hello_with_arguments"
+  }
+
+  fn default_args(&self) -> FluentArgs<'static> {
+    FluentArgs::from_iter([("name", "friend")])
+  }
+}
+
+#[test]
+fn default_arguments_of_key_apply_unless_passed() {
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, GreetingKey>::builder()
+    .directory(&fixture("arguments"))
+    .default_language(&english)
+    .use_isolating(false)
+    .build()
+    .unwrap();
+
+  let overridden = translator
+    .translate(&english, GreetingKey)
+    .add_argument("name", "Alex");
+
+  assert_eq!(
+    translator.translate(&english, GreetingKey).build(),
+    "Hello friend!"
+  );
+  assert_eq!(overridden.build(), "Hello Alex!");
+  assert_eq!(
+    translator.translate_without_arguments(&english, GreetingKey),
+    "Hello friend!"
+  );
+}
+
+#[test]
+fn default_arguments_of_key_apply_to_every_entry_point() {
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, GreetingKey>::builder()
+    .directory(&fixture("arguments"))
+    .default_language(&english)
+    .use_isolating(false)
+    .build()
+    .unwrap();
+
+  let mut into = String::new();
+  let mut formatted = String::new();
+  let mut overridden = String::new();
+  let mut arguments = FluentArgs::new();
+  arguments.set("name", "Alex");
+
+  assert!(translator.translate_into(&english, GreetingKey, &mut into));
+  translator
+    .format_into(&mut formatted, &english, GreetingKey, None)
+    .unwrap();
+  translator
+    .format_into(&mut overridden, &english, GreetingKey, Some(&arguments))
+    .unwrap();
+
+  assert_eq!(
+    translator.translate_detailed(&english, GreetingKey).text,
+    "Hello friend!"
+  );
+  assert_eq!(into, "Hello friend!");
+  assert_eq!(formatted, "Hello friend!");
+  assert_eq!(overridden, "Hello Alex!");
+  assert_eq!(
+    translator.translate_many(&english, [GreetingKey], None),
+    ["Hello friend!"]
+  );
+  assert_eq!(
+    translator.translate_many(&english, [GreetingKey], Some(&arguments)),
+    ["Hello Alex!"]
+  );
+  assert!(translator
+    .translate_all_iter(GreetingKey, None)
+    .all(|(_, text)| text == "Hello friend!"));
+}
+
+struct CartKey;
+
+impl TranslationKey for CartKey {
+  fn as_str(&self) -> &'static str {
+    "cart"
+  }
+
+  fn required_args(&self) -> &'static [&'static str] {
+    &["name"]
+  }
+
+  fn default_args(&self) -> FluentArgs<'static> {
+    FluentArgs::from_iter([("count", 1)])
+  }
+}
+
+#[test]
+fn default_arguments_are_not_reported_as_unexpected() {
+  let english = TestLanguage("en-US");
+  let sources = [("en-US", "cart = { $name } has { $count } items\n")];
+  let mut translator =
+    Translator::<TestLanguage, CartKey>::from_sources(sources, &english).unwrap();
+  translator.set_use_isolating(false);
+
+  let message = translator
+    .translate(&english, CartKey)
+    .add_argument("name", "Alex");
+  let without_name = translator.translate(&english, CartKey);
+  let extra = translator
+    .translate(&english, CartKey)
+    .add_argument("name", "Alex")
+    .add_argument("city", "Lima");
+
+  assert_eq!(message.try_build().unwrap(), "Alex has 1 items");
+  assert!(matches!(
+    without_name.try_build(),
+    Err(TranslationError::ArgumentMismatch { missing, extra, .. })
+      if missing == ["name"] && extra.is_empty()
+  ));
+  assert!(matches!(
+    extra.try_build(),
+    Err(TranslationError::ArgumentMismatch { missing, extra, .. })
+      if missing.is_empty() && extra == ["city"]
+  ));
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn derived_default_arguments_apply_unless_passed() {
+  #[derive(translate::TranslationKey)]
+  enum Keys {
+    #[translate(defaults(name = "friend"))]
+    HelloWithArguments,
+  }
+
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, Keys>::builder()
+    .directory(&fixture("arguments"))
+    .default_language(&english)
+    .use_isolating(false)
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    translator.translate_without_arguments(&english, Keys::HelloWithArguments),
+    "Hello friend!"
+  );
+}
+
+#[test]
+fn message_is_displayed_and_converted_into_string() {
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("arguments"))
+    .default_language(&english)
+    .use_isolating(false)
+    .build()
+    .unwrap();
+
+  let message = || {
+    translator
+      .translate(&english, TestKey("hello_with_arguments"))
+      .add_argument("name", "Alex")
+  };
+
+  assert_eq!(format!("<{}>", message()), "<Hello Alex!>");
+  assert_eq!(message().into_string(), "Hello Alex!");
+}
+
+#[test]
+fn owned_translations_outlive_the_translator() {
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("arguments"))
+    .default_language(&english)
+    .use_isolating(false)
+    .build()
+    .unwrap();
+  let name = String::from("Alex");
+
+  let with_arguments = translator.translate_with_args_owned(
+    &english,
+    TestKey("hello_with_arguments"),
+    [("name", name)],
+  );
+  let missing = translator.translate_owned(&english, TestKey("missing"));
+  drop(translator);
+
+  assert_eq!(with_arguments, "Hello Alex!");
+  assert!(!missing.is_empty());
+}
+
+#[test]
+fn argument_names_may_be_owned() {
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("arguments"))
+    .default_language(&english)
+    .use_isolating(false)
+    .build()
+    .unwrap();
+
+  let message = translator
+    .translate(&english, TestKey("hello_with_arguments"))
+    .add_argument(String::from("name"), String::from("Alex"));
+
+  assert_eq!(message.into_string(), "Hello Alex!");
+}
+
+#[test]
+fn number_arguments_are_formatted_with_options() {
+  let english = TestLanguage("en-US");
+  let spanish = TestLanguage("es-ES");
+  let sources = [
+    ("en-US", "price = Price: { $price }\n"),
+    ("es-ES", "price = Precio: { $price }\n"),
+  ];
+  let mut translator =
+    Translator::<TestLanguage, TestKey>::from_sources(sources, &english).unwrap();
+  translator.set_use_isolating(false);
+
+  let options = NumberOptions {
+    minimum_fraction_digits: Some(2),
+    ..Default::default()
+  };
+  let euros = NumberOptions {
+    style: NumberStyle::Currency("EUR".to_string()),
+    ..Default::default()
+  };
+  let price = |language: &TestLanguage, options: NumberOptions| {
+    translator
+      .translate(language, TestKey("price"))
+      .add_number_argument("price", 12345.5, options)
+      .into_string()
+  };
+
+  assert_eq!(price(&english, options.clone()), "Price: 12,345.50");
+  assert_eq!(price(&spanish, options), "Precio: 12.345,50");
+  assert_eq!(price(&spanish, euros), "Precio: 12.345,50\u{a0}EUR");
+}
@@ -0,0 +1,100 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use std::sync::{Arc, Mutex};
+
+use translate::{MissingMessagePolicy, Translator, TranslatorBuilder, TRANSLATION_FAILED};
+
+fn builder() -> TranslatorBuilder<TestLanguage, TestKey> {
+  Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("missing-messages"))
+    .default_language(&TestLanguage("en-US"))
+}
+
+fn translator(policy: MissingMessagePolicy) -> Translator<TestLanguage, TestKey> {
+  builder()
+    .with_missing_message_policy(policy)
+    .build()
+    .unwrap()
+}
+
+#[test]
+fn policies_choose_the_text_of_messages_which_can_not_be_translated() {
+  let spanish = TestLanguage("es-ES");
+
+  let texts = |policy| {
+    let translator = translator(policy);
+
+    [
+      translator.translate_owned(&spanish, TestKey("missing")),
+      translator.translate_owned(&spanish, TestKey("about")),
+      translator
+        .translate_attribute(&spanish, TestKey("login"), "title")
+        .into_owned(),
+    ]
+  };
+
+  assert_eq!(
+    texts(MissingMessagePolicy::FailureText),
+    [TRANSLATION_FAILED; 3]
+  );
+  assert_eq!(
+    texts(MissingMessagePolicy::Key),
+    ["missing", "about", "login.title"]
+  );
+  assert_eq!(
+    texts(MissingMessagePolicy::DefaultLanguage),
+    ["missing", "About us", "Log in to continue"]
+  );
+  assert_eq!(texts(MissingMessagePolicy::Empty), ["", "", ""]);
+}
+
+#[test]
+fn policies_do_not_change_methods_reporting_failures() {
+  let translator = translator(MissingMessagePolicy::Key);
+  let spanish = TestLanguage("es-ES");
+
+  assert_eq!(translator.translate_opt(&spanish, TestKey("missing")), None);
+  assert!(translator
+    .try_translate(&spanish, TestKey("about"))
+    .is_err());
+  assert_eq!(
+    translator
+      .translate(&spanish, TestKey("missing"))
+      .build_opt(),
+    None
+  );
+  assert_eq!(
+    translator.translate(&spanish, TestKey("missing")).build(),
+    "missing"
+  );
+}
+
+#[test]
+fn the_handler_builds_the_text_from_the_language_and_key() {
+  let missing = Arc::new(Mutex::new(Vec::new()));
+  let recorded = Arc::clone(&missing);
+
+  let translator = builder()
+    .with_missing_message_policy(MissingMessagePolicy::Empty)
+    .with_missing_message_handler(move |language, key| {
+      recorded.lock().unwrap().push(format!("{language} {key}"));
+      format!("[{key}]")
+    })
+    .build()
+    .unwrap();
+  let spanish = TestLanguage("es-ES");
+
+  assert_eq!(
+    translator.translate_without_arguments(&spanish, TestKey("missing")),
+    "[missing]"
+  );
+  assert_eq!(
+    translator.translate_attribute(&spanish, TestKey("login"), "title"),
+    "[login.title]"
+  );
+  assert_eq!(
+    *missing.lock().unwrap(),
+    ["es-ES missing", "es-ES login.title"]
+  );
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use translate::Translator;
+
+fn translator() -> Translator<TestLanguage, TestKey> {
+  Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("namespaces"))
+    .default_language(&TestLanguage("en-US"))
+    .use_isolating(false)
+    .with_file_namespaces("-")
+    .build()
+    .unwrap()
+}
+
+fn translate(translator: &Translator<TestLanguage, TestKey>, key: &'static str) -> String {
+  translator
+    .translate(&TestLanguage("en-US"), TestKey(key))
+    .into_string()
+}
+
+#[test]
+fn messages_are_prefixed_with_the_namespace_of_their_file() {
+  let translator = translator();
+
+  assert_eq!(
+    translator.message_keys("en-US").collect::<Vec<_>>(),
+    [
+      "errors-not-found",
+      "errors-retry",
+      "errors-title",
+      "settings-profile-greeting",
+      "settings-profile-name",
+    ]
+  );
+  assert!(!translator.has_message(&TestLanguage("en-US"), TestKey("title")));
+}
+
+#[test]
+fn references_inside_the_file_and_from_terms_are_namespaced() {
+  let translator = translator();
+
+  assert_eq!(
+    translate(&translator, "errors-not-found"),
+    "Page of Acme Error not found"
+  );
+  assert_eq!(
+    translate(&translator, "errors-retry"),
+    "Error - Acme Error again"
+  );
+  assert_eq!(
+    translator
+      .translate(&TestLanguage("en-US"), TestKey("errors-not-found"))
+      .attribute("title")
+      .into_string(),
+    "Error - Acme Error"
+  );
+}
+
+#[test]
+fn references_to_other_files_and_comments_are_kept() {
+  let translator = translator();
+
+  assert_eq!(
+    translate(&translator, "settings-profile-greeting"),
+    "Hello Name, see Error"
+  );
+  assert_eq!(
+    translator
+      .message_comment(&TestLanguage("en-US"), &TestKey("errors-not-found"))
+      .as_deref(),
+    Some("Shown when title cannot be found")
+  );
+}
+
+#[test]
+fn files_with_crlf_line_endings_are_namespaced() {
+  let translator = translator();
+
+  assert_eq!(
+    translator
+      .translate(&TestLanguage("en-US"), TestKey("settings-profile-name"))
+      .attribute("placeholder")
+      .into_string(),
+    "Your Name"
+  );
+  assert_eq!(
+    translator
+      .message_comment(&TestLanguage("en-US"), &TestKey("settings-profile-name"))
+      .as_deref(),
+    Some("Profile settings, see title")
+  );
+
+  let (path, line) = translator
+    .message_origin(
+      &TestLanguage("en-US"),
+      &TestKey("settings-profile-greeting"),
+    )
+    .unwrap();
+
+  assert!(path.ends_with("settings/profile.ftl"), "{path:?}");
+  assert_eq!(line, 4);
+}
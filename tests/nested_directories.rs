@@ -0,0 +1,86 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use std::path::{Path, PathBuf};
+
+use translate::Translator;
+
+fn translator(lazy_loading: bool) -> Translator<TestLanguage, TestKey> {
+  Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("nested"))
+    .default_language(&TestLanguage("en-US"))
+    .lazy_loading(lazy_loading)
+    .build()
+    .unwrap()
+}
+
+fn origin(
+  translator: &Translator<TestLanguage, TestKey>,
+  language: &'static str,
+  key: &'static str,
+) -> PathBuf {
+  let (path, _) = translator
+    .message_origin(&TestLanguage(language), &TestKey(key))
+    .unwrap();
+
+  path.strip_prefix(fixture("nested")).unwrap().to_path_buf()
+}
+
+#[test]
+fn files_of_nested_directories_are_loaded() {
+  for lazy_loading in [false, true] {
+    let translator = translator(lazy_loading);
+
+    assert_eq!(
+      translator.translate_without_arguments(&TestLanguage("en-US"), TestKey("security")),
+      "Security"
+    );
+    assert_eq!(
+      origin(&translator, "en-US", "profile"),
+      Path::new("en-US/settings/profile.ftl")
+    );
+    assert_eq!(
+      origin(&translator, "en-US", "security"),
+      Path::new("en-US/settings/account/security.ftl")
+    );
+  }
+}
+
+#[test]
+fn common_files_are_shared_by_every_language() {
+  for lazy_loading in [false, true] {
+    let translator = translator(lazy_loading);
+
+    for language in ["en-US", "es-ES"] {
+      assert_eq!(
+        translator.translate_without_arguments(&TestLanguage(language), TestKey("app-name")),
+        "Rocket"
+      );
+      assert!(translator.has_message(&TestLanguage(language), TestKey("legal")));
+    }
+
+    assert_eq!(
+      origin(&translator, "es-ES", "legal"),
+      Path::new("common/legal/terms.ftl")
+    );
+    assert_eq!(translator.languages().count(), 2);
+  }
+}
+
+#[test]
+fn messages_of_a_language_take_precedence_over_common_ones() {
+  let translator = translator(false);
+
+  assert_eq!(
+    translator.translate_without_arguments(&TestLanguage("en-US"), TestKey("footer")),
+    "Made by Acme"
+  );
+  assert_eq!(
+    translator.translate_without_arguments(&TestLanguage("es-ES"), TestKey("footer")),
+    "Hecho por Acme"
+  );
+  assert_eq!(
+    origin(&translator, "es-ES", "footer"),
+    Path::new("es-ES/main.ftl")
+  );
+}
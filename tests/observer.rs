@@ -0,0 +1,83 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use std::sync::{Arc, Mutex};
+
+use translate::{FluentError, TranslationObserver, Translator};
+
+#[derive(Clone, Default)]
+struct Recorder {
+  missing: Arc<Mutex<Vec<(String, String)>>>,
+  format_errors: Arc<Mutex<Vec<(String, String, usize)>>>,
+}
+
+impl TranslationObserver for Recorder {
+  fn on_missing(&self, language: &str, key: &str) {
+    self
+      .missing
+      .lock()
+      .unwrap()
+      .push((language.to_string(), key.to_string()));
+  }
+
+  fn on_format_error(&self, language: &str, key: &str, errors: &[FluentError]) {
+    self
+      .format_errors
+      .lock()
+      .unwrap()
+      .push((language.to_string(), key.to_string(), errors.len()));
+  }
+}
+
+fn translator(recorder: &Recorder) -> Translator<TestLanguage, TestKey> {
+  Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("missing-messages"))
+    .default_language(&TestLanguage("en-US"))
+    .with_observer(recorder.clone())
+    .build()
+    .unwrap()
+}
+
+fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+  pairs
+    .iter()
+    .map(|(language, key)| (language.to_string(), key.to_string()))
+    .collect()
+}
+
+#[test]
+fn missing_messages_are_reported_with_a_loaded_language() {
+  let recorder = Recorder::default();
+  let translator = translator(&recorder);
+
+  translator.translate_without_arguments(&TestLanguage("en-US"), TestKey("about"));
+  translator.translate_without_arguments(&TestLanguage("es-ES"), TestKey("missing"));
+  translator.translate_without_arguments(&TestLanguage("ja-JP"), TestKey("missing"));
+  translator.translate_without_arguments(&TestLanguage("ja-JP"), TestKey("about"));
+
+  assert_eq!(
+    *recorder.missing.lock().unwrap(),
+    pairs(&[("es-ES", "missing"), ("en-US", "missing")])
+  );
+  assert!(recorder.format_errors.lock().unwrap().is_empty());
+}
+
+#[test]
+fn format_errors_are_reported_with_the_language_of_the_bundle() {
+  let recorder = Recorder::default();
+  let translator = translator(&recorder);
+
+  translator.translate_without_arguments(&TestLanguage("es-ES"), TestKey("about"));
+  translator
+    .translate(&TestLanguage("es-ES"), TestKey("about"))
+    .build();
+
+  assert_eq!(
+    *recorder.format_errors.lock().unwrap(),
+    [
+      ("es-ES".to_string(), "about".to_string(), 1),
+      ("es-ES".to_string(), "about".to_string(), 1),
+    ]
+  );
+  assert!(recorder.missing.lock().unwrap().is_empty());
+}
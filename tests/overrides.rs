@@ -0,0 +1,108 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use translate::{Translator, TranslatorError};
+
+fn translator() -> Translator<TestLanguage, TestKey> {
+  Translator::<TestLanguage, TestKey>::new(&fixture("overrides"), &TestLanguage("en-US")).unwrap()
+}
+
+fn welcome(translator: &Translator<TestLanguage, TestKey>, language: &'static str) -> String {
+  translator
+    .translate_without_arguments(&TestLanguage(language), TestKey("welcome"))
+    .into_owned()
+}
+
+#[test]
+fn overrides_replace_the_loaded_message_of_their_language() {
+  let mut translator = translator();
+
+  translator
+    .set_override(&TestLanguage("en-US"), "welcome", "Welcome back")
+    .unwrap();
+
+  assert_eq!(welcome(&translator, "en-US"), "Welcome back");
+  assert_eq!(welcome(&translator, "es-ES"), "Bienvenido");
+  assert_eq!(
+    translator.translate_without_arguments(&TestLanguage("en-US"), TestKey("goodbye")),
+    "Goodbye"
+  );
+}
+
+#[test]
+fn overrides_can_use_arguments_and_terms() {
+  let mut translator = translator();
+
+  translator
+    .set_override(
+      &TestLanguage("en-US"),
+      "welcome",
+      "Welcome to { -brand }, { $name }",
+    )
+    .unwrap();
+
+  assert_eq!(
+    translator
+      .translate(&TestLanguage("en-US"), TestKey("welcome"))
+      .add_argument("name", "Alex")
+      .build(),
+    "Welcome to Acme, \u{2068}Alex\u{2069}"
+  );
+}
+
+#[test]
+fn overrides_can_add_messages_missing_from_the_files() {
+  let mut translator = translator();
+
+  translator
+    .set_override(&TestLanguage("es-ES"), "goodbye", "Adiós")
+    .unwrap();
+
+  assert_eq!(
+    translator.translate_without_arguments(&TestLanguage("es-ES"), TestKey("goodbye")),
+    "Adiós"
+  );
+}
+
+#[test]
+fn clearing_an_override_restores_the_loaded_message() {
+  let mut translator = translator();
+
+  translator
+    .set_override(&TestLanguage("en-US"), "welcome", "Welcome back")
+    .unwrap();
+
+  assert!(translator.clear_override(&TestLanguage("en-US"), "welcome"));
+  assert_eq!(welcome(&translator, "en-US"), "Welcome");
+  assert!(!translator.clear_override(&TestLanguage("en-US"), "welcome"));
+}
+
+#[test]
+fn overrides_of_unloaded_languages_fail() {
+  let mut translator = translator();
+
+  let result = translator.set_override(&TestLanguage("fr-FR"), "welcome", "Bienvenue");
+
+  assert!(matches!(
+    result,
+    Err(TranslatorError::UnknownLanguage { language }) if language == "fr-FR"
+  ));
+}
+
+#[test]
+fn invalid_patterns_are_rejected_and_keep_the_loaded_message() {
+  let mut translator = translator();
+
+  let invalid = ["{ $name", "Welcome\nother = Other"];
+
+  for pattern in invalid {
+    let result = translator.set_override(&TestLanguage("en-US"), "welcome", pattern);
+
+    assert!(
+      matches!(&result, Err(TranslatorError::InvalidOverride { key, .. }) if key == "welcome"),
+      "{pattern:?} was accepted: {result:?}"
+    );
+  }
+
+  assert_eq!(welcome(&translator, "en-US"), "Welcome");
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use translate::Translator;
+
+#[test]
+fn plural_forms_returns_one_text_per_reached_variant() {
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("plurals"))
+    .default_language(&english)
+    .use_isolating(false)
+    .build()
+    .unwrap();
+
+  let forms = translator
+    .plural_forms(&english, TestKey("unread"), "count", [1, 2, 5])
+    .unwrap();
+
+  assert_eq!(
+    forms,
+    [
+      "One unread message",
+      "2 unread messages",
+      "5 unread messages"
+    ]
+  );
+  assert!(translator
+    .plural_forms(&english, TestKey("missing"), "count", [1])
+    .is_err());
+}
+
+#[test]
+fn plural_categories_follow_cldr() {
+  let translator =
+    Translator::<TestLanguage, TestKey>::from_sources([("en-US", "")], &TestLanguage("en-US"))
+      .unwrap();
+
+  assert_eq!(
+    translator.plural_categories(&TestLanguage("en-US")),
+    ["one", "other"]
+  );
+  assert_eq!(
+    translator.plural_categories(&TestLanguage("ar")),
+    ["zero", "one", "two", "few", "many", "other"]
+  );
+  assert_eq!(
+    translator.plural_categories(&TestLanguage("ru")),
+    ["one", "few", "many", "other"]
+  );
+  assert_eq!(translator.plural_categories(&TestLanguage("ja")), ["other"]);
+}
+
+#[test]
+fn plural_categories_use_the_language_parser() {
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("plurals"))
+    .default_language(&TestLanguage("en-US"))
+    .with_language_parser(|name| match name {
+      "russian" => "ru".parse().ok(),
+      name => name.parse().ok(),
+    })
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    translator.plural_categories(&TestLanguage("russian")),
+    ["one", "few", "many", "other"]
+  );
+}
+
+#[test]
+fn validate_plurals_reports_missing_categories() {
+  let translator = Translator::<TestLanguage, TestKey>::from_sources(
+    [
+      ("en-US", "unread = { $count ->\n    [one] One\n   *[other] Many\n}\n"),
+      (
+        "ru",
+        "unread = { $count ->\n    [one] One\n   *[other] Many\n}\n\
+         title =\n    .label = { $count ->\n        [one] One\n        [few] Few\n        [many] Many\n       *[other] Other\n    }\n",
+      ),
+    ],
+    &TestLanguage("en-US"),
+  )
+  .unwrap();
+
+  let issues = translator.validate_plurals();
+
+  assert_eq!(issues.len(), 1, "{issues:?}");
+  assert_eq!(issues[0].language, "ru");
+  assert_eq!(issues[0].key, "unread");
+  assert_eq!(issues[0].missing_categories, ["few", "many"]);
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "relative-time")]
+
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use std::time::{Duration, SystemTime};
+
+use translate::{RelativeTime, Translator};
+
+#[test]
+fn relative_times_use_the_largest_unit() {
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("relative-time"))
+    .default_language(&english)
+    .use_isolating(false)
+    .build()
+    .unwrap();
+
+  let now = SystemTime::now();
+  let relative_time = |seconds: u64| {
+    let past = RelativeTime::Past(Duration::from_secs(seconds));
+    translator.translate_relative_time(&english, past)
+  };
+  let in_two_days = RelativeTime::between(now + Duration::from_secs(2 * 86_400 + 60), now);
+
+  assert_eq!(relative_time(0), "just now");
+  assert_eq!(relative_time(60), "a minute ago");
+  assert_eq!(relative_time(3 * 60 + 59), "3 minutes ago");
+  assert_eq!(
+    translator.translate_relative_time(&english, in_two_days),
+    "in 2 days"
+  );
+  assert_eq!(
+    translator.translate_duration(&english, Duration::from_secs(7_200)),
+    "2 hours"
+  );
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use common::{temporary_fixture, TestKey, TestLanguage};
+use std::fs;
+
+use translate::{Translator, TranslatorError};
+
+#[test]
+fn reload_reads_the_changed_files() {
+  let directory = temporary_fixture("languages");
+  let english = TestLanguage("en-US");
+  let mut translator =
+    Translator::<TestLanguage, TestKey>::new(directory.to_str().unwrap(), &english).unwrap();
+
+  fs::write(directory.join("en-US/main.ftl"), "hello = Hi\n").unwrap();
+
+  assert_eq!(
+    translator.translate_without_arguments(&english, TestKey("hello")),
+    "Hello"
+  );
+
+  translator.reload().unwrap();
+
+  assert_eq!(
+    translator.translate_without_arguments(&english, TestKey("hello")),
+    "Hi"
+  );
+
+  fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn reload_keeps_runtime_overrides() {
+  let directory = temporary_fixture("languages");
+  let spanish = TestLanguage("es-ES");
+  let mut translator =
+    Translator::<TestLanguage, TestKey>::new(directory.to_str().unwrap(), &TestLanguage("en-US"))
+      .unwrap();
+
+  translator
+    .set_override(&spanish, "hello", "Buenas")
+    .unwrap();
+  fs::write(directory.join("es-ES/main.ftl"), "hello = Hola de nuevo\n").unwrap();
+
+  translator.reload().unwrap();
+
+  assert_eq!(
+    translator.translate_without_arguments(&spanish, TestKey("hello")),
+    "Buenas"
+  );
+  assert!(translator.clear_override(&spanish, "hello"));
+  assert_eq!(
+    translator.translate_without_arguments(&spanish, TestKey("hello")),
+    "Hola de nuevo"
+  );
+
+  fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn failed_reloads_keep_the_previous_translations() {
+  let directory = temporary_fixture("languages");
+  let english = TestLanguage("en-US");
+  let mut translator =
+    Translator::<TestLanguage, TestKey>::new(directory.to_str().unwrap(), &english).unwrap();
+
+  fs::remove_dir_all(&directory).unwrap();
+
+  assert!(matches!(
+    translator.reload(),
+    Err(TranslatorError::DirectoryNotFound { .. })
+  ));
+  assert_eq!(
+    translator.translate_without_arguments(&english, TestKey("hello")),
+    "Hello"
+  );
+}
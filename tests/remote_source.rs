@@ -0,0 +1,162 @@
+#![cfg(feature = "reqwest")]
+
+mod common;
+
+use common::{TestKey, TestLanguage};
+use std::{
+  env, fs,
+  io::{BufRead, BufReader, Write},
+  net::TcpListener,
+  path::PathBuf,
+  process,
+  sync::{Arc, Mutex},
+  thread,
+};
+
+use translate::{RemoteSource, Translator, TranslatorError};
+
+/// Serves `<language>.ftl` files with an ETag, answering `304 Not Modified` when it matches, and records
+/// the `If-None-Match` header of every request.
+struct Server {
+  url: String,
+  revalidations: Arc<Mutex<Vec<Option<String>>>>,
+}
+
+fn serve(files: &'static [(&'static str, &'static str)], requests: usize) -> Server {
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let url = format!(
+    "http://{}/translations/{{language}}.ftl",
+    listener.local_addr().unwrap()
+  );
+  let revalidations = Arc::new(Mutex::new(Vec::new()));
+  let recorded = Arc::clone(&revalidations);
+
+  thread::spawn(move || {
+    for stream in listener.incoming().take(requests) {
+      let mut stream = stream.unwrap();
+      let mut lines = BufReader::new(&stream).lines().map(Result::unwrap);
+
+      let path = lines.next().unwrap().split(' ').nth(1).unwrap().to_string();
+      let if_none_match = lines
+        .by_ref()
+        .take_while(|line| !line.is_empty())
+        .find_map(|line| {
+          let (name, value) = line.split_once(": ")?;
+          name
+            .eq_ignore_ascii_case("if-none-match")
+            .then(|| value.to_string())
+        });
+
+      recorded.lock().unwrap().push(if_none_match.clone());
+
+      let file = files
+        .iter()
+        .find(|(language, _)| path == format!("/translations/{language}.ftl"));
+
+      let response = match (file, if_none_match) {
+        (Some(_), Some(_)) => "HTTP/1.1 304 Not Modified\r\nConnection: close\r\n\r\n".to_string(),
+        (Some((language, content)), None) => format!(
+          "HTTP/1.1 200 OK\r\nETag: \"{language}-1\"\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{content}",
+          content.len()
+        ),
+        (None, _) => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string(),
+      };
+
+      stream.write_all(response.as_bytes()).unwrap();
+    }
+  });
+
+  Server { url, revalidations }
+}
+
+fn cache_directory(name: &str) -> PathBuf {
+  let directory = env::temp_dir().join(format!("translate-remote-{name}-{}", process::id()));
+
+  if directory.exists() {
+    fs::remove_dir_all(&directory).unwrap();
+  }
+
+  directory
+}
+
+const FILES: &[(&str, &str)] = &[("en-US", "hello = Hello\n"), ("es-ES", "hello = Hola\n")];
+
+#[test]
+fn files_are_fetched_per_language() {
+  let server = serve(FILES, 2);
+  let source = RemoteSource::new(&server.url, &["en-US", "es-ES"]);
+
+  let translator =
+    Translator::<TestLanguage, TestKey>::from_source(&source, &TestLanguage("en-US")).unwrap();
+
+  assert_eq!(
+    translator.translate_without_arguments(&TestLanguage("es-ES"), TestKey("hello")),
+    "Hola"
+  );
+  assert_eq!(*server.revalidations.lock().unwrap(), [None, None]);
+}
+
+#[test]
+fn cached_files_are_revalidated_with_their_etag() {
+  let server = serve(FILES, 2);
+  let cache_directory = cache_directory("revalidated");
+  let source = RemoteSource::new(&server.url, &["en-US"]).with_cache_directory(&cache_directory);
+
+  for _ in 0..2 {
+    let translator =
+      Translator::<TestLanguage, TestKey>::from_source(&source, &TestLanguage("en-US")).unwrap();
+
+    assert_eq!(
+      translator.translate_without_arguments(&TestLanguage("en-US"), TestKey("hello")),
+      "Hello"
+    );
+  }
+
+  assert_eq!(
+    *server.revalidations.lock().unwrap(),
+    [None, Some("\"en-US-1\"".to_string())]
+  );
+  assert_eq!(
+    fs::read_to_string(cache_directory.join("en-US.etag")).unwrap(),
+    "\"en-US-1\""
+  );
+
+  fs::remove_dir_all(cache_directory).unwrap();
+}
+
+#[test]
+fn cached_files_are_used_when_fetching_fails() {
+  let cache_directory = cache_directory("offline");
+  fs::create_dir_all(&cache_directory).unwrap();
+  fs::write(
+    cache_directory.join("en-US.ftl"),
+    "hello = Hello from cache\n",
+  )
+  .unwrap();
+
+  let server = serve(&[], 1);
+  let source = RemoteSource::new(&server.url, &["en-US"]).with_cache_directory(&cache_directory);
+
+  let translator =
+    Translator::<TestLanguage, TestKey>::from_source(&source, &TestLanguage("en-US")).unwrap();
+
+  assert_eq!(
+    translator.translate_without_arguments(&TestLanguage("en-US"), TestKey("hello")),
+    "Hello from cache"
+  );
+
+  fs::remove_dir_all(cache_directory).unwrap();
+}
+
+#[test]
+fn failed_fetches_without_cache_fail_with_the_url() {
+  let server = serve(&[], 1);
+  let source = RemoteSource::new(&server.url, &["en-US"]);
+
+  let result = Translator::<TestLanguage, TestKey>::from_source(&source, &TestLanguage("en-US"));
+
+  assert!(matches!(
+    result,
+    Err(TranslatorError::RemoteError { url, .. }) if url.ends_with("/translations/en-US.ftl")
+  ));
+}
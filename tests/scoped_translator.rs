@@ -0,0 +1,72 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use translate::Translator;
+
+#[test]
+fn resolve_binds_first_loaded_candidate_or_default() {
+  let english = TestLanguage("en-US");
+  let mexican_spanish = TestLanguage("es-MX");
+  let japanese = TestLanguage("ja-JP");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("languages"), &english).unwrap();
+
+  let preferred = translator.resolve(&[&japanese, &mexican_spanish]);
+  let unmatched = translator.resolve(&[&japanese]);
+
+  assert_eq!(preferred.resolved_language(), "es-ES");
+  assert_eq!(
+    preferred.translate_without_arguments(TestKey("hello")),
+    "Hola"
+  );
+  assert!(unmatched.language().is_none());
+  assert_eq!(
+    unmatched.translate_without_arguments(TestKey("hello")),
+    "Hello"
+  );
+}
+
+#[test]
+fn scoped_translators_translate_like_the_translator() {
+  let english = TestLanguage("en-US");
+  let spanish = TestLanguage("es-ES");
+  let mut translator = Translator::<TestLanguage, TestKey>::from_sources(
+    [
+      (
+        "en-US",
+        "hello = Hello\ngreeting = Hello { $name }\nlogin = Log in\n    .title = Log in to continue\n",
+      ),
+      ("es-ES", "hello = Hola\nlogin = Entrar\n    .title = Entra para continuar\n"),
+    ],
+    &english,
+  )
+  .unwrap();
+  translator.set_use_isolating(false);
+
+  let scoped = translator.for_language(&spanish);
+  let copied = scoped;
+
+  assert_eq!(scoped.resolved_language(), "es-ES");
+  assert!(scoped.language().is_some());
+  assert_eq!(
+    copied
+      .translate(TestKey("greeting"))
+      .add_argument("name", "Alex")
+      .into_string(),
+    "Hello Alex"
+  );
+  assert_eq!(
+    scoped.translate_attribute(TestKey("login"), "title"),
+    "Entra para continuar"
+  );
+  assert_eq!(scoped.translate_opt(TestKey("missing")), None);
+  assert!(scoped.try_translate(TestKey("missing")).is_err());
+
+  let translation = scoped.translate_detailed(TestKey("greeting"));
+  assert!(translation.used_fallback);
+  assert_eq!(translation.effective_language, "en-US");
+
+  let mut out = String::new();
+  assert!(scoped.translate_into(TestKey("hello"), &mut out));
+  assert_eq!(out, "Hola");
+}
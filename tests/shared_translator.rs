@@ -0,0 +1,56 @@
+mod common;
+
+use common::{fixture, temporary_fixture, TestKey, TestLanguage};
+use std::{fs, thread};
+
+use translate::{SharedTranslator, Translator, TranslatorError};
+
+fn hello(translator: &SharedTranslator<TestLanguage, TestKey>) -> String {
+  translator
+    .read()
+    .translate_owned(&TestLanguage("en-US"), TestKey("hello"))
+}
+
+#[test]
+fn replaced_translators_are_seen_by_every_clone() {
+  let english = TestLanguage("en-US");
+  let shared = SharedTranslator::new(
+    Translator::<TestLanguage, TestKey>::new(&fixture("languages"), &english).unwrap(),
+  );
+  let clone = shared.clone();
+
+  let previous = shared.replace(
+    Translator::<TestLanguage, TestKey>::from_sources([("en-US", "hello = Hi\n")], &english)
+      .unwrap(),
+  );
+
+  assert_eq!(
+    previous.translate_without_arguments(&english, TestKey("hello")),
+    "Hello"
+  );
+  assert_eq!(hello(&clone), "Hi");
+  assert_eq!(thread::spawn(move || hello(&clone)).join().unwrap(), "Hi");
+}
+
+#[test]
+fn reloads_are_seen_by_every_clone() {
+  let directory = temporary_fixture("languages");
+  let shared = SharedTranslator::new(
+    Translator::<TestLanguage, TestKey>::new(directory.to_str().unwrap(), &TestLanguage("en-US"))
+      .unwrap(),
+  );
+  let clone = shared.clone();
+
+  fs::write(directory.join("en-US/main.ftl"), "hello = Hi\n").unwrap();
+  shared.reload().unwrap();
+
+  assert_eq!(hello(&clone), "Hi");
+
+  fs::remove_dir_all(&directory).unwrap();
+
+  assert!(matches!(
+    shared.reload(),
+    Err(TranslatorError::DirectoryNotFound { .. })
+  ));
+  assert_eq!(hello(&clone), "Hi");
+}
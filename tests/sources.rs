@@ -0,0 +1,92 @@
+mod common;
+
+use common::{TestKey, TestLanguage};
+use translate::{Translator, TranslatorError};
+
+#[test]
+fn embedded_sources_are_loaded_like_files() {
+  let translator = Translator::<TestLanguage, TestKey>::from_sources(
+    [
+      ("en-US", include_str!("fixtures/languages/en-US/main.ftl")),
+      ("es-ES", include_str!("fixtures/languages/es-ES/main.ftl")),
+    ],
+    &TestLanguage("en-US"),
+  )
+  .unwrap();
+
+  assert_eq!(
+    translator.translate_without_arguments(&TestLanguage("en-US"), TestKey("hello")),
+    "Hello"
+  );
+  assert_eq!(
+    translator.translate_without_arguments(&TestLanguage("es-ES"), TestKey("hello")),
+    "Hola"
+  );
+}
+
+#[test]
+fn sources_of_the_same_language_are_added_in_order() {
+  let translator = Translator::<TestLanguage, TestKey>::from_sources(
+    [
+      ("en-US", "-brand = Acme\nhello = Hello\n".to_string()),
+      (
+        "en-US",
+        "welcome = Welcome to { -brand }\nhello = Hi\n".to_string(),
+      ),
+    ],
+    &TestLanguage("en-US"),
+  )
+  .unwrap();
+
+  assert_eq!(
+    translator.translate_without_arguments(&TestLanguage("en-US"), TestKey("welcome")),
+    "Welcome to Acme"
+  );
+  assert_eq!(
+    translator.translate_without_arguments(&TestLanguage("en-US"), TestKey("hello")),
+    "Hello"
+  );
+}
+
+#[test]
+fn sources_with_invalid_language_names_are_ignored() {
+  let translator = Translator::<TestLanguage, TestKey>::from_sources(
+    [
+      ("en-US", "hello = Hello\n"),
+      ("not a language", "hello = ?\n"),
+    ],
+    &TestLanguage("en-US"),
+  )
+  .unwrap();
+
+  assert!(translator.bundle(&TestLanguage("en-US")).is_some());
+  assert!(translator.bundle(&TestLanguage("not a language")).is_none());
+}
+
+#[test]
+fn sources_without_the_default_language_fail() {
+  let result = Translator::<TestLanguage, TestKey>::from_sources(
+    [("es-ES", "hello = Hola\n")],
+    &TestLanguage("en-US"),
+  );
+
+  assert!(matches!(result, Err(TranslatorError::NoDefaultLanuage)));
+}
+
+#[test]
+fn translators_from_sources_cannot_be_reloaded() {
+  let mut translator = Translator::<TestLanguage, TestKey>::from_sources(
+    [("en-US", "hello = Hello\n")],
+    &TestLanguage("en-US"),
+  )
+  .unwrap();
+
+  assert!(matches!(
+    translator.reload(),
+    Err(TranslatorError::NoDirectory)
+  ));
+  assert_eq!(
+    translator.translate_without_arguments(&TestLanguage("en-US"), TestKey("hello")),
+    "Hello"
+  );
+}
@@ -0,0 +1,138 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use translate::{Language, TranslationKey, Translator, TranslatorError};
+
+enum Languages {
+  English,
+  Spanish,
+}
+
+impl Language for Languages {
+  fn as_str(&self) -> &'static str {
+    match self {
+      Languages::English => "en-US",
+      Languages::Spanish => "es-ES",
+    }
+  }
+
+  fn from_str(language: &str) -> Option<Self> {
+    match language {
+      "en-US" => Some(Languages::English),
+      "es-ES" => Some(Languages::Spanish),
+      _ => None,
+    }
+  }
+}
+
+enum Keys {
+  HelloWithArguments,
+}
+
+impl TranslationKey for Keys {
+  fn as_str(&self) -> &'static str {
+    match self {
+      Keys::HelloWithArguments => "hello_with_arguments",
+    }
+  }
+
+  fn from_str(key: &str) -> Option<Self> {
+    match key {
+      "hello_with_arguments" => Some(Keys::HelloWithArguments),
+      _ => None,
+    }
+  }
+}
+
+#[test]
+fn languages_and_keys_are_resolved_from_strings() {
+  let translator =
+    Translator::<Languages, Keys>::new(&fixture("arguments"), &Languages::English).unwrap();
+
+  let message = translator
+    .translate_by_str("es-ES", "hello_with_arguments")
+    .unwrap()
+    .add_argument("name", "Alex");
+
+  assert_eq!(message.build(), "Hello \u{2068}Alex\u{2069}!");
+  assert!(matches!(
+    translator.translate_by_str("fr-FR", "hello_with_arguments"),
+    Err(TranslatorError::UnknownLanguage { language }) if language == "fr-FR"
+  ));
+  assert!(matches!(
+    translator.translate_by_str("en-US", "missing"),
+    Err(TranslatorError::UnknownKey { key }) if key == "missing"
+  ));
+}
+
+#[test]
+fn types_without_from_str_are_not_resolved() {
+  let english = TestLanguage("en-US");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("languages"), &english).unwrap();
+
+  assert!(matches!(
+    translator.translate_by_str("en-US", "hello"),
+    Err(TranslatorError::UnknownLanguage { .. })
+  ));
+}
+
+#[test]
+fn languages_given_as_runtime_strings_are_translated_with_fallbacks() {
+  let mut translator = Translator::<TestLanguage, TestKey>::from_sources(
+    [
+      ("en-US", "greeting = Hello { $name }\nthanks = Thanks\n"),
+      ("es-ES", "greeting = Hola { $name }\n"),
+    ],
+    &TestLanguage("en-US"),
+  )
+  .unwrap();
+  translator.set_use_isolating(false);
+
+  let greet = |language_code: String| {
+    translator
+      .translate_str(&language_code, TestKey("greeting"))
+      .add_argument("name", "Alex")
+      .build()
+      .into_owned()
+  };
+
+  assert_eq!(greet(String::from("es-ES")), "Hola Alex");
+  assert_eq!(greet(String::from("es-MX")), "Hola Alex");
+  assert_eq!(greet(String::from("ja-JP")), "Hello Alex");
+  assert_eq!(
+    translator
+      .translate_str(&String::from("es-ES"), TestKey("thanks"))
+      .build(),
+    "Thanks"
+  );
+}
+
+#[test]
+fn keys_only_known_at_runtime_are_translated_with_fallbacks() {
+  let translator = Translator::<TestLanguage, TestKey>::from_sources(
+    [
+      (
+        "en-US",
+        "menu-home = Home\nmenu-help = Help\nmenu-login =\n    .title = Log in\n",
+      ),
+      ("es-ES", "menu-home = Inicio\n"),
+    ],
+    &TestLanguage("en-US"),
+  )
+  .unwrap();
+  let spanish = TestLanguage("es-ES");
+  let cms_keys: Vec<String> = ["menu-home", "menu-help", "menu-about", "menu-login"]
+    .map(String::from)
+    .into();
+
+  let labels: Vec<_> = cms_keys
+    .iter()
+    .map(|key| translator.translate_dynamic(&spanish, key))
+    .collect();
+
+  assert_eq!(labels[0].as_deref(), Some("Inicio"));
+  assert_eq!(labels[1].as_deref(), Some("Help"));
+  assert_eq!(labels[2], None);
+  assert_eq!(labels[3], None);
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use translate::{MessageTranslator, SharedTranslator, Translator};
+
+fn assert_send_sync<Value: Send + Sync>() {}
+
+fn assert_send<Value: Send>() {}
+
+#[test]
+fn translator_is_send_and_sync() {
+  assert_send_sync::<Translator<TestLanguage, TestKey>>();
+  assert_send_sync::<SharedTranslator<TestLanguage, TestKey>>();
+}
+
+#[test]
+fn message_translator_is_send() {
+  assert_send::<MessageTranslator<'static, 'static, TestKey>>();
+}
+
+#[test]
+fn translator_debug_shows_languages() {
+  let english = TestLanguage("en-US");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("arguments"), &english).unwrap();
+
+  let debug = format!("{translator:?}");
+
+  assert!(debug.contains(r#"languages: ["en-US"]"#));
+  assert!(debug.contains(r#"default_language: "en-US""#));
+}
+
+#[test]
+fn message_translator_debug_shows_pending_arguments() {
+  let english = TestLanguage("en-US");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("arguments"), &english).unwrap();
+
+  let message = translator
+    .translate(&english, TestKey("hello_with_arguments"))
+    .add_argument("name", "Alex");
+
+  let debug = format!("{message:?}");
+
+  assert!(debug.contains(r#"key: "hello_with_arguments""#));
+  assert!(debug.contains("Alex"));
+}
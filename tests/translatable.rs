@@ -0,0 +1,34 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use translate::{Translatable, Translator};
+
+#[test]
+fn translatable_is_rendered_once_bound() {
+  let english = TestLanguage("en-US");
+  let spanish = TestLanguage("es-ES");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("languages"), &english).unwrap();
+
+  let hello = Translatable::new(TestKey("hello"));
+  let spanish_hello = Translatable::new(TestKey("hello")).language(&spanish);
+
+  assert_eq!(hello.bind(&translator).to_string(), "Hello");
+  assert_eq!(spanish_hello.bind(&translator).to_string(), "Hola");
+  assert_eq!(hello.bind_to(&translator, &spanish).to_string(), "Hola");
+}
+
+#[test]
+fn translatable_formats_its_arguments() {
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(&fixture("arguments"))
+    .default_language(&english)
+    .use_isolating(false)
+    .build()
+    .unwrap();
+
+  let greeting = Translatable::new(TestKey("hello_with_arguments")).add_argument("name", "Alex");
+
+  assert_eq!(format!("{}", greeting.bind(&translator)), "Hello Alex!");
+}
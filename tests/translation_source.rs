@@ -0,0 +1,126 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use std::collections::HashMap;
+
+use translate::{FileSystemSource, TranslationSource, Translator, TranslatorError};
+
+/// A source keeping its resources in memory, like one backed by a database would.
+#[derive(Default)]
+struct MemorySource {
+  resources: HashMap<String, Vec<(String, String)>>,
+  common_resources: Vec<(String, String)>,
+  unavailable: bool,
+}
+
+impl MemorySource {
+  fn with(mut self, language: &str, file_name: &str, content: &str) -> Self {
+    self
+      .resources
+      .entry(language.to_string())
+      .or_default()
+      .push((file_name.to_string(), content.to_string()));
+    self
+  }
+}
+
+impl TranslationSource for MemorySource {
+  fn languages(&self) -> Result<Vec<String>, TranslatorError> {
+    if self.unavailable {
+      return Err(TranslatorError::NoDirectory);
+    }
+
+    Ok(self.resources.keys().cloned().collect())
+  }
+
+  fn resources(&self, language: &str) -> Result<Vec<(String, String)>, TranslatorError> {
+    Ok(self.resources.get(language).cloned().unwrap_or_default())
+  }
+
+  fn common_resources(&self) -> Result<Vec<(String, String)>, TranslatorError> {
+    Ok(self.common_resources.clone())
+  }
+}
+
+#[test]
+fn custom_sources_are_loaded_with_common_resources_filling_gaps() {
+  let source = MemorySource {
+    common_resources: vec![(
+      "brand.ftl".to_string(),
+      "app-name = Rocket\nfooter = Made by Acme\n".to_string(),
+    )],
+    ..MemorySource::default()
+  }
+  .with("en-US", "main.ftl", "hello = Hello\n")
+  .with(
+    "es-ES",
+    "main.ftl",
+    "hello = Hola\nfooter = Hecho por Acme\n",
+  );
+
+  let translator =
+    Translator::<TestLanguage, TestKey>::from_source(&source, &TestLanguage("en-US")).unwrap();
+  let text = |language: &'static str, key: &'static str| {
+    translator.translate_owned(&TestLanguage(language), TestKey(key))
+  };
+
+  assert_eq!(text("es-ES", "hello"), "Hola");
+  assert_eq!(text("es-ES", "app-name"), "Rocket");
+  assert_eq!(text("es-ES", "footer"), "Hecho por Acme");
+  assert_eq!(text("en-US", "footer"), "Made by Acme");
+}
+
+#[test]
+fn resources_are_added_in_file_name_order() {
+  let source = MemorySource::default()
+    .with("en-US", "b.ftl", "hello = Hello from b\n")
+    .with("en-US", "a.ftl", "hello = Hello from a\n");
+
+  let translator =
+    Translator::<TestLanguage, TestKey>::from_source(&source, &TestLanguage("en-US")).unwrap();
+
+  assert_eq!(
+    translator.translate_without_arguments(&TestLanguage("en-US"), TestKey("hello")),
+    "Hello from a"
+  );
+}
+
+#[test]
+fn source_errors_are_returned() {
+  let source = MemorySource {
+    unavailable: true,
+    ..MemorySource::default()
+  };
+
+  let result = Translator::<TestLanguage, TestKey>::from_source(&source, &TestLanguage("en-US"));
+
+  assert!(matches!(result, Err(TranslatorError::NoDirectory)));
+}
+
+#[test]
+fn the_file_system_source_reads_directories_like_new() {
+  let source = FileSystemSource::new(fixture("nested"));
+  let from_source =
+    Translator::<TestLanguage, TestKey>::from_source(&source, &TestLanguage("en-US")).unwrap();
+  let from_directory =
+    Translator::<TestLanguage, TestKey>::new(&fixture("nested"), &TestLanguage("en-US")).unwrap();
+
+  assert_eq!(
+    from_source.languages().collect::<Vec<_>>(),
+    from_directory.languages().collect::<Vec<_>>()
+  );
+
+  for language in ["en-US", "es-ES"] {
+    assert_eq!(
+      from_source.message_keys(language).collect::<Vec<_>>(),
+      from_directory.message_keys(language).collect::<Vec<_>>()
+    );
+
+    for key in ["hello", "profile", "security", "footer", "legal"] {
+      assert_eq!(
+        from_source.translate_owned(&TestLanguage(language), TestKey(key)),
+        from_directory.translate_owned(&TestLanguage(language), TestKey(key))
+      );
+    }
+  }
+}
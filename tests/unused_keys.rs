@@ -0,0 +1,15 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use translate::Translator;
+
+#[test]
+fn messages_referenced_by_used_messages_are_not_unused() {
+  let english = TestLanguage("en-US");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("references"), &english).unwrap();
+
+  let unused_keys = translator.unused_keys(["welcome", "about", "not-a-message"]);
+
+  assert_eq!(unused_keys, ["old-banner", "old-banner-title"]);
+}
@@ -0,0 +1,66 @@
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use translate::{MissingAttribute, MissingFile, MissingMessage, Translator};
+
+const KEYS: [TestKey; 3] = [TestKey("hello"), TestKey("login"), TestKey("theme")];
+
+fn translator() -> Translator<TestLanguage, TestKey> {
+  Translator::<TestLanguage, TestKey>::new(&fixture("validation"), &TestLanguage("en-US")).unwrap()
+}
+
+#[test]
+fn validate_reports_missing_messages_attributes_and_files() {
+  let report = translator().validate(&KEYS);
+
+  assert!(!report.is_valid());
+  assert_eq!(
+    report.missing_messages,
+    [MissingMessage {
+      language: "es-ES".to_string(),
+      key: "theme".to_string(),
+    }]
+  );
+  assert_eq!(
+    report.missing_attributes,
+    [MissingAttribute {
+      language: "es-ES".to_string(),
+      key: "login".to_string(),
+      attribute: "title".to_string(),
+    }]
+  );
+  assert_eq!(
+    report.missing_files,
+    [MissingFile {
+      language: "es-ES".to_string(),
+      file_name: "settings.ftl".to_string(),
+    }]
+  );
+}
+
+#[test]
+fn keys_unknown_to_every_language_are_reported_for_each_of_them() {
+  let report = translator().validate(&[TestKey("missing")]);
+
+  let languages: Vec<&str> = report
+    .missing_messages
+    .iter()
+    .map(|missing| missing.language.as_str())
+    .collect();
+
+  assert_eq!(languages, ["en-US", "es-ES", "fr-FR"]);
+}
+
+#[test]
+fn translated_keys_are_valid() {
+  let translator = Translator::<TestLanguage, TestKey>::from_sources(
+    [
+      ("en-US", "hello = Hello\n    .title = Hi\n"),
+      ("es-ES", "hello = Hola\n    .title = Hola\n"),
+    ],
+    &TestLanguage("en-US"),
+  )
+  .unwrap();
+
+  assert!(translator.validate(&[TestKey("hello")]).is_valid());
+}
@@ -0,0 +1,110 @@
+#![cfg(feature = "notify")]
+
+mod common;
+
+use common::{temporary_fixture, TestKey, TestLanguage};
+use std::{
+  fs, thread,
+  time::{Duration, Instant},
+};
+
+use translate::Translator;
+
+/// Waits for the watcher to reload the translations, which happens in the background after a short delay.
+fn eventually(condition: impl Fn() -> bool) -> bool {
+  let deadline = Instant::now() + Duration::from_secs(10);
+
+  while Instant::now() < deadline {
+    if condition() {
+      return true;
+    }
+
+    thread::sleep(Duration::from_millis(50));
+  }
+
+  false
+}
+
+#[test]
+fn edited_files_are_reloaded() {
+  let directory = temporary_fixture("languages");
+  let english = TestLanguage("en-US");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(directory.to_str().unwrap(), &english).unwrap();
+
+  let (translator, _watch_guard) = translator.watch().unwrap();
+
+  assert_eq!(
+    translator
+      .read()
+      .translate_without_arguments(&english, TestKey("hello")),
+    "Hello"
+  );
+
+  fs::write(directory.join("en-US/main.ftl"), "hello = Hi\n").unwrap();
+
+  assert!(eventually(|| translator
+    .read()
+    .translate_without_arguments(&english, TestKey("hello"))
+    == "Hi"));
+
+  fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn added_languages_are_loaded() {
+  let directory = temporary_fixture("languages");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(directory.to_str().unwrap(), &TestLanguage("en-US"))
+      .unwrap();
+
+  let (translator, _watch_guard) = translator.watch().unwrap();
+
+  assert!(translator.read().bundle(&TestLanguage("fr-FR")).is_none());
+
+  fs::create_dir(directory.join("fr-FR")).unwrap();
+  fs::write(directory.join("fr-FR/main.ftl"), "hello = Bonjour\n").unwrap();
+
+  assert!(eventually(|| translator
+    .read()
+    .bundle(&TestLanguage("fr-FR"))
+    .is_some()));
+  assert_eq!(
+    translator
+      .read()
+      .translate_without_arguments(&TestLanguage("fr-FR"), TestKey("hello")),
+    "Bonjour"
+  );
+
+  fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn files_accepted_by_the_file_filter_are_reloaded() {
+  let directory = temporary_fixture("languages");
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, TestKey>::builder()
+    .directory(directory.to_str().unwrap())
+    .default_language(&english)
+    .with_file_extensions(&["fluent"])
+    .build()
+    .unwrap();
+
+  let (translator, _watch_guard) = translator.watch().unwrap();
+
+  assert_ne!(
+    translator
+      .read()
+      .translate_without_arguments(&english, TestKey("hello")),
+    "Hi"
+  );
+
+  fs::write(directory.join("en-US/main.fluent"), "hello = Hi\n").unwrap();
+
+  assert!(eventually(|| translator
+    .read()
+    .translate_without_arguments(&english, TestKey("hello"))
+    == "Hi"));
+
+  fs::remove_dir_all(directory).unwrap();
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "xliff")]
+
+mod common;
+
+use common::{fixture, TestKey, TestLanguage};
+use translate::{xliff_to_ftl, Translator, TranslatorError, XliffVersion};
+
+#[test]
+fn xliff_export_includes_sources_and_existing_targets() {
+  let english = TestLanguage("en-US");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("languages"), &english).unwrap();
+
+  let xliff = translator.export_xliff("es-ES", XliffVersion::V1_2);
+
+  assert!(xliff.contains("source-language=\"en-US\" target-language=\"es-ES\""));
+  assert!(xliff.contains(
+    "<trans-unit id=\"hello\">\n        <source>Hello</source>\n        <target state=\"translated\">Hola</target>"
+  ));
+}
+
+#[test]
+fn xliff_export_round_trips_attributes_and_placeables() {
+  let english = TestLanguage("en-US");
+  let translator =
+    Translator::<TestLanguage, TestKey>::new(&fixture("variants"), &english).unwrap();
+
+  for version in [XliffVersion::V1_2, XliffVersion::V2_0] {
+    let xliff = translator.export_xliff("en-US", version);
+    let import = xliff_to_ftl(&xliff).unwrap();
+
+    assert_eq!(import.source_language.as_deref(), Some("en-US"));
+    assert_eq!(import.target_language, "en-US");
+    assert_eq!(
+      import.ftl,
+      "greeting = Hello\n    .formal = Good day\nlogin-button =\n    .label = Log in\n"
+    );
+  }
+}
+
+#[test]
+fn xliff_import_converts_translated_units() {
+  let xliff = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xliff version="2.0" xmlns="urn:oasis:names:tc:xliff:document:2.0" srcLang="en-US" trgLang="es-ES">
+  <file id="translations">
+    <unit id="hello">
+      <segment><source>Hello { $name } &amp; friends</source><target>Hola { $name } &amp; amigos</target></segment>
+    </unit>
+    <unit id="pending">
+      <segment><source>Pending</source></segment>
+    </unit>
+    <unit id="broken">
+      <segment><source>Broken</source><target>Roto { $name</target></segment>
+    </unit>
+  </file>
+</xliff>"#;
+
+  assert!(matches!(
+    xliff_to_ftl(xliff),
+    Err(TranslatorError::InvalidXliffFile { .. })
+  ));
+
+  let import = xliff_to_ftl(&xliff.replace("Roto { $name", "Roto")).unwrap();
+
+  assert_eq!(import.target_language, "es-ES");
+  assert_eq!(
+    import.ftl,
+    "hello = Hola { $name } & amigos\nbroken = Roto\n"
+  );
+}
+
+#[test]
+fn xliff_round_trips_select_expressions() {
+  let english = TestLanguage("en-US");
+  let translator = Translator::<TestLanguage, TestKey>::new(&fixture("plurals"), &english).unwrap();
+
+  let import = xliff_to_ftl(&translator.export_xliff("en-US", XliffVersion::V2_0)).unwrap();
+  assert_eq!(
+    import.ftl,
+    "unread =\n    { $count ->\n        [one] One unread message\n       *[other] { $count } unread messages\n    }\n"
+  );
+}
@@ -0,0 +1,81 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Error, Field, Fields, LitStr};
+
+pub(crate) fn derive(input: &DeriveInput) -> syn::Result<TokenStream> {
+  let Data::Struct(data) = &input.data else {
+    return Err(Error::new_spanned(
+      &input.ident,
+      "IntoFluentArgs can only be derived for structs",
+    ));
+  };
+
+  let Fields::Named(fields) = &data.fields else {
+    return Err(Error::new_spanned(
+      &input.ident,
+      "IntoFluentArgs can only be derived for structs with named fields",
+    ));
+  };
+
+  let name = &input.ident;
+  let (_, type_generics, _) = input.generics.split_for_impl();
+
+  let mut generics = input.generics.clone();
+  generics.params.insert(0, parse_quote!('__args));
+
+  let where_clause = generics.make_where_clause();
+  for field in &fields.named {
+    let field_type = &field.ty;
+    where_clause.predicates.push(parse_quote! {
+      #field_type: ::core::convert::Into<::translate::FluentValue<'__args>>
+    });
+  }
+
+  let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+  let identifiers = fields.named.iter().map(|field| &field.ident);
+  let argument_names = fields
+    .named
+    .iter()
+    .map(argument_name)
+    .collect::<syn::Result<Vec<String>>>()?;
+
+  Ok(quote! {
+    impl #impl_generics ::translate::IntoFluentArgs<'__args> for #name #type_generics #where_clause {
+      fn into_fluent_args(self) -> ::translate::FluentArgs<'__args> {
+        let mut args = ::translate::FluentArgs::new();
+        #(args.set(#argument_names, self.#identifiers);)*
+        args
+      }
+    }
+  })
+}
+
+fn argument_name(field: &Field) -> syn::Result<String> {
+  let mut name = None;
+
+  for attribute in &field.attrs {
+    if !attribute.path().is_ident("translate") {
+      continue;
+    }
+
+    attribute.parse_nested_meta(|meta| {
+      if !meta.path.is_ident("name") {
+        return Err(meta.error("unsupported translate attribute, expected `name`"));
+      }
+
+      let value: LitStr = meta.value()?.parse()?;
+      name = Some(value.value());
+      Ok(())
+    })?;
+  }
+
+  // Named fields always have an identifier.
+  Ok(name.unwrap_or_else(|| {
+    field
+      .ident
+      .as_ref()
+      .map(ToString::to_string)
+      .unwrap_or_default()
+  }))
+}
@@ -0,0 +1,182 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+  parenthesized, punctuated::Punctuated, Data, DeriveInput, Error, Fields, Ident, Lit, LitStr,
+  Token, Variant,
+};
+
+/// A variant of a translation key enum along with the key it maps to.
+pub(crate) struct KeyVariant {
+  pub(crate) ident: Ident,
+  pub(crate) key: String,
+  pub(crate) args: Vec<String>,
+  /// Default arguments by name, string or number literals.
+  pub(crate) defaults: Vec<(String, Lit)>,
+}
+
+pub(crate) fn derive(input: &DeriveInput) -> syn::Result<TokenStream> {
+  let variants = key_variants(input)?;
+
+  let name = &input.ident;
+  let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+  let identifiers: Vec<&Ident> = variants.iter().map(|variant| &variant.ident).collect();
+  let keys: Vec<&String> = variants.iter().map(|variant| &variant.key).collect();
+  let args: Vec<&Vec<String>> = variants.iter().map(|variant| &variant.args).collect();
+  let default_names: Vec<Vec<&String>> = variants
+    .iter()
+    .map(|variant| variant.defaults.iter().map(|(name, _)| name).collect())
+    .collect();
+  let default_values: Vec<Vec<&Lit>> = variants
+    .iter()
+    .map(|variant| variant.defaults.iter().map(|(_, value)| value).collect())
+    .collect();
+
+  Ok(quote! {
+    impl #impl_generics ::translate::TranslationKey for #name #type_generics #where_clause {
+      fn as_str(&self) -> &'static str {
+        match *self {
+          #(Self::#identifiers => #keys,)*
+        }
+      }
+
+      fn from_str(key: &str) -> ::core::option::Option<Self> {
+        match key {
+          #(#keys => ::core::option::Option::Some(Self::#identifiers),)*
+          _ => ::core::option::Option::None,
+        }
+      }
+
+      fn required_args(&self) -> &'static [&'static str] {
+        match *self {
+          #(Self::#identifiers => &[#(#args),*],)*
+        }
+      }
+
+      fn default_args(&self) -> ::translate::FluentArgs<'static> {
+        #[allow(unused_mut)]
+        let mut args = ::translate::FluentArgs::new();
+
+        match *self {
+          #(Self::#identifiers => {
+            #(args.set(#default_names, #default_values);)*
+          })*
+        }
+
+        args
+      }
+    }
+  })
+}
+
+pub(crate) fn key_variants(input: &DeriveInput) -> syn::Result<Vec<KeyVariant>> {
+  let Data::Enum(data) = &input.data else {
+    return Err(Error::new_spanned(
+      &input.ident,
+      "TranslationKey can only be derived for enums",
+    ));
+  };
+
+  data
+    .variants
+    .iter()
+    .map(|variant| {
+      if !matches!(variant.fields, Fields::Unit) {
+        return Err(Error::new_spanned(
+          variant,
+          "TranslationKey variants can not have fields",
+        ));
+      }
+
+      variant_attributes(variant)
+    })
+    .collect()
+}
+
+/// Reads `#[translate(key = "...", args("...", ...), defaults(name = value, ...))]`, defaulting the key to
+/// the variant name in snake case.
+fn variant_attributes(variant: &Variant) -> syn::Result<KeyVariant> {
+  let mut key = None;
+  let mut args = Vec::new();
+  let mut defaults = Vec::new();
+
+  for attribute in &variant.attrs {
+    if !attribute.path().is_ident("translate") {
+      continue;
+    }
+
+    attribute.parse_nested_meta(|meta| {
+      if meta.path.is_ident("key") {
+        let value: LitStr = meta.value()?.parse()?;
+        key = Some(value.value());
+        return Ok(());
+      }
+
+      if meta.path.is_ident("args") {
+        let content;
+        parenthesized!(content in meta.input);
+        let names = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+        args.extend(names.iter().map(LitStr::value));
+        return Ok(());
+      }
+
+      if meta.path.is_ident("defaults") {
+        return meta.parse_nested_meta(|default| {
+          let name = default
+            .path
+            .get_ident()
+            .ok_or_else(|| default.error("expected an argument name"))?
+            .to_string();
+          let value: Lit = default.value()?.parse()?;
+
+          if !matches!(value, Lit::Str(_) | Lit::Int(_) | Lit::Float(_)) {
+            return Err(Error::new_spanned(
+              value,
+              "default arguments must be strings or numbers",
+            ));
+          }
+
+          defaults.push((name, value));
+          Ok(())
+        });
+      }
+
+      Err(meta.error("unsupported translate attribute, expected `key`, `args` or `defaults`"))
+    })?;
+  }
+
+  let key = key.unwrap_or_else(|| to_snake_case(&variant.ident.to_string()));
+
+  Ok(KeyVariant {
+    ident: variant.ident.clone(),
+    key,
+    args,
+    defaults,
+  })
+}
+
+/// `HelloWithArguments` becomes `hello_with_arguments` and `HTTPError` becomes `http_error`.
+fn to_snake_case(name: &str) -> String {
+  let characters: Vec<char> = name.chars().collect();
+  let mut snake_case = String::with_capacity(name.len() + 4);
+
+  for (index, character) in characters.iter().enumerate() {
+    if character.is_uppercase() && index > 0 {
+      let previous = characters[index - 1];
+      let next_is_lowercase = characters
+        .get(index + 1)
+        .is_some_and(|next| next.is_lowercase());
+
+      if previous.is_lowercase()
+        || previous.is_numeric()
+        || (previous.is_uppercase() && next_is_lowercase)
+      {
+        snake_case.push('_');
+      }
+    }
+
+    snake_case.extend(character.to_lowercase());
+  }
+
+  snake_case
+}
@@ -0,0 +1,87 @@
+mod args;
+mod keys;
+mod validate;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+use crate::validate::ValidateKeysInput;
+
+/// ### Description
+/// Implements `TranslationKey` for an enum of unit variants. Each variant maps to its name in snake case,
+/// which can be changed with `#[translate(key = "...")]`. `from_str` is implemented as well, and
+/// `required_args` returns the arguments listed with `#[translate(args("..."))]`, and `default_args` the
+/// ones set with `#[translate(defaults(count = 1))]`.
+/// ### Usage
+/// ```ignore
+/// #[derive(TranslationKey)]
+/// enum TranslationKeys {
+///   Hello,
+///   HelloWithArguments,
+///   #[translate(key = "menu-save")]
+///   Save,
+///   #[translate(args("name"))]
+///   Welcome,
+///   #[translate(defaults(count = 1))]
+///   Unread,
+/// }
+///
+/// assert_eq!(TranslationKeys::HelloWithArguments.as_str(), "hello_with_arguments");
+/// ```
+#[proc_macro_derive(TranslationKey, attributes(translate))]
+pub fn derive_translation_key(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+
+  keys::derive(&input)
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
+
+/// ### Description
+/// Implements `IntoFluentArgs` for a struct with named fields, so it can be passed to
+/// `MessageTranslator::with`. Each field becomes the argument with its name, which can be changed with
+/// `#[translate(name = "...")]`. Field types must convert into `FluentValue`.
+/// ### Usage
+/// ```ignore
+/// #[derive(IntoFluentArgs)]
+/// struct UserContext<'a> {
+///   name: &'a str,
+///   #[translate(name = "unread-count")]
+///   unread_count: u32,
+/// }
+///
+/// let message = translator.translate(language, key).with(UserContext { name: "Alex", unread_count: 3 });
+/// ```
+#[proc_macro_derive(IntoFluentArgs, attributes(translate))]
+pub fn derive_into_fluent_args(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+
+  args::derive(&input)
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
+
+/// ### Description
+/// Wraps a translation key enum and fails to compile when the key of a variant is not defined by the
+/// translation files of a language. The directory is relative to the crate manifest, and the crate is
+/// rebuilt whenever one of the checked files changes.
+/// ### Usage
+/// ```ignore
+/// validate_keys! {
+///   "translations", "en-US";
+///
+///   #[derive(TranslationKey)]
+///   pub enum TranslationKeys {
+///     Hello,
+///     HelloWithArguments,
+///   }
+/// }
+/// ```
+#[proc_macro]
+pub fn validate_keys(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as ValidateKeysInput);
+
+  validate::expand(&input)
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
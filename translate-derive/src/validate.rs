@@ -0,0 +1,130 @@
+use crate::keys::key_variants;
+
+use std::{
+  collections::HashSet,
+  env, fs,
+  path::{Path, PathBuf},
+};
+
+use fluent_syntax::{ast::Entry, parser};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+  parse::{Parse, ParseStream},
+  DeriveInput, Error, LitStr, Token,
+};
+
+/// `"directory", "language"; enum`
+pub(crate) struct ValidateKeysInput {
+  directory: LitStr,
+  language: LitStr,
+  item: DeriveInput,
+}
+
+impl Parse for ValidateKeysInput {
+  fn parse(input: ParseStream) -> syn::Result<Self> {
+    let directory = input.parse()?;
+    input.parse::<Token![,]>()?;
+    let language = input.parse()?;
+    input.parse::<Token![;]>()?;
+    let item = input.parse()?;
+
+    Ok(Self {
+      directory,
+      language,
+      item,
+    })
+  }
+}
+
+pub(crate) fn expand(input: &ValidateKeysInput) -> syn::Result<TokenStream> {
+  let variants = key_variants(&input.item)?;
+
+  let manifest_directory = env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+  let directory = Path::new(&manifest_directory).join(input.directory.value());
+  let language = input.language.value();
+
+  let files = language_files(&directory, &language);
+
+  if files.is_empty() {
+    return Err(Error::new_spanned(
+      &input.language,
+      format!(
+        "No translation files found for {language} in {}",
+        directory.display()
+      ),
+    ));
+  }
+
+  let mut message_ids = HashSet::new();
+
+  for file in &files {
+    let content = fs::read_to_string(file).map_err(|error| {
+      Error::new_spanned(
+        &input.directory,
+        format!("Could not read {}: {error}", file.display()),
+      )
+    })?;
+
+    let resource = match parser::parse(content.as_str()) {
+      Ok(resource) => resource,
+      Err((resource, _)) => resource,
+    };
+
+    message_ids.extend(resource.body.into_iter().filter_map(|entry| match entry {
+      Entry::Message(message) => Some(message.id.name.to_string()),
+      _ => None,
+    }));
+  }
+
+  let missing_keys = variants
+    .iter()
+    .filter(|variant| !message_ids.contains(&variant.key))
+    .map(|variant| {
+      Error::new_spanned(
+        &variant.ident,
+        format!(
+          "Key {} is not defined by the {language} translations in {}",
+          variant.key,
+          directory.display()
+        ),
+      )
+    })
+    .reduce(|mut errors, error| {
+      errors.combine(error);
+      errors
+    });
+
+  if let Some(errors) = missing_keys {
+    return Err(errors);
+  }
+
+  let item = &input.item;
+  let tracked_files = files.iter().map(|file| file.to_string_lossy().to_string());
+
+  Ok(quote! {
+    #item
+
+    #(const _: &str = include_str!(#tracked_files);)*
+  })
+}
+
+/// Files of `<directory>/<language>/` and `<directory>/<language>.ftl`, the same layout the translator loads.
+fn language_files(directory: &Path, language: &str) -> Vec<PathBuf> {
+  let mut files: Vec<PathBuf> = fs::read_dir(directory.join(language))
+    .into_iter()
+    .flatten()
+    .filter_map(Result::ok)
+    .map(|entry| entry.path())
+    .filter(|path| path.is_file())
+    .collect();
+
+  let top_level_file = directory.join(format!("{language}.ftl"));
+
+  if top_level_file.is_file() {
+    files.push(top_level_file);
+  }
+
+  files.sort();
+  files
+}